@@ -1,6 +1,6 @@
 use clap::{Parser, Subcommand};
 
-const DEFAULT_CONFIG: &str = "8wXtPM3EHPu4BKXpBCrWXqhzPc9vS2HSkD9veATmU4Yq";
+const DEFAULT_NAMESPACE: &str = "default";
 const DEFAULT_PROGRAM_ID: &str = "Fu4zvEKjgxWjaQifp7fyghKJfk6HzUCaJRvoGffJBm6Q";
 
 #[derive(Parser)]
@@ -16,6 +16,10 @@ pub struct Cli {
     #[arg(short, long, default_value = DEFAULT_PROGRAM_ID)]
     pub program_id: String,
 
+    /// Namespace of the config, used by create-config and by commands not given --config
+    #[arg(long, global = true, default_value = DEFAULT_NAMESPACE)]
+    pub namespace: String,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -23,68 +27,189 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     /// List all blacklisted validators
-    List,
+    List {
+        /// Only list validators of this config
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Only list the validator recorded with this vote account
+        #[arg(long)]
+        vote_account: Option<String>,
+    },
+
+    /// List all config accounts (one per blacklist namespace)
+    ListConfigs,
     
-    /// Create a new config account
+    /// Create a new config account at the PDA derived from the namespace
     CreateConfig {
-        #[arg(short, long, default_value = DEFAULT_CONFIG)]
-        config: String,
         #[arg(short, long)]
         min_tvl: u64,
+        /// Comma separated PROGRAM_ID[:MIN_TVL[:ADAPTER]] entries, MIN_TVL overrides --min-tvl for that program and may be empty, ADAPTER is spl-stake-pool (the default) or marinade
         #[arg(short, long, value_delimiter = ',')]
         allowed_programs: Vec<String>,
+        #[arg(long, default_value_t = 1)]
+        min_add_votes: u64,
+        #[arg(long, default_value_t = 1)]
+        min_net_votes: u64,
     },
     
     /// Update an existing config account
     UpdateConfig {
-        #[arg(short, long, default_value = DEFAULT_CONFIG)]
-        config: String,
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
         #[arg(short, long)]
         min_tvl: Option<u64>,
+        /// Comma separated PROGRAM_ID[:MIN_TVL[:ADAPTER]] entries, replaces the whole list
         #[arg(short, long, value_delimiter = ',')]
         allowed_programs: Option<Vec<String>>,
+        #[arg(long)]
+        min_add_votes: Option<u64>,
+        #[arg(long)]
+        min_net_votes: Option<u64>,
+        /// Seconds after which a vote can be expired unless reaffirmed, 0 disables expiry
+        #[arg(long)]
+        vote_lifetime: Option<i64>,
+        /// Stake pool roles that may vote directly: manager-only, staker-only or either
+        #[arg(long)]
+        authority_policy: Option<String>,
     },
     
-    /// Update config admin
-    UpdateConfigAdmin {
-        #[arg(short, long, default_value = DEFAULT_CONFIG)]
-        config: String,
+    /// Resize the config account to hold a different number of allowed programs, migrating an older layout version
+    ResizeConfig {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(long)]
+        capacity: u16,
+    },
+
+    /// Pause voting and delegating (unvotes and undelegations keep working)
+    Pause {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+    },
+
+    /// Resume voting and delegating
+    Unpause {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+    },
+
+    /// Propose a new config admin, who then has to accept
+    ProposeAdmin {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
         #[arg(short, long)]
         new_admin: String,
     },
+
+    /// Accept a pending admin proposal (signed by the proposed admin)
+    AcceptAdmin {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+    },
+
+    /// Cancel a pending admin proposal
+    CancelAdminProposal {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+    },
+
+    /// Admit a stake pool regardless of the minimum TVL (its program must still be allowed)
+    AllowPool {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        stake_pool: String,
+    },
+
+    /// Ban a stake pool from voting and delegating
+    DenyPool {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        stake_pool: String,
+    },
+
+    /// Remove the allow or deny override of a stake pool
+    RemovePoolOverride {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        stake_pool: String,
+    },
+
+    /// List the stake pool overrides
+    ListPoolOverrides {
+        /// Only list overrides of this config
+        #[arg(short, long)]
+        config: Option<String>,
+    },
     
     /// Vote to add a validator to the blacklist
     VoteAdd {
-        #[arg(short, long, default_value = DEFAULT_CONFIG)]
-        config: String,
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
         #[arg(short, long)]
         validator_address: String,
         #[arg(short, long)]
         stake_pool: String,
         #[arg(short, long)]
         reason: String,
+        /// Category of the misbehaviour: sandwiching, delinquency, commission-rug, censorship or other
+        #[arg(long, default_value = "other")]
+        category: String,
+        /// Hex encoded 32-byte content hash of the off-chain evidence bundle
+        #[arg(long, requires = "evidence_uri")]
+        evidence_hash: Option<String>,
+        /// Location of the off-chain evidence bundle (Arweave, IPFS, ...)
+        #[arg(long, requires = "evidence_hash")]
+        evidence_uri: Option<String>,
+        /// Vote account of the validator, recorded on the blacklist entry after checking its identity
+        #[arg(long)]
+        vote_account: Option<String>,
         #[arg(short, long)]
         delegation: Option<String>,
     },
     
     /// Vote to remove a validator from the blacklist
     VoteRemove {
-        #[arg(short, long, default_value = DEFAULT_CONFIG)]
-        config: String,
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
         #[arg(short, long)]
         validator_address: String,
         #[arg(short, long)]
         stake_pool: String,
         #[arg(short, long)]
         reason: String,
+        /// Category of the misbehaviour: sandwiching, delinquency, commission-rug, censorship or other
+        #[arg(long, default_value = "other")]
+        category: String,
+        /// Hex encoded 32-byte content hash of the off-chain evidence bundle
+        #[arg(long, requires = "evidence_uri")]
+        evidence_hash: Option<String>,
+        /// Location of the off-chain evidence bundle (Arweave, IPFS, ...)
+        #[arg(long, requires = "evidence_hash")]
+        evidence_uri: Option<String>,
         #[arg(short, long)]
         delegation: Option<String>,
     },
     
     /// Unvote add (remove a previous add vote)
     UnvoteAdd {
-        #[arg(short, long, default_value = DEFAULT_CONFIG)]
-        config: String,
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
         #[arg(short, long)]
         validator_address: String,
         #[arg(short, long)]
@@ -95,24 +220,154 @@ pub enum Commands {
     
     /// Unvote remove (remove a previous remove vote)
     UnvoteRemove {
-        #[arg(short, long, default_value = DEFAULT_CONFIG)]
-        config: String,
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        validator_address: String,
+        #[arg(short, long)]
+        stake_pool: String,
+        #[arg(short, long)]
+        delegation: Option<String>,
+    },
+
+    /// Change an add vote into a remove vote, or the reverse, in one transaction
+    ChangeVote {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
         #[arg(short, long)]
         validator_address: String,
         #[arg(short, long)]
         stake_pool: String,
+        /// Reason of the new vote
+        #[arg(short, long)]
+        reason: String,
+        /// Change the add vote into a remove vote instead of the remove vote into an add vote
+        #[arg(long)]
+        remove: bool,
+        /// Category of the misbehaviour: sandwiching, delinquency, commission-rug, censorship or other
+        #[arg(long, default_value = "other")]
+        category: String,
+        /// Hex encoded 32-byte content hash of the off-chain evidence bundle
+        #[arg(long, requires = "evidence_uri")]
+        evidence_hash: Option<String>,
+        /// Location of the off-chain evidence bundle (Arweave, IPFS, ...)
+        #[arg(long, requires = "evidence_hash")]
+        evidence_uri: Option<String>,
         #[arg(short, long)]
         delegation: Option<String>,
     },
     
+    /// Reaffirm a vote so that it does not expire
+    ReaffirmVote {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        validator_address: String,
+        #[arg(short, long)]
+        stake_pool: String,
+        /// Reaffirm the remove vote instead of the add vote
+        #[arg(long)]
+        remove: bool,
+        #[arg(short, long)]
+        delegation: Option<String>,
+    },
+
+    /// Replace the reason of a vote
+    EditReason {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        validator_address: String,
+        #[arg(short, long)]
+        stake_pool: String,
+        #[arg(short, long)]
+        reason: String,
+        /// Edit the remove vote instead of the add vote
+        #[arg(long)]
+        remove: bool,
+        #[arg(short, long)]
+        delegation: Option<String>,
+    },
+
+    /// Expire a vote that outlived the vote lifetime (anyone can do this)
+    ExpireVote {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        validator_address: String,
+        #[arg(short, long)]
+        stake_pool: String,
+        /// Expire the remove vote instead of the add vote
+        #[arg(long)]
+        remove: bool,
+    },
+    
+    /// Prune a vote whose stake pool no longer meets the config requirements (anyone can do this)
+    PruneVote {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        validator_address: String,
+        #[arg(short, long)]
+        stake_pool: String,
+        /// Prune the remove vote instead of the add vote
+        #[arg(long)]
+        remove: bool,
+    },
+    
+    /// Close a blacklist entry that no longer has any votes, refunding its rent (anyone can do this)
+    CloseBlacklist {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        validator_address: String,
+    },
+
+    /// Veto a blacklist entry, reporting it as overridden regardless of the votes (admin only)
+    Veto {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        validator_address: String,
+        #[arg(short, long)]
+        reason: String,
+    },
+
+    /// Lift the veto of a blacklist entry (admin only)
+    LiftVeto {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        validator_address: String,
+    },
+    
     /// Delegate authority to another account
     Delegate {
-        #[arg(short, long, default_value = DEFAULT_CONFIG)]
-        config: String,
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
         #[arg(short, long)]
         stake_pool: String,
         #[arg(short, long)]
         delegate: String,
+        /// Index of the delegation, a stake pool can have up to 256 delegations
+        #[arg(short, long, default_value_t = 0)]
+        index: u8,
+        /// Comma separated permissions: vote-add, unvote-add, vote-remove, unvote-remove or all
+        #[arg(long, value_delimiter = ',', default_value = "all")]
+        permissions: Vec<String>,
+        /// Unix timestamp from which the delegation is no longer valid
+        #[arg(long)]
+        expires_at: Option<i64>,
         #[arg(short = 'o', long, default_value = "execute")]
         output: String,
         #[arg(short = 'M', long)]
@@ -121,20 +376,64 @@ pub enum Commands {
     
     /// Remove delegation (undelegate)
     Undelegate {
-        #[arg(short, long, default_value = DEFAULT_CONFIG)]
-        config: String,
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        stake_pool: String,
+        #[arg(short, long, default_value_t = 0)]
+        index: u8,
+        #[arg(short = 'o', long, default_value = "execute")]
+        output: String,
+        #[arg(short = 'M', long)]
+        manager: Option<String>,
+    },
+
+    /// Replace the delegate of an existing delegation in a single transaction
+    UpdateDelegate {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
         #[arg(short, long)]
         stake_pool: String,
+        #[arg(short, long, default_value_t = 0)]
+        index: u8,
+        /// The new delegate
+        #[arg(short, long)]
+        delegate: String,
         #[arg(short = 'o', long, default_value = "execute")]
         output: String,
         #[arg(short = 'M', long)]
         manager: Option<String>,
     },
 
+    /// Close a delegation created by a previous stake pool manager (anyone can do this)
+    CloseStaleDelegation {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        stake_pool: String,
+        #[arg(short, long, default_value_t = 0)]
+        index: u8,
+    },
+
+    /// Close a delegation that has expired (anyone can do this)
+    CloseExpiredDelegation {
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
+        #[arg(short, long)]
+        stake_pool: String,
+        #[arg(short, long, default_value_t = 0)]
+        index: u8,
+    },
+
     /// Batch ban validators from a CSV file, filtering by active validators
     BatchBan {
-        #[arg(short, long, default_value = DEFAULT_CONFIG)]
-        config: String,
+        /// Config address, derived from --namespace when omitted
+        #[arg(short, long)]
+        config: Option<String>,
         #[arg(short, long)]
         stake_pool: String,
         #[arg(short = 'f', long)]
@@ -143,5 +442,11 @@ pub enum Commands {
         validators_file: Option<String>,
         #[arg(short, long)]
         delegation: Option<String>,
+        /// Maximum number of validators voted on per transaction, batches are also limited to what fits into a transaction
+        #[arg(short, long, default_value_t = 5)]
+        batch_size: usize,
+        /// CSV file the rows of failed batches are written to, pass it as --file to retry them [default: <FILE>.failed.csv]
+        #[arg(long)]
+        failed_file: Option<String>,
     },
 }
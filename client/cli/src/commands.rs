@@ -5,6 +5,10 @@ use anchor_client::solana_sdk::{
     signer::Signer,
 };
 use solana_commitment_config::CommitmentConfig;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::Message;
+use solana_sdk::packet::PACKET_DATA_SIZE;
 use solana_sdk::transaction::Transaction;
 use anchor_client::solana_account_decoder::UiAccountEncoding;
 use anchor_client::{Client, Cluster};
@@ -14,7 +18,11 @@ use solana_client::rpc_client::RpcClient;
 use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
 use solana_client::rpc_filter::{RpcFilterType, Memcmp};
 use solana_sdk_ids::system_program;
-use validator_blacklist::state::Blacklist;
+use validator_blacklist::config_migration::{read_migrated_config, LEGACY_CONFIG_LEN};
+use validator_blacklist::instructions::BatchVote;
+use validator_blacklist::state::{AllowedProgram, AuthorityPolicy, Blacklist, Config, Delegation, Evidence, PoolAdapter, PoolOverride, PoolOverrideKind, ReasonCategory, Veto, VoteAddToBlacklist, VoteDirection, VoteRemoveFromBlacklist};
+use std::collections::HashMap;
+use std::ops::Range;
 use std::str::FromStr;
 use std::rc::Rc;
 
@@ -23,54 +31,139 @@ pub fn run_command(cli: Cli) -> Result<()> {
         .context("Invalid program ID")?;
 
     match cli.command {
-        Commands::List => {
-            list_blacklisted_validators(&cli.rpc, &program_id)?;
+        Commands::List { config, vote_account } => {
+            list_blacklisted_validators(&cli.rpc, &program_id, config, vote_account)?;
         }
-        Commands::Delegate { config, stake_pool, delegate, output, manager } => {
-            handle_delegate_command(&cli.rpc, &program_id, config, stake_pool, delegate, output, manager, cli.keypair)?;
+        Commands::ListConfigs => {
+            list_configs(&cli.rpc, &program_id)?;
         }
-        Commands::Undelegate { config, stake_pool, output, manager } => {
-            handle_undelegate_command(&cli.rpc, &program_id, config, stake_pool, output, manager, cli.keypair)?;
+        Commands::Delegate { config, stake_pool, delegate, index, permissions, expires_at, output, manager } => {
+            handle_delegate_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, stake_pool, delegate, index, permissions, expires_at, output, manager, cli.keypair)?;
         }
-        Commands::CreateConfig { config, min_tvl, allowed_programs } => {
-            handle_create_config_command(&cli.rpc, &program_id, config, min_tvl, allowed_programs, cli.keypair)?;
+        Commands::Undelegate { config, stake_pool, index, output, manager } => {
+            handle_undelegate_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, stake_pool, index, output, manager, cli.keypair)?;
         }
-        Commands::UpdateConfig { config, min_tvl, allowed_programs } => {
-            handle_update_config_command(&cli.rpc, &program_id, config, min_tvl, allowed_programs, cli.keypair)?;
+        Commands::UpdateDelegate { config, stake_pool, index, delegate, output, manager } => {
+            handle_update_delegate_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, stake_pool, index, delegate, output, manager, cli.keypair)?;
         }
-        Commands::UpdateConfigAdmin { config, new_admin } => {
-            handle_update_config_admin_command(&cli.rpc, &program_id, config, new_admin, cli.keypair)?;
+        Commands::CloseStaleDelegation { config, stake_pool, index } => {
+            handle_close_stale_delegation_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, stake_pool, index, cli.keypair)?;
         }
-        Commands::VoteAdd { config, validator_address, stake_pool, reason, delegation } => {
-            handle_vote_add_command(&cli.rpc, &program_id, config, validator_address, stake_pool, reason, delegation, cli.keypair)?;
+        Commands::CloseExpiredDelegation { config, stake_pool, index } => {
+            handle_close_expired_delegation_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, stake_pool, index, cli.keypair)?;
         }
-        Commands::VoteRemove { config, validator_address, stake_pool, reason, delegation } => {
-            handle_vote_remove_command(&cli.rpc, &program_id, config, validator_address, stake_pool, reason, delegation, cli.keypair)?;
+        Commands::CreateConfig { min_tvl, allowed_programs, min_add_votes, min_net_votes } => {
+            handle_create_config_command(&cli.rpc, &program_id, cli.namespace, min_tvl, allowed_programs, min_add_votes, min_net_votes, cli.keypair)?;
+        }
+        Commands::UpdateConfig { config, min_tvl, allowed_programs, min_add_votes, min_net_votes, vote_lifetime, authority_policy } => {
+            let authority_policy = authority_policy.as_deref().map(parse_authority_policy).transpose()?;
+            handle_update_config_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, min_tvl, allowed_programs, min_add_votes, min_net_votes, vote_lifetime, authority_policy, cli.keypair)?;
+        }
+        Commands::ResizeConfig { config, capacity } => {
+            handle_resize_config_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, capacity, cli.keypair)?;
+        }
+        Commands::Pause { config } => {
+            handle_set_paused_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, true, cli.keypair)?;
+        }
+        Commands::Unpause { config } => {
+            handle_set_paused_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, false, cli.keypair)?;
+        }
+        Commands::ProposeAdmin { config, new_admin } => {
+            handle_propose_admin_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, new_admin, cli.keypair)?;
+        }
+        Commands::AcceptAdmin { config } => {
+            handle_accept_admin_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, cli.keypair)?;
+        }
+        Commands::CancelAdminProposal { config } => {
+            handle_cancel_admin_proposal_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, cli.keypair)?;
+        }
+        Commands::AllowPool { config, stake_pool } => {
+            handle_set_pool_override_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, stake_pool, PoolOverrideKind::Allow, cli.keypair)?;
+        }
+        Commands::DenyPool { config, stake_pool } => {
+            handle_set_pool_override_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, stake_pool, PoolOverrideKind::Deny, cli.keypair)?;
+        }
+        Commands::RemovePoolOverride { config, stake_pool } => {
+            handle_remove_pool_override_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, stake_pool, cli.keypair)?;
+        }
+        Commands::ListPoolOverrides { config } => {
+            list_pool_overrides(&cli.rpc, &program_id, config)?;
+        }
+        Commands::VoteAdd { config, validator_address, stake_pool, reason, category, evidence_hash, evidence_uri, vote_account, delegation } => {
+            let category = parse_category(&category)?;
+            let evidence = parse_evidence(evidence_hash.as_deref(), evidence_uri.as_deref())?;
+            handle_vote_add_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, stake_pool, reason, category, evidence, vote_account, delegation, cli.keypair)?;
+        }
+        Commands::VoteRemove { config, validator_address, stake_pool, reason, category, evidence_hash, evidence_uri, delegation } => {
+            let category = parse_category(&category)?;
+            let evidence = parse_evidence(evidence_hash.as_deref(), evidence_uri.as_deref())?;
+            handle_vote_remove_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, stake_pool, reason, category, evidence, delegation, cli.keypair)?;
         }
         Commands::UnvoteAdd { config, validator_address, stake_pool, delegation } => {
-            handle_unvote_add_command(&cli.rpc, &program_id, config, validator_address, stake_pool, delegation, cli.keypair)?;
+            handle_unvote_add_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, stake_pool, delegation, cli.keypair)?;
         }
         Commands::UnvoteRemove { config, validator_address, stake_pool, delegation } => {
-            handle_unvote_remove_command(&cli.rpc, &program_id, config, validator_address, stake_pool, delegation, cli.keypair)?;
+            handle_unvote_remove_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, stake_pool, delegation, cli.keypair)?;
+        }
+        Commands::ChangeVote { config, validator_address, stake_pool, reason, remove, category, evidence_hash, evidence_uri, delegation } => {
+            let category = parse_category(&category)?;
+            let evidence = parse_evidence(evidence_hash.as_deref(), evidence_uri.as_deref())?;
+            handle_change_vote_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, stake_pool, reason, remove, category, evidence, delegation, cli.keypair)?;
+        }
+        Commands::ReaffirmVote { config, validator_address, stake_pool, remove, delegation } => {
+            handle_reaffirm_vote_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, stake_pool, remove, delegation, cli.keypair)?;
+        }
+        Commands::EditReason { config, validator_address, stake_pool, reason, remove, delegation } => {
+            handle_edit_reason_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, stake_pool, reason, remove, delegation, cli.keypair)?;
         }
-        Commands::BatchBan { config, stake_pool, file, validators_file, delegation } => {
-            handle_batch_ban_command(&cli.rpc, &program_id, config, stake_pool, file, validators_file, delegation, cli.keypair)?;
+        Commands::ExpireVote { config, validator_address, stake_pool, remove } => {
+            handle_expire_vote_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, stake_pool, remove, cli.keypair)?;
+        }
+        Commands::CloseBlacklist { config, validator_address } => {
+            handle_close_blacklist_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, cli.keypair)?;
+        }
+        Commands::Veto { config, validator_address, reason } => {
+            handle_veto_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, reason, cli.keypair)?;
+        }
+        Commands::LiftVeto { config, validator_address } => {
+            handle_lift_veto_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, cli.keypair)?;
+        }
+        Commands::PruneVote { config, validator_address, stake_pool, remove } => {
+            handle_prune_vote_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, validator_address, stake_pool, remove, cli.keypair)?;
+        }
+        Commands::BatchBan { config, stake_pool, file, validators_file, delegation, batch_size, failed_file } => {
+            handle_batch_ban_command(&cli.rpc, &program_id, resolve_config(&program_id, config, &cli.namespace)?, stake_pool, file, validators_file, delegation, batch_size, failed_file, cli.keypair)?;
         }
     }
 
     Ok(())
 }
 
-fn list_blacklisted_validators(rpc_url: &str, program_id: &Pubkey) -> Result<()> {
+fn list_blacklisted_validators(rpc_url: &str, program_id: &Pubkey, config: Option<String>, vote_account: Option<String>) -> Result<()> {
+    let config_pubkey = config
+        .map(|config| Pubkey::from_str(&config).context("Invalid config address"))
+        .transpose()?;
+    let vote_account_pubkey = vote_account
+        .map(|vote_account| Pubkey::from_str(&vote_account).context("Invalid vote account address"))
+        .transpose()?;
+
     let rpc_client = RpcClient::new(rpc_url.to_string());
 
+    let mut filters = vec![
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, Blacklist::DISCRIMINATOR.to_vec())),
+    ];
+    if let Some(vote_account_pubkey) = vote_account_pubkey {
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            Blacklist::VOTE_ACCOUNT_OFFSET,
+            [&[1], vote_account_pubkey.as_ref()].concat(),
+        )));
+    }
+
     // Get all blacklist accounts
     let accounts = rpc_client.get_program_accounts_with_config(
         program_id,
         RpcProgramAccountsConfig {
-            filters: Some(vec![
-                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, Blacklist::DISCRIMINATOR.to_vec())),
-            ]),
+            filters: Some(filters),
             account_config: RpcAccountInfoConfig {
                 encoding: Some(UiAccountEncoding::Base64),
                 commitment: Some(CommitmentConfig::confirmed()),
@@ -85,9 +178,13 @@ fn list_blacklisted_validators(rpc_url: &str, program_id: &Pubkey) -> Result<()>
         return Ok(());
     }
 
+    // The stored status is only updated by votes, so evaluate it against the current thresholds
+    // of each config like `is_blacklisted` does
+    let mut configs: HashMap<Pubkey, Option<Config>> = HashMap::new();
+
     println!("Blacklisted Validators:");
-    println!("{:<44} {:<10} {:<10}", "Validator Address", "Add Votes", "Remove Votes");
-    println!("{}", "-".repeat(70));
+    println!("{:<44} {:<44} {:<20} {:<10} {:<12} {:<16} {:<16}", "Validator Address", "Vote Account", "Status", "Add Votes", "Remove Votes", "Add Stake", "Remove Stake");
+    println!("{}", "-".repeat(168));
 
     for (pubkey, account) in accounts {
         // Try to deserialize using the borsh trait method directly
@@ -98,23 +195,187 @@ fn list_blacklisted_validators(rpc_url: &str, program_id: &Pubkey) -> Result<()>
 
         let mut data = account.data.as_slice();
 
-        let blacklist = Blacklist::try_deserialize(&mut data)?;
+        let mut blacklist = Blacklist::try_deserialize(&mut data)?;
+
+        if config_pubkey.is_some_and(|config_pubkey| config_pubkey != blacklist.config) {
+            continue;
+        }
+
+        let config = configs
+            .entry(blacklist.config)
+            .or_insert_with(|| {
+                rpc_client.get_account_data(&blacklist.config)
+                    .ok()
+                    .and_then(|data| Config::try_deserialize(&mut data.as_slice()).ok())
+            });
+
+        // Fall back to the stored status if the config cannot be read, and say so
+        let status = match config {
+            Some(config) => {
+                blacklist.update_status(config);
+                format!("{:?}", blacklist.status)
+            }
+            None => format!("{:?} (stored)", blacklist.status),
+        };
 
         println!(
-            "{:<44} {:<10} {:<10}",
+            "{:<44} {:<44} {:<20} {:<10} {:<12} {:<16.2} {:<16.2}",
             blacklist.validator_identity_address,
+            blacklist.vote_account.map_or("-".to_string(), |vote_account| vote_account.to_string()),
+            status,
             blacklist.tally_add,
-            blacklist.tally_remove
+            blacklist.tally_remove,
+            lamports_to_sol(blacklist.stake_tally_add),
+            lamports_to_sol(blacklist.stake_tally_remove)
+        );
+    }
+
+    Ok(())
+}
+
+fn list_configs(rpc_url: &str, program_id: &Pubkey) -> Result<()> {
+    let rpc_client = RpcClient::new(rpc_url.to_string());
+
+    let accounts = rpc_client.get_program_accounts_with_config(
+        program_id,
+        RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, Config::DISCRIMINATOR.to_vec())),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(CommitmentConfig::confirmed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )?;
+
+    if accounts.is_empty() {
+        println!("No configs found.");
+        return Ok(());
+    }
+
+    println!("{:<44} {:<32} {:<44} {:<16} {:<8}", "Config Address", "Namespace", "Admin", "Min TVL", "Programs");
+    println!("{}", "-".repeat(148));
+
+    for (pubkey, account) in accounts {
+        // Keypair configs created before namespaces were introduced use the legacy layout, the
+        // program rejects them until `resize-config` migrates them
+        let config = match read_migrated_config(&account.data) {
+            Ok(config) => config,
+            Err(_) => {
+                eprintln!("Config {} could not be deserialized", pubkey);
+                continue;
+            }
+        };
+        let namespace = if account.data.len() == LEGACY_CONFIG_LEN {
+            "(legacy, run resize-config)".to_string()
+        } else {
+            config.namespace
+        };
+
+        println!(
+            "{:<44} {:<32} {:<44} {:<16.2} {:<8}",
+            pubkey,
+            namespace,
+            config.admin,
+            lamports_to_sol(config.min_tvl),
+            config.allowed_programs.len()
+        );
+    }
+
+    Ok(())
+}
+
+fn list_pool_overrides(rpc_url: &str, program_id: &Pubkey, config: Option<String>) -> Result<()> {
+    let config_pubkey = config
+        .map(|config| Pubkey::from_str(&config).context("Invalid config address"))
+        .transpose()?;
+
+    let rpc_client = RpcClient::new(rpc_url.to_string());
+
+    let mut filters = vec![
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, PoolOverride::DISCRIMINATOR.to_vec())),
+    ];
+    if let Some(config_pubkey) = config_pubkey {
+        // The config is the first field after the discriminator
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(8, config_pubkey.to_bytes().to_vec())));
+    }
+
+    let accounts = rpc_client.get_program_accounts_with_config(
+        program_id,
+        RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(CommitmentConfig::confirmed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )?;
+
+    if accounts.is_empty() {
+        println!("No pool overrides found.");
+        return Ok(());
+    }
+
+    println!("{:<44} {:<44} {:<8} {:<12}", "Stake Pool", "Config", "Kind", "Timestamp");
+    println!("{}", "-".repeat(110));
+
+    for (pubkey, account) in accounts {
+        let mut data = account.data.as_slice();
+
+        let pool_override = match PoolOverride::try_deserialize(&mut data) {
+            Ok(pool_override) => pool_override,
+            Err(_) => {
+                eprintln!("Pool override {} could not be deserialized", pubkey);
+                continue;
+            }
+        };
+
+        println!(
+            "{:<44} {:<44} {:<8} {:<12}",
+            pool_override.stake_pool,
+            pool_override.config,
+            format!("{:?}", pool_override.kind),
+            pool_override.timestamp
         );
     }
 
     Ok(())
 }
 
-fn handle_delegate_command(rpc_url: &str, program_id: &Pubkey, config: String, stake_pool: String, delegate: String, output: String, manager: Option<String>, keypair_option: Option<String>) -> Result<()> {
-    let config_pubkey = Pubkey::from_str(&config).context("Invalid config address")?;
+fn event_authority_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], program_id).0
+}
+
+/// Config given by --config, or else the config PDA of the namespace
+fn resolve_config(program_id: &Pubkey, config: Option<String>, namespace: &str) -> Result<Pubkey> {
+    match config {
+        Some(config) => Pubkey::from_str(&config).context("Invalid config address"),
+        None => Ok(Pubkey::find_program_address(&[b"config", namespace.as_bytes()], program_id).0),
+    }
+}
+
+/// Address of the admin override of a stake pool, which the vote instructions take whether it exists or not
+fn pool_override_address(program_id: &Pubkey, config: &Pubkey, stake_pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"pool_override", config.as_ref(), stake_pool.as_ref()],
+        program_id,
+    ).0
+}
+
+fn lamports_to_sol(lamports: u64) -> f64 {
+    lamports as f64 / LAMPORTS_PER_SOL as f64
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_delegate_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, stake_pool: String, delegate: String, index: u8, permissions: Vec<String>, expires_at: Option<i64>, output: String, manager: Option<String>, keypair_option: Option<String>) -> Result<()> {
     let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
     let delegate_pubkey = Pubkey::from_str(&delegate).context("Invalid delegate address")?;
+    let permissions = parse_permissions(&permissions)?;
 
     match output.as_str() {
         "execute" => {
@@ -127,21 +388,26 @@ fn handle_delegate_command(rpc_url: &str, program_id: &Pubkey, config: String, s
             let program = client.program(*program_id)?;
 
             let (delegation_pda, _) = Pubkey::find_program_address(
-                &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref()],
+                &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), &[index]],
                 program_id,
             );
+            let previous_manager = fetch_delegation_manager(rpc_url, &delegation_pda)?;
 
             let signature = program
                 .request()
                 .accounts(validator_blacklist::accounts::Delegate {
                     config: config_pubkey,
                     stake_pool: stake_pool_pubkey,
+                    pool_override: pool_override_address(program_id, &config_pubkey, &stake_pool_pubkey),
                     delegation: delegation_pda,
                     manager: keypair.pubkey(),
+                    previous_manager,
                     delegate: delegate_pubkey,
                     system_program: system_program::id(),
+                    event_authority: event_authority_address(program_id),
+                    program: *program_id,
                 })
-                .args(validator_blacklist::instruction::Delegate {})
+                .args(validator_blacklist::instruction::Delegate { index, permissions, expires_at })
                 .send()?;
 
             println!("Delegate transaction sent: {}", signature);
@@ -156,21 +422,26 @@ fn handle_delegate_command(rpc_url: &str, program_id: &Pubkey, config: String, s
             let program = client.program(*program_id)?;
 
             let (delegation_pda, _) = Pubkey::find_program_address(
-                &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref()],
+                &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), &[index]],
                 program_id,
             );
+            let previous_manager = fetch_delegation_manager(rpc_url, &delegation_pda)?;
 
             let ixs = program
                 .request()
                 .accounts(validator_blacklist::accounts::Delegate {
                     config: config_pubkey,
                     stake_pool: stake_pool_pubkey,
+                    pool_override: pool_override_address(program_id, &config_pubkey, &stake_pool_pubkey),
                     delegation: delegation_pda,
                     manager: manager_pubkey,
+                    previous_manager,
                     delegate: delegate_pubkey,
                     system_program: system_program::id(),
+                    event_authority: event_authority_address(program_id),
+                    program: *program_id,
                 })
-                .args(validator_blacklist::instruction::Delegate {})
+                .args(validator_blacklist::instruction::Delegate { index, permissions, expires_at })
                 .instructions()?;
 
             let tx = Transaction::new_with_payer(&ixs, Some(&manager_pubkey));
@@ -187,8 +458,8 @@ fn handle_delegate_command(rpc_url: &str, program_id: &Pubkey, config: String, s
 }
 
 
-fn handle_undelegate_command(rpc_url: &str, program_id: &Pubkey, config: String, stake_pool: String, output: String, manager: Option<String>, keypair_option: Option<String>) -> Result<()> {
-    let config_pubkey = Pubkey::from_str(&config).context("Invalid config address")?;
+#[allow(clippy::too_many_arguments)]
+fn handle_undelegate_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, stake_pool: String, index: u8, output: String, manager: Option<String>, keypair_option: Option<String>) -> Result<()> {
     let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
 
     match output.as_str() {
@@ -202,7 +473,7 @@ fn handle_undelegate_command(rpc_url: &str, program_id: &Pubkey, config: String,
             let program = client.program(*program_id)?;
 
             let (delegation_pda, _) = Pubkey::find_program_address(
-                &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref()],
+                &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), &[index]],
                 program_id,
             );
 
@@ -213,6 +484,8 @@ fn handle_undelegate_command(rpc_url: &str, program_id: &Pubkey, config: String,
                     stake_pool: stake_pool_pubkey,
                     delegation: delegation_pda,
                     manager: keypair.pubkey(),
+                    event_authority: event_authority_address(program_id),
+                    program: *program_id,
                 })
                 .args(validator_blacklist::instruction::Undelegate {})
                 .send()?;
@@ -229,7 +502,7 @@ fn handle_undelegate_command(rpc_url: &str, program_id: &Pubkey, config: String,
             let program = client.program(*program_id)?;
 
             let (delegation_pda, _) = Pubkey::find_program_address(
-                &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref()],
+                &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), &[index]],
                 program_id,
             );
 
@@ -240,6 +513,8 @@ fn handle_undelegate_command(rpc_url: &str, program_id: &Pubkey, config: String,
                     stake_pool: stake_pool_pubkey,
                     delegation: delegation_pda,
                     manager: manager_pubkey,
+                    event_authority: event_authority_address(program_id),
+                    program: *program_id,
                 })
                 .args(validator_blacklist::instruction::Undelegate {})
                 .instructions()?;
@@ -248,67 +523,864 @@ fn handle_undelegate_command(rpc_url: &str, program_id: &Pubkey, config: String,
             let serialized = bincode::serialize(&tx).context("Failed to serialize transaction")?;
             let base58_tx = bs58::encode(serialized).into_string();
 
-            println!("{}", base58_tx);
-        }
-        _ => {
-            return Err(anyhow::anyhow!("Invalid output format. Use 'execute' or 'base58'"));
-        }
-    }
+            println!("{}", base58_tx);
+        }
+        _ => {
+            return Err(anyhow::anyhow!("Invalid output format. Use 'execute' or 'base58'"));
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_update_delegate_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, stake_pool: String, index: u8, delegate: String, output: String, manager: Option<String>, keypair_option: Option<String>) -> Result<()> {
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+    let new_delegate_pubkey = Pubkey::from_str(&delegate).context("Invalid delegate address")?;
+
+    let (delegation_pda, _) = Pubkey::find_program_address(
+        &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), &[index]],
+        program_id,
+    );
+
+    match output.as_str() {
+        "execute" => {
+            let keypair_path = keypair_option.context("Keypair path is required for execute mode")?;
+            let keypair = read_keypair_file(&keypair_path)
+                .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+            let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+            let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+            let program = client.program(*program_id)?;
+
+            let signature = program
+                .request()
+                .accounts(validator_blacklist::accounts::UpdateDelegate {
+                    config: config_pubkey,
+                    delegation: delegation_pda,
+                    stake_pool: stake_pool_pubkey,
+                    manager: keypair.pubkey(),
+                    new_delegate: new_delegate_pubkey,
+                    event_authority: event_authority_address(program_id),
+                    program: *program_id,
+                })
+                .args(validator_blacklist::instruction::UpdateDelegate {})
+                .send()?;
+
+            println!("UpdateDelegate transaction sent: {}", signature);
+        }
+        "base58" => {
+            let manager_pubkey = manager.context("Manager pubkey is required when output is base58")?;
+            let manager_pubkey = Pubkey::from_str(&manager_pubkey).context("Invalid manager pubkey")?;
+
+            let dummy_keypair = Keypair::new();
+            let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+            let client = Client::new_with_options(cluster, Rc::new(dummy_keypair), CommitmentConfig::confirmed());
+            let program = client.program(*program_id)?;
+
+            let ixs = program
+                .request()
+                .accounts(validator_blacklist::accounts::UpdateDelegate {
+                    config: config_pubkey,
+                    delegation: delegation_pda,
+                    stake_pool: stake_pool_pubkey,
+                    manager: manager_pubkey,
+                    new_delegate: new_delegate_pubkey,
+                    event_authority: event_authority_address(program_id),
+                    program: *program_id,
+                })
+                .args(validator_blacklist::instruction::UpdateDelegate {})
+                .instructions()?;
+
+            let tx = Transaction::new_with_payer(&ixs, Some(&manager_pubkey));
+            let serialized = bincode::serialize(&tx).context("Failed to serialize transaction")?;
+            let base58_tx = bs58::encode(serialized).into_string();
+
+            println!("{}", base58_tx);
+        }
+        _ => {
+            return Err(anyhow::anyhow!("Invalid output format. Use 'execute' or 'base58'"));
+        }
+    }
+
+    Ok(())
+}
+
+fn fetch_delegation(rpc_url: &str, delegation: &Pubkey) -> Result<Delegation> {
+    let rpc_client = RpcClient::new(rpc_url.to_string());
+    let delegation_data = rpc_client.get_account_data(delegation)
+        .context("Failed to fetch the delegation account")?;
+
+    Ok(Delegation::try_deserialize(&mut delegation_data.as_slice())?)
+}
+
+/// The manager who created the delegation, if it exists. Overwriting a stale delegation refunds
+/// its rent to that manager.
+fn fetch_delegation_manager(rpc_url: &str, delegation: &Pubkey) -> Result<Option<Pubkey>> {
+    let rpc_client = RpcClient::new(rpc_url.to_string());
+    let delegation_account = rpc_client.get_account_with_commitment(delegation, CommitmentConfig::confirmed())
+        .context("Failed to fetch the delegation account")?
+        .value;
+
+    delegation_account
+        .map(|account| Ok(Delegation::try_deserialize(&mut account.data.as_slice())?.manager))
+        .transpose()
+}
+
+fn handle_close_stale_delegation_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, stake_pool: String, index: u8, keypair_option: Option<String>) -> Result<()> {
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+
+    let (delegation_pda, _) = Pubkey::find_program_address(
+        &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), &[index]],
+        program_id,
+    );
+
+    // The rent goes back to the manager who created the delegation
+    let delegation = fetch_delegation(rpc_url, &delegation_pda)?;
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .accounts(validator_blacklist::accounts::CloseStaleDelegation {
+            config: config_pubkey,
+            delegation: delegation_pda,
+            stake_pool: stake_pool_pubkey,
+            manager: delegation.manager,
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::CloseStaleDelegation {})
+        .send()?;
+
+    println!("CloseStaleDelegation transaction sent: {}", signature);
+
+    Ok(())
+}
+
+fn handle_close_expired_delegation_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, stake_pool: String, index: u8, keypair_option: Option<String>) -> Result<()> {
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+
+    let (delegation_pda, _) = Pubkey::find_program_address(
+        &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), &[index]],
+        program_id,
+    );
+
+    // The rent goes back to the manager who created the delegation
+    let delegation = fetch_delegation(rpc_url, &delegation_pda)?;
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .accounts(validator_blacklist::accounts::CloseExpiredDelegation {
+            config: config_pubkey,
+            delegation: delegation_pda,
+            stake_pool: stake_pool_pubkey,
+            manager: delegation.manager,
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::CloseExpiredDelegation {})
+        .send()?;
+
+    println!("CloseExpiredDelegation transaction sent: {}", signature);
+
+    Ok(())
+}
+
+/// Parse delegation permission names into the `Delegation::PERMISSION_*` bitmask
+fn parse_permissions(names: &[String]) -> Result<u8> {
+    names.iter().try_fold(0, |permissions, name| {
+        let permission = match name.as_str() {
+            "vote-add" => Delegation::PERMISSION_VOTE_ADD,
+            "unvote-add" => Delegation::PERMISSION_UNVOTE_ADD,
+            "vote-remove" => Delegation::PERMISSION_VOTE_REMOVE,
+            "unvote-remove" => Delegation::PERMISSION_UNVOTE_REMOVE,
+            "all" => Delegation::PERMISSION_ALL,
+            _ => return Err(anyhow::anyhow!("Invalid permission '{}'. Use vote-add, unvote-add, vote-remove, unvote-remove or all", name)),
+        };
+        Ok(permissions | permission)
+    })
+}
+
+/// Parse a reason category name into a `ReasonCategory`
+fn parse_category(name: &str) -> Result<ReasonCategory> {
+    match name.trim() {
+        "sandwiching" => Ok(ReasonCategory::Sandwiching),
+        "delinquency" => Ok(ReasonCategory::Delinquency),
+        "commission-rug" => Ok(ReasonCategory::CommissionRug),
+        "censorship" => Ok(ReasonCategory::Censorship),
+        "other" => Ok(ReasonCategory::Other),
+        _ => Err(anyhow::anyhow!("Invalid category '{}'. Use sandwiching, delinquency, commission-rug, censorship or other", name)),
+    }
+}
+
+/// Parse an evidence reference from a hex encoded 32-byte hash and a URI, both or neither must be given
+fn parse_evidence(hash: Option<&str>, uri: Option<&str>) -> Result<Option<Evidence>> {
+    let (hash, uri) = match (hash, uri) {
+        (Some(hash), Some(uri)) => (hash.trim(), uri.trim()),
+        (None, None) => return Ok(None),
+        _ => return Err(anyhow::anyhow!("Evidence needs both a hash and a URI")),
+    };
+
+    if hash.len() != 64 || !hash.is_ascii() {
+        return Err(anyhow::anyhow!("Invalid evidence hash '{}': expected 64 hex characters", hash));
+    }
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hash[i * 2..i * 2 + 2], 16)
+            .context(format!("Invalid evidence hash '{}': expected 64 hex characters", hash))?;
+    }
+
+    Ok(Some(Evidence { hash: bytes, uri: uri.to_string() }))
+}
+
+/// Parse an authority policy name as used by --authority-policy
+fn parse_authority_policy(name: &str) -> Result<AuthorityPolicy> {
+    match name.trim() {
+        "manager-only" => Ok(AuthorityPolicy::ManagerOnly),
+        "staker-only" => Ok(AuthorityPolicy::StakerOnly),
+        "either" => Ok(AuthorityPolicy::Either),
+        _ => Err(anyhow::anyhow!("Invalid authority policy '{}'. Use manager-only, staker-only or either", name)),
+    }
+}
+
+/// Parse a pool adapter name as used by --allowed-programs
+fn parse_pool_adapter(name: &str) -> Result<PoolAdapter> {
+    match name.trim() {
+        "spl-stake-pool" => Ok(PoolAdapter::SplStakePool),
+        "marinade" => Ok(PoolAdapter::Marinade),
+        _ => Err(anyhow::anyhow!("Invalid pool adapter '{}'. Use spl-stake-pool or marinade", name)),
+    }
+}
+
+/// Parse allowed program entries of the form PROGRAM_ID[:MIN_TVL[:ADAPTER]], MIN_TVL may be empty
+fn parse_allowed_programs(entries: &[String]) -> Result<Vec<AllowedProgram>> {
+    entries
+        .iter()
+        .map(|entry| {
+            let mut parts = entry.splitn(3, ':');
+            let program = parts.next().unwrap_or_default();
+            let min_tvl = parts.next().filter(|min_tvl| !min_tvl.trim().is_empty());
+            let adapter = parts.next();
+
+            let program_id = Pubkey::from_str(program.trim())
+                .context(format!("Invalid program address: {}", program))?;
+            let min_tvl = min_tvl
+                .map(|min_tvl| min_tvl.trim().parse::<u64>().context(format!("Invalid minimum TVL for program {}: {}", program, min_tvl)))
+                .transpose()?;
+            let adapter = adapter
+                .map(parse_pool_adapter)
+                .transpose()?
+                .unwrap_or_default();
+
+            Ok(AllowedProgram { program_id, min_tvl, adapter })
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_create_config_command(rpc_url: &str, program_id: &Pubkey, namespace: String, min_tvl: u64, allowed_programs: Vec<String>, min_add_votes: u64, min_net_votes: u64, keypair_option: Option<String>) -> Result<()> {
+    let allowed_programs = parse_allowed_programs(&allowed_programs)?;
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let (config_pda, _) = Pubkey::find_program_address(
+        &[b"config", namespace.as_bytes()],
+        program_id,
+    );
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+    let signature = program
+        .request()
+        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
+        .accounts(validator_blacklist::accounts::InitConfig {
+            config: config_pda,
+            admin: keypair.pubkey(),
+            system_program: system_program::id(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::InitConfig {
+            namespace,
+            min_tvl,
+            allowed_programs,
+            min_add_votes,
+            min_net_votes,
+        })
+        .send()?;
+
+    println!("CreateConfig transaction sent: {}", signature);
+    println!("Config account: {}", config_pda);
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_update_config_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, min_tvl: Option<u64>, allowed_programs: Option<Vec<String>>, min_add_votes: Option<u64>, min_net_votes: Option<u64>, vote_lifetime: Option<i64>, authority_policy: Option<AuthorityPolicy>, keypair_option: Option<String>) -> Result<()> {
+
+    let allowed_programs = allowed_programs
+        .map(|programs| parse_allowed_programs(&programs))
+        .transpose()?;
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
+        .accounts(validator_blacklist::accounts::UpdateConfig {
+            config: config_pubkey,
+            admin: keypair.pubkey(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::UpdateConfig {
+            min_tvl,
+            allowed_programs,
+            min_add_votes,
+            min_net_votes,
+            vote_lifetime,
+            authority_policy,
+        })
+        .send()?;
+
+    println!("UpdateConfig transaction sent: {}", signature);
+
+    Ok(())
+}
+
+fn handle_resize_config_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, capacity: u16, keypair_option: Option<String>) -> Result<()> {
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .accounts(validator_blacklist::accounts::ResizeConfig {
+            config: config_pubkey,
+            admin: keypair.pubkey(),
+            system_program: system_program::id(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::ResizeConfig { capacity })
+        .send()?;
+
+    println!("ResizeConfig transaction sent: {}", signature);
+
+    Ok(())
+}
+
+fn handle_set_paused_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, paused: bool, keypair_option: Option<String>) -> Result<()> {
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .accounts(validator_blacklist::accounts::SetPaused {
+            config: config_pubkey,
+            admin: keypair.pubkey(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::SetPaused { paused })
+        .send()?;
+
+    println!("SetPaused transaction sent: {}", signature);
+
+    Ok(())
+}
+
+fn handle_set_pool_override_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, stake_pool: String, kind: PoolOverrideKind, keypair_option: Option<String>) -> Result<()> {
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .accounts(validator_blacklist::accounts::SetPoolOverride {
+            config: config_pubkey,
+            stake_pool: stake_pool_pubkey,
+            pool_override: pool_override_address(program_id, &config_pubkey, &stake_pool_pubkey),
+            admin: keypair.pubkey(),
+            system_program: system_program::id(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::SetPoolOverride { kind })
+        .send()?;
+
+    println!("SetPoolOverride transaction sent: {}", signature);
+
+    Ok(())
+}
+
+fn handle_remove_pool_override_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, stake_pool: String, keypair_option: Option<String>) -> Result<()> {
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .accounts(validator_blacklist::accounts::RemovePoolOverride {
+            config: config_pubkey,
+            pool_override: pool_override_address(program_id, &config_pubkey, &stake_pool_pubkey),
+            admin: keypair.pubkey(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::RemovePoolOverride {})
+        .send()?;
+
+    println!("RemovePoolOverride transaction sent: {}", signature);
+
+    Ok(())
+}
+
+fn handle_propose_admin_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, new_admin: String, keypair_option: Option<String>) -> Result<()> {
+    let new_admin_pubkey = Pubkey::from_str(&new_admin).context("Invalid new admin address")?;
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
+        .accounts(validator_blacklist::accounts::ProposeAdmin {
+            config: config_pubkey,
+            admin: keypair.pubkey(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::ProposeAdmin {
+            new_admin: new_admin_pubkey,
+        })
+        .send()?;
+
+    println!("ProposeAdmin transaction sent: {}", signature);
+    println!("{} has to run accept-admin to take over the config", new_admin_pubkey);
+
+    Ok(())
+}
+
+fn handle_accept_admin_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, keypair_option: Option<String>) -> Result<()> {
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .accounts(validator_blacklist::accounts::AcceptAdmin {
+            config: config_pubkey,
+            pending_admin: keypair.pubkey(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::AcceptAdmin {})
+        .send()?;
+
+    println!("AcceptAdmin transaction sent: {}", signature);
+
+    Ok(())
+}
+
+fn handle_cancel_admin_proposal_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, keypair_option: Option<String>) -> Result<()> {
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .accounts(validator_blacklist::accounts::CancelAdminProposal {
+            config: config_pubkey,
+            admin: keypair.pubkey(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::CancelAdminProposal {})
+        .send()?;
+
+    println!("CancelAdminProposal transaction sent: {}", signature);
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_vote_add_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, stake_pool: String, reason: String, category: ReasonCategory, evidence: Option<Evidence>, vote_account: Option<String>, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
+    let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+    let vote_account_pubkey = vote_account.as_ref()
+        .map(|vote_account| Pubkey::from_str(vote_account).context("Invalid vote account address"))
+        .transpose()?;
+    let delegation_pubkey = if let Some(del) = delegation {
+        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
+    } else {
+        None
+    };
+
+    let (blacklist_pda, _) = Pubkey::find_program_address(
+        &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
+        program_id,
+    );
+
+    let (vote_add_pda, _) = Pubkey::find_program_address(
+        &[b"vote_add", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
+        program_id,
+    );
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
+        .accounts(validator_blacklist::accounts::VoteAdd {
+            config: config_pubkey,
+            stake_pool: stake_pool_pubkey,
+            pool_override: pool_override_address(program_id, &config_pubkey, &stake_pool_pubkey),
+            blacklist: blacklist_pda,
+            vote_add: vote_add_pda,
+            vote_remove: vote_address(program_id, &config_pubkey, &stake_pool_pubkey, &validator_pubkey, true),
+            validator_vote_account: vote_account_pubkey,
+            delegation: delegation_pubkey,
+            authority: keypair.pubkey(),
+            system_program: system_program::id(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::VoteAdd {
+            validator_identity_address: validator_pubkey,
+            reason,
+            category,
+            evidence,
+        })
+        .send()?;
+
+    println!("Vote to add transaction sent: {}", signature);
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_vote_remove_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, stake_pool: String, reason: String, category: ReasonCategory, evidence: Option<Evidence>, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
+    let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+    let delegation_pubkey = if let Some(del) = delegation {
+        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
+    } else {
+        None
+    };
+
+    let (blacklist_pda, _) = Pubkey::find_program_address(
+        &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
+        program_id,
+    );
+
+    let (vote_remove_pda, _) = Pubkey::find_program_address(
+        &[b"vote_remove", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
+        program_id,
+    );
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
+        .accounts(validator_blacklist::accounts::VoteRemove {
+            config: config_pubkey,
+            stake_pool: stake_pool_pubkey,
+            pool_override: pool_override_address(program_id, &config_pubkey, &stake_pool_pubkey),
+            blacklist: blacklist_pda,
+            vote_remove: vote_remove_pda,
+            vote_add: vote_address(program_id, &config_pubkey, &stake_pool_pubkey, &validator_pubkey, false),
+            delegation: delegation_pubkey,
+            authority: keypair.pubkey(),
+            system_program: system_program::id(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::VoteRemove {
+            validator_identity_address: validator_pubkey,
+            reason,
+            category,
+            evidence,
+        })
+        .send()?;
+
+    println!("Vote to remove transaction sent: {}", signature);
+
+    Ok(())
+}
+
+fn handle_unvote_add_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, stake_pool: String, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
+    let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+    let delegation_pubkey = if let Some(del) = delegation {
+        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
+    } else {
+        None
+    };
+
+    let (blacklist_pda, _) = Pubkey::find_program_address(
+        &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
+        program_id,
+    );
+
+    let (vote_add_pda, _) = Pubkey::find_program_address(
+        &[b"vote_add", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
+        program_id,
+    );
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
+        .accounts(validator_blacklist::accounts::UnvoteAdd {
+            config: config_pubkey,
+            stake_pool: stake_pool_pubkey,
+            blacklist: blacklist_pda,
+            vote_add: vote_add_pda,
+            delegation: delegation_pubkey,
+            authority: keypair.pubkey(),
+            payer: fetch_vote_payer(rpc_url, &vote_add_pda, false)?,
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::UnvoteAdd {
+            validator_identity_address: validator_pubkey,
+        })
+        .send()?;
+
+    println!("Unvote add transaction sent: {}", signature);
+
+    Ok(())
+}
+
+fn handle_unvote_remove_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, stake_pool: String, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
+    let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+    let delegation_pubkey = if let Some(del) = delegation {
+        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
+    } else {
+        None
+    };
+
+    let (blacklist_pda, _) = Pubkey::find_program_address(
+        &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
+        program_id,
+    );
+
+    let (vote_remove_pda, _) = Pubkey::find_program_address(
+        &[b"vote_remove", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
+        program_id,
+    );
+
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
+        .accounts(validator_blacklist::accounts::UnvoteRemove {
+            config: config_pubkey,
+            stake_pool: stake_pool_pubkey,
+            blacklist: blacklist_pda,
+            vote_remove: vote_remove_pda,
+            delegation: delegation_pubkey,
+            authority: keypair.pubkey(),
+            payer: fetch_vote_payer(rpc_url, &vote_remove_pda, true)?,
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::UnvoteRemove {
+            validator_identity_address: validator_pubkey,
+        })
+        .send()?;
+
+    println!("Unvote remove transaction sent: {}", signature);
 
     Ok(())
 }
 
-fn handle_create_config_command(rpc_url: &str, program_id: &Pubkey, config: String, min_tvl: u64, allowed_programs: Vec<String>, keypair_option: Option<String>) -> Result<()> {
-    let allowed_program_pubkeys: Result<Vec<Pubkey>> = allowed_programs
-        .iter()
-        .map(|p| Pubkey::from_str(p).context(format!("Invalid program address: {}", p)))
-        .collect();
-    let allowed_program_pubkeys = allowed_program_pubkeys?;
+#[allow(clippy::too_many_arguments)]
+fn handle_change_vote_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, stake_pool: String, reason: String, remove: bool, category: ReasonCategory, evidence: Option<Evidence>, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
+    let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+    let delegation_pubkey = if let Some(del) = delegation {
+        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
+    } else {
+        None
+    };
+
+    let (blacklist_pda, _) = Pubkey::find_program_address(
+        &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
+        program_id,
+    );
+
+    // Changing to a remove vote closes the add vote and the reverse, its rent goes back to whoever paid for it
+    let old_vote_pda = vote_address(program_id, &config_pubkey, &stake_pool_pubkey, &validator_pubkey, !remove);
+    let payer = fetch_vote_payer(rpc_url, &old_vote_pda, !remove)?;
+    let direction = if remove { VoteDirection::Remove } else { VoteDirection::Add };
 
     let keypair_path = keypair_option.context("Keypair path is required")?;
     let keypair = read_keypair_file(&keypair_path)
         .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
 
-    let config_keypair = read_keypair_file(&config)
-        .map_err(|e| anyhow::anyhow!("Failed to read config keypair file: {}", e))?;
-
     let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
     let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
     let program = client.program(*program_id)?;
+
     let signature = program
         .request()
         .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
-        .signer(&config_keypair)
-        .accounts(validator_blacklist::accounts::InitConfig {
-            config: config_keypair.pubkey(),
-            admin: keypair.pubkey(),
+        .accounts(validator_blacklist::accounts::ChangeVote {
+            config: config_pubkey,
+            stake_pool: stake_pool_pubkey,
+            pool_override: pool_override_address(program_id, &config_pubkey, &stake_pool_pubkey),
+            blacklist: blacklist_pda,
+            vote_add: vote_address(program_id, &config_pubkey, &stake_pool_pubkey, &validator_pubkey, false),
+            vote_remove: vote_address(program_id, &config_pubkey, &stake_pool_pubkey, &validator_pubkey, true),
+            delegation: delegation_pubkey,
+            authority: keypair.pubkey(),
+            payer,
             system_program: system_program::id(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
         })
-        .args(validator_blacklist::instruction::InitConfig {
-            min_tvl,
-            allowed_programs: allowed_program_pubkeys,
+        .args(validator_blacklist::instruction::ChangeVote {
+            validator_identity_address: validator_pubkey,
+            direction,
+            reason,
+            category,
+            evidence,
         })
         .send()?;
 
-    println!("CreateConfig transaction sent: {}", signature);
-    println!("Config account: {}", config_keypair.pubkey());
+    println!("Change vote transaction sent: {}", signature);
 
     Ok(())
 }
 
-fn handle_update_config_command(rpc_url: &str, program_id: &Pubkey, config: String, min_tvl: Option<u64>, allowed_programs: Option<Vec<String>>, keypair_option: Option<String>) -> Result<()> {
-    let config_pubkey = Pubkey::from_str(&config).context("Invalid config address")?;
+/// Address of the add vote, or of the remove vote if `remove` is set
+fn vote_address(program_id: &Pubkey, config: &Pubkey, stake_pool: &Pubkey, validator: &Pubkey, remove: bool) -> Pubkey {
+    let seed: &[u8] = if remove { b"vote_remove" } else { b"vote_add" };
+    Pubkey::find_program_address(
+        &[seed, config.as_ref(), stake_pool.as_ref(), validator.as_ref()],
+        program_id,
+    ).0
+}
+
+/// Fetch who paid for a vote, which is where the rent goes when the vote is closed by a crank
+fn fetch_vote_payer(rpc_url: &str, vote: &Pubkey, remove: bool) -> Result<Pubkey> {
+    let rpc_client = RpcClient::new(rpc_url.to_string());
+    let vote_data = rpc_client.get_account_data(vote)
+        .context("Failed to fetch the vote account")?;
 
-    let allowed_program_pubkeys = if let Some(programs) = allowed_programs {
-        let pubkeys: Result<Vec<Pubkey>> = programs
-            .iter()
-            .map(|p| Pubkey::from_str(p).context(format!("Invalid program address: {}", p)))
-            .collect();
-        Some(pubkeys?)
+    let payer = if remove {
+        VoteRemoveFromBlacklist::try_deserialize(&mut vote_data.as_slice())?.payer
+    } else {
+        VoteAddToBlacklist::try_deserialize(&mut vote_data.as_slice())?.payer
+    };
+
+    Ok(payer)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_reaffirm_vote_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, stake_pool: String, remove: bool, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
+    let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+    let delegation_pubkey = if let Some(del) = delegation {
+        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
     } else {
         None
     };
 
+    let vote_pda = vote_address(program_id, &config_pubkey, &stake_pool_pubkey, &validator_pubkey, remove);
+
     let keypair_path = keypair_option.context("Keypair path is required")?;
     let keypair = read_keypair_file(&keypair_path)
         .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
@@ -319,25 +1391,40 @@ fn handle_update_config_command(rpc_url: &str, program_id: &Pubkey, config: Stri
 
     let signature = program
         .request()
-        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
-        .accounts(validator_blacklist::accounts::UpdateConfig {
+        .accounts(validator_blacklist::accounts::ReaffirmVote {
             config: config_pubkey,
-            admin: keypair.pubkey(),
+            stake_pool: stake_pool_pubkey,
+            vote_add: (!remove).then_some(vote_pda),
+            vote_remove: remove.then_some(vote_pda),
+            delegation: delegation_pubkey,
+            authority: keypair.pubkey(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
         })
-        .args(validator_blacklist::instruction::UpdateConfig {
-            min_tvl,
-            allowed_programs: allowed_program_pubkeys,
+        .args(validator_blacklist::instruction::ReaffirmVote {
+            validator_identity_address: validator_pubkey,
         })
         .send()?;
 
-    println!("UpdateConfig transaction sent: {}", signature);
+    println!("Reaffirm vote transaction sent: {}", signature);
 
     Ok(())
 }
 
-fn handle_update_config_admin_command(rpc_url: &str, program_id: &Pubkey, config: String, new_admin: String, keypair_option: Option<String>) -> Result<()> {
-    let config_pubkey = Pubkey::from_str(&config).context("Invalid config address")?;
-    let new_admin_pubkey = Pubkey::from_str(&new_admin).context("Invalid new admin address")?;
+#[allow(clippy::too_many_arguments)]
+fn handle_edit_reason_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, stake_pool: String, reason: String, remove: bool, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
+    let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
+    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
+    let delegation_pubkey = if let Some(del) = delegation {
+        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
+    } else {
+        None
+    };
+
+    let vote_pda = vote_address(program_id, &config_pubkey, &stake_pool_pubkey, &validator_pubkey, remove);
+
+    // Excess rent of a shorter reason goes back to whoever paid for the vote
+    let payer = fetch_vote_payer(rpc_url, &vote_pda, remove)?;
 
     let keypair_path = keypair_option.context("Keypair path is required")?;
     let keypair = read_keypair_file(&keypair_path)
@@ -349,47 +1436,41 @@ fn handle_update_config_admin_command(rpc_url: &str, program_id: &Pubkey, config
 
     let signature = program
         .request()
-        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
-        .accounts(validator_blacklist::accounts::UpdateConfigAdmin {
+        .accounts(validator_blacklist::accounts::EditReason {
             config: config_pubkey,
-            admin: keypair.pubkey(),
+            stake_pool: stake_pool_pubkey,
+            vote_add: (!remove).then_some(vote_pda),
+            vote_remove: remove.then_some(vote_pda),
+            delegation: delegation_pubkey,
+            authority: keypair.pubkey(),
+            payer,
+            system_program: system_program::id(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
         })
-        .args(validator_blacklist::instruction::UpdateConfigAdmin {
-            new_admin: new_admin_pubkey,
+        .args(validator_blacklist::instruction::EditReason {
+            validator_identity_address: validator_pubkey,
+            reason,
         })
         .send()?;
 
-    println!("UpdateConfigAdmin transaction sent: {}", signature);
+    println!("Edit reason transaction sent: {}", signature);
 
     Ok(())
 }
 
-fn handle_vote_add_command(rpc_url: &str, program_id: &Pubkey, config: String, validator_address: String, stake_pool: String, reason: String, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
-    let config_pubkey = Pubkey::from_str(&config).context("Invalid config address")?;
+fn handle_expire_vote_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, stake_pool: String, remove: bool, keypair_option: Option<String>) -> Result<()> {
     let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
     let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
-    let delegation_pubkey = if let Some(del) = delegation {
-        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
-    } else {
-        None
-    };
 
     let (blacklist_pda, _) = Pubkey::find_program_address(
         &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
         program_id,
     );
 
-    let (vote_add_pda, _) = Pubkey::find_program_address(
-        &[b"vote_add", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
-        program_id,
-    );
+    let vote_pda = vote_address(program_id, &config_pubkey, &stake_pool_pubkey, &validator_pubkey, remove);
 
-    let delegation_pda = delegation_pubkey.map(|_| {
-        Pubkey::find_program_address(
-            &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref()],
-            program_id,
-        ).0
-    });
+    let payer = fetch_vote_payer(rpc_url, &vote_pda, remove)?;
 
     let keypair_path = keypair_option.context("Keypair path is required")?;
     let keypair = read_keypair_file(&keypair_path)
@@ -401,53 +1482,37 @@ fn handle_vote_add_command(rpc_url: &str, program_id: &Pubkey, config: String, v
 
     let signature = program
         .request()
-        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
-        .accounts(validator_blacklist::accounts::VoteAdd {
+        .accounts(validator_blacklist::accounts::ExpireVote {
             config: config_pubkey,
             stake_pool: stake_pool_pubkey,
             blacklist: blacklist_pda,
-            vote_add: vote_add_pda,
-            delegation: delegation_pda,
-            authority: keypair.pubkey(),
-            system_program: system_program::id(),
+            vote_add: (!remove).then_some(vote_pda),
+            vote_remove: remove.then_some(vote_pda),
+            payer,
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
         })
-        .args(validator_blacklist::instruction::VoteAdd {
+        .args(validator_blacklist::instruction::ExpireVote {
             validator_identity_address: validator_pubkey,
-            reason,
         })
         .send()?;
 
-    println!("Vote to add transaction sent: {}", signature);
+    println!("Expire vote transaction sent: {}", signature);
 
     Ok(())
 }
 
-fn handle_vote_remove_command(rpc_url: &str, program_id: &Pubkey, config: String, validator_address: String, stake_pool: String, reason: String, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
-    let config_pubkey = Pubkey::from_str(&config).context("Invalid config address")?;
+fn handle_prune_vote_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, stake_pool: String, remove: bool, keypair_option: Option<String>) -> Result<()> {
     let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
     let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
-    let delegation_pubkey = if let Some(del) = delegation {
-        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
-    } else {
-        None
-    };
 
     let (blacklist_pda, _) = Pubkey::find_program_address(
         &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
         program_id,
     );
 
-    let (vote_remove_pda, _) = Pubkey::find_program_address(
-        &[b"vote_remove", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
-        program_id,
-    );
-
-    let delegation_pda = delegation_pubkey.map(|_| {
-        Pubkey::find_program_address(
-            &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref()],
-            program_id,
-        ).0
-    });
+    let vote_pda = vote_address(program_id, &config_pubkey, &stake_pool_pubkey, &validator_pubkey, remove);
+    let payer = fetch_vote_payer(rpc_url, &vote_pda, remove)?;
 
     let keypair_path = keypair_option.context("Keypair path is required")?;
     let keypair = read_keypair_file(&keypair_path)
@@ -459,53 +1524,39 @@ fn handle_vote_remove_command(rpc_url: &str, program_id: &Pubkey, config: String
 
     let signature = program
         .request()
-        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
-        .accounts(validator_blacklist::accounts::VoteRemove {
+        .accounts(validator_blacklist::accounts::PruneVote {
             config: config_pubkey,
             stake_pool: stake_pool_pubkey,
+            pool_override: pool_override_address(program_id, &config_pubkey, &stake_pool_pubkey),
             blacklist: blacklist_pda,
-            vote_remove: vote_remove_pda,
-            delegation: delegation_pda,
-            authority: keypair.pubkey(),
-            system_program: system_program::id(),
+            vote_add: (!remove).then_some(vote_pda),
+            vote_remove: remove.then_some(vote_pda),
+            payer,
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
         })
-        .args(validator_blacklist::instruction::VoteRemove {
+        .args(validator_blacklist::instruction::PruneVote {
             validator_identity_address: validator_pubkey,
-            reason,
         })
         .send()?;
 
-    println!("Vote to remove transaction sent: {}", signature);
+    println!("Prune vote transaction sent: {}", signature);
 
     Ok(())
 }
 
-fn handle_unvote_add_command(rpc_url: &str, program_id: &Pubkey, config: String, validator_address: String, stake_pool: String, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
-    let config_pubkey = Pubkey::from_str(&config).context("Invalid config address")?;
+fn handle_close_blacklist_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, keypair_option: Option<String>) -> Result<()> {
     let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
-    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
-    let delegation_pubkey = if let Some(del) = delegation {
-        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
-    } else {
-        None
-    };
 
     let (blacklist_pda, _) = Pubkey::find_program_address(
         &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
         program_id,
     );
 
-    let (vote_add_pda, _) = Pubkey::find_program_address(
-        &[b"vote_add", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
-        program_id,
-    );
-
-    let delegation_pda = delegation_pubkey.map(|_| {
-        Pubkey::find_program_address(
-            &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref()],
-            program_id,
-        ).0
-    });
+    let rpc_client = RpcClient::new(rpc_url.to_string());
+    let blacklist_data = rpc_client.get_account_data(&blacklist_pda)
+        .context("Failed to fetch the blacklist account")?;
+    let blacklist = Blacklist::try_deserialize(&mut blacklist_data.as_slice())?;
 
     let keypair_path = keypair_option.context("Keypair path is required")?;
     let keypair = read_keypair_file(&keypair_path)
@@ -517,51 +1568,65 @@ fn handle_unvote_add_command(rpc_url: &str, program_id: &Pubkey, config: String,
 
     let signature = program
         .request()
-        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
-        .accounts(validator_blacklist::accounts::UnvoteAdd {
+        .accounts(validator_blacklist::accounts::CloseBlacklist {
             config: config_pubkey,
-            stake_pool: stake_pool_pubkey,
             blacklist: blacklist_pda,
-            vote_add: vote_add_pda,
-            delegation: delegation_pda,
-            authority: keypair.pubkey(),
+            payer: blacklist.payer,
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
         })
-        .args(validator_blacklist::instruction::UnvoteAdd {
+        .args(validator_blacklist::instruction::CloseBlacklist {
             validator_identity_address: validator_pubkey,
         })
         .send()?;
 
-    println!("Unvote add transaction sent: {}", signature);
+    println!("Close blacklist transaction sent: {}", signature);
 
     Ok(())
 }
 
-fn handle_unvote_remove_command(rpc_url: &str, program_id: &Pubkey, config: String, validator_address: String, stake_pool: String, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
-    let config_pubkey = Pubkey::from_str(&config).context("Invalid config address")?;
+fn handle_veto_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, reason: String, keypair_option: Option<String>) -> Result<()> {
     let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
-    let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
-    let delegation_pubkey = if let Some(del) = delegation {
-        Some(Pubkey::from_str(&del).context("Invalid delegation address")?)
-    } else {
-        None
-    };
 
-    let (blacklist_pda, _) = Pubkey::find_program_address(
-        &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
-        program_id,
-    );
+    let keypair_path = keypair_option.context("Keypair path is required")?;
+    let keypair = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
 
-    let (vote_remove_pda, _) = Pubkey::find_program_address(
-        &[b"vote_remove", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
-        program_id,
-    );
+    let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
+    let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
+    let program = client.program(*program_id)?;
+
+    let signature = program
+        .request()
+        .accounts(validator_blacklist::accounts::VetoBlacklist {
+            config: config_pubkey,
+            blacklist: blacklist_address(program_id, &config_pubkey, &validator_pubkey),
+            veto: veto_address(program_id, &config_pubkey, &validator_pubkey),
+            admin: keypair.pubkey(),
+            system_program: system_program::id(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
+        })
+        .args(validator_blacklist::instruction::Veto {
+            validator_identity_address: validator_pubkey,
+            reason,
+        })
+        .send()?;
+
+    println!("Veto transaction sent: {}", signature);
+
+    Ok(())
+}
+
+fn handle_lift_veto_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, validator_address: String, keypair_option: Option<String>) -> Result<()> {
+    let validator_pubkey = Pubkey::from_str(&validator_address).context("Invalid validator address")?;
+    let veto_pda = veto_address(program_id, &config_pubkey, &validator_pubkey);
 
-    let delegation_pda = delegation_pubkey.map(|_| {
-        Pubkey::find_program_address(
-            &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref()],
-            program_id,
-        ).0
-    });
+    // The rent of the veto goes back to the admin who vetoed
+    let rpc_client = RpcClient::new(rpc_url.to_string());
+    let veto_data = rpc_client.get_account_data(&veto_pda)
+        .context("Failed to fetch the veto account")?;
+    let veto = Veto::try_deserialize(&mut veto_data.as_slice())?;
 
     let keypair_path = keypair_option.context("Keypair path is required")?;
     let keypair = read_keypair_file(&keypair_path)
@@ -573,35 +1638,66 @@ fn handle_unvote_remove_command(rpc_url: &str, program_id: &Pubkey, config: Stri
 
     let signature = program
         .request()
-        .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
-        .accounts(validator_blacklist::accounts::UnvoteRemove {
+        .accounts(validator_blacklist::accounts::LiftVeto {
             config: config_pubkey,
-            stake_pool: stake_pool_pubkey,
-            blacklist: blacklist_pda,
-            vote_remove: vote_remove_pda,
-            delegation: delegation_pda,
-            authority: keypair.pubkey(),
+            blacklist: blacklist_address(program_id, &config_pubkey, &validator_pubkey),
+            veto: veto_pda,
+            veto_admin: veto.admin,
+            admin: keypair.pubkey(),
+            event_authority: event_authority_address(program_id),
+            program: *program_id,
         })
-        .args(validator_blacklist::instruction::UnvoteRemove {
+        .args(validator_blacklist::instruction::LiftVeto {
             validator_identity_address: validator_pubkey,
         })
         .send()?;
 
-    println!("Unvote remove transaction sent: {}", signature);
+    println!("Lift veto transaction sent: {}", signature);
 
     Ok(())
 }
 
-fn handle_batch_ban_command(rpc_url: &str, program_id: &Pubkey, config: String, stake_pool: String, csv: String, validators_file: Option<String>, delegation: Option<String>, keypair_option: Option<String>) -> Result<()> {
+/// Address of the blacklist entry of a validator
+fn blacklist_address(program_id: &Pubkey, config: &Pubkey, validator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"blacklist", config.as_ref(), validator.as_ref()],
+        program_id,
+    ).0
+}
+
+/// Address of the admin veto of a validator's blacklist entry
+fn veto_address(program_id: &Pubkey, config: &Pubkey, validator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"veto", config.as_ref(), validator.as_ref()],
+        program_id,
+    ).0
+}
+
+/// A CSV row of `batch-ban`
+#[derive(Clone)]
+struct BatchBanRow {
+    reason: String,
+    category: ReasonCategory,
+    evidence: Option<Evidence>,
+    vote_account: Option<Pubkey>,
+    /// The row as read, written to the failed file if its batch fails
+    record: csv::StringRecord,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_batch_ban_command(rpc_url: &str, program_id: &Pubkey, config_pubkey: Pubkey, stake_pool: String, csv: String, validators_file: Option<String>, delegation: Option<String>, batch_size: usize, failed_file: Option<String>, keypair_option: Option<String>) -> Result<()> {
     use std::fs;
     use std::collections::HashSet;
 
-    let config_pubkey = Pubkey::from_str(&config).context("Invalid config address")?;
     let stake_pool_pubkey = Pubkey::from_str(&stake_pool).context("Invalid stake pool address")?;
 
+    if batch_size == 0 {
+        return Err(anyhow::anyhow!("The batch size must be at least 1"));
+    }
+
     // Read the CSV file
     let mut validator_addresses = Vec::new();
-    let mut csv_reasons = Vec::new();
+    let mut csv_votes = Vec::new();
 
     println!("📖 Reading CSV file: {}", csv);
     let mut rdr = csv::Reader::from_path(&csv)?;
@@ -611,18 +1707,18 @@ fn handle_batch_ban_command(rpc_url: &str, program_id: &Pubkey, config: String,
         let record = result.context("Invalid CSV record")?;
 
         // Skip empty lines
-        if record.len() == 0 || record.get(0).map(|s| s.is_empty()).unwrap_or(true) {
+        if record.is_empty() || record.get(0).map(|s| s.is_empty()).unwrap_or(true) {
             continue;
         }
 
         // Skip header line (check if first field looks like "validator" or similar)
-        if row_count == 0 && (record.get(0).unwrap_or(&"").to_lowercase().contains("validator") ||
-            record.get(0).unwrap_or(&"").to_lowercase().contains("address")) {
+        if row_count == 0 && (record.get(0).unwrap_or("").to_lowercase().contains("validator") ||
+            record.get(0).unwrap_or("").to_lowercase().contains("address")) {
             println!("   ℹ️  Skipping header row");
             continue;
         }
 
-        if record.len() < 1 {
+        if record.is_empty() {
             println!("   ⚠️  Skipping invalid row (expected at least 1 column): {:?}", record);
             continue;
         }
@@ -634,11 +1730,21 @@ fn handle_batch_ban_command(rpc_url: &str, program_id: &Pubkey, config: String,
             .context(format!("Missing reason on row {}: validator {} has no reason provided", row_count + 1, validator_address))?
             .to_string();
 
+        // Category, evidence and vote account are optional trailing columns
+        let non_empty = |index: usize| record.get(index).map(str::trim).filter(|s| !s.is_empty());
+        let category = non_empty(2).map(parse_category).transpose()
+            .context(format!("Invalid category on row {}", row_count + 1))?
+            .unwrap_or_default();
+        let evidence = parse_evidence(non_empty(3), non_empty(4))
+            .context(format!("Invalid evidence on row {}", row_count + 1))?;
+        let vote_account = non_empty(5).map(Pubkey::from_str).transpose()
+            .context(format!("Invalid vote account on row {}", row_count + 1))?;
+
         let validator_pubkey = Pubkey::from_str(validator_address)
             .context(format!("Invalid validator address on row {}: {}", row_count + 1, validator_address))?;
 
         validator_addresses.push(validator_pubkey);
-        csv_reasons.push(csv_reason);
+        csv_votes.push(BatchBanRow { reason: csv_reason, category, evidence, vote_account, record: record.clone() });
         row_count += 1;
     }
 
@@ -656,8 +1762,9 @@ fn handle_batch_ban_command(rpc_url: &str, program_id: &Pubkey, config: String,
 
         let entries = crate::validator_parser::parse_validator_list(&validators_content)?;
         let active_set: HashSet<Pubkey> = entries.iter().map(|e| e.identity).collect();
+        let delinquent_count = entries.iter().filter(|e| e.has_warning).count();
 
-        println!("✅ Loaded {} active validators from list", active_set.len());
+        println!("✅ Loaded {} active validators from list ({} marked delinquent)", active_set.len(), delinquent_count);
         Some(active_set)
     } else {
         None
@@ -665,10 +1772,10 @@ fn handle_batch_ban_command(rpc_url: &str, program_id: &Pubkey, config: String,
 
     // Filter validators to only those in the active list
     let mut filtered_validators = Vec::new();
-    let mut filtered_reasons = Vec::new();
+    let mut filtered_votes = Vec::new();
     let mut skipped_count = 0;
 
-    for (validator_pubkey, reason) in validator_addresses.iter().zip(csv_reasons.iter()) {
+    for (validator_pubkey, vote) in validator_addresses.iter().zip(csv_votes.iter()) {
         if let Some(ref active_set) = active_validators {
             if !active_set.contains(validator_pubkey) {
                 println!("⏭️  Skipping {} (not in active validators list)", validator_pubkey);
@@ -677,7 +1784,7 @@ fn handle_batch_ban_command(rpc_url: &str, program_id: &Pubkey, config: String,
             }
         }
         filtered_validators.push(*validator_pubkey);
-        filtered_reasons.push(reason.clone());
+        filtered_votes.push(vote.clone());
     }
 
     if skipped_count > 0 {
@@ -694,13 +1801,6 @@ fn handle_batch_ban_command(rpc_url: &str, program_id: &Pubkey, config: String,
         .map(|del| Pubkey::from_str(del).context("Invalid delegation address"))
         .transpose()?;
 
-    let delegation_pda = delegation_pubkey.as_ref().map(|_| {
-        Pubkey::find_program_address(
-            &[b"delegation", config_pubkey.as_ref(), stake_pool_pubkey.as_ref()],
-            program_id,
-        ).0
-    });
-
     let keypair_path = keypair_option.context("Keypair path is required for execute mode")?;
     let keypair = read_keypair_file(&keypair_path)
         .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
@@ -708,43 +1808,338 @@ fn handle_batch_ban_command(rpc_url: &str, program_id: &Pubkey, config: String,
     let cluster = Cluster::Custom(rpc_url.to_string(), "none".to_string());
     let client = Client::new_with_options(cluster, Rc::new(keypair.insecure_clone()), CommitmentConfig::confirmed());
     let program = client.program(*program_id)?;
+    let rpc_client = RpcClient::new(rpc_url.to_string());
 
-    println!("Starting batch ban...\n");
+    // A vote that was already cast fails its whole batch, so leave those out up front. This also
+    // lets a run over the same file resume where a previous one stopped. getMultipleAccounts
+    // takes at most 100 addresses, two per validator
+    let mut pending_votes = Vec::new();
+    for chunk in filtered_validators.iter().zip(filtered_votes.iter()).collect::<Vec<_>>().chunks(50) {
+        let vote_addresses: Vec<Pubkey> = chunk.iter()
+            .flat_map(|(validator_pubkey, _)| [
+                vote_address(program_id, &config_pubkey, &stake_pool_pubkey, validator_pubkey, false),
+                vote_address(program_id, &config_pubkey, &stake_pool_pubkey, validator_pubkey, true),
+            ])
+            .collect();
+        let vote_accounts = rpc_client.get_multiple_accounts(&vote_addresses)?;
+
+        for (vote, existing) in chunk.iter().zip(vote_accounts.chunks(2)) {
+            if existing[0].is_some() {
+                println!("⏭️  Skipping {} (already voted to ban)", vote.0);
+            } else if existing[1].is_some() {
+                println!("⏭️  Skipping {} (voted against banning, use change-vote)", vote.0);
+            } else {
+                pending_votes.push(*vote);
+            }
+        }
+    }
 
-    for (i, (validator_pubkey, ban_reason)) in filtered_validators.iter().zip(filtered_reasons.iter()).enumerate() {
-        let (blacklist_pda, _) = Pubkey::find_program_address(
-            &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
-            program_id,
-        );
+    let vote_add_many_instructions = |batch: &[(&Pubkey, &BatchBanRow)]| -> Result<Vec<Instruction>> {
+        let mut votes = Vec::with_capacity(batch.len());
+        let mut remaining_accounts = Vec::with_capacity(batch.len() * validator_blacklist::instructions::ACCOUNTS_PER_BATCH_VOTE);
 
-        let (vote_add_pda, _) = Pubkey::find_program_address(
-            &[b"vote_add", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
-            program_id,
-        );
+        for (validator_pubkey, row) in batch {
+            let (blacklist_pda, _) = Pubkey::find_program_address(
+                &[b"blacklist", config_pubkey.as_ref(), validator_pubkey.as_ref()],
+                program_id,
+            );
+            let (vote_add_pda, _) = Pubkey::find_program_address(
+                &[b"vote_add", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
+                program_id,
+            );
+            let (vote_remove_pda, _) = Pubkey::find_program_address(
+                &[b"vote_remove", config_pubkey.as_ref(), stake_pool_pubkey.as_ref(), validator_pubkey.as_ref()],
+                program_id,
+            );
+
+            remaining_accounts.push(AccountMeta::new(blacklist_pda, false));
+            remaining_accounts.push(AccountMeta::new(vote_add_pda, false));
+            remaining_accounts.push(AccountMeta::new_readonly(vote_remove_pda, false));
+            if let Some(vote_account) = row.vote_account {
+                remaining_accounts.push(AccountMeta::new_readonly(vote_account, false));
+            }
+
+            votes.push(BatchVote {
+                validator_identity_address: **validator_pubkey,
+                reason: row.reason.clone(),
+                category: row.category,
+                evidence: row.evidence.clone(),
+                vote_account: row.vote_account,
+            });
+        }
 
-        let signature = program
+        Ok(program
             .request()
             .instruction(solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(1_000_000))
-            .accounts(validator_blacklist::accounts::VoteAdd {
+            .accounts(validator_blacklist::accounts::VoteAddMany {
                 config: config_pubkey,
                 stake_pool: stake_pool_pubkey,
-                blacklist: blacklist_pda,
-                vote_add: vote_add_pda,
-                delegation: delegation_pda,
+                pool_override: pool_override_address(program_id, &config_pubkey, &stake_pool_pubkey),
+                delegation: delegation_pubkey,
                 authority: keypair.pubkey(),
                 system_program: system_program::id(),
+                event_authority: event_authority_address(program_id),
+                program: *program_id,
             })
-            .args(validator_blacklist::instruction::VoteAdd {
-                validator_identity_address: *validator_pubkey,
-                reason: ban_reason.clone(),
-            })
-            .send()?;
+            .accounts(remaining_accounts)
+            .args(validator_blacklist::instruction::VoteAddMany { votes })
+            .instructions()?)
+    };
+
+    // Reasons and evidence vary in length, so the batches are sized by the transaction they make
+    let batches = pack_by_transaction_size(pending_votes.len(), batch_size, &keypair.pubkey(), |range| {
+        vote_add_many_instructions(&pending_votes[range])
+    })?;
+
+    println!("Starting batch ban of {} validators in {} transactions...\n", pending_votes.len(), batches.len());
+
+    let mut banned_count = 0;
+    let mut failed_rows = Vec::new();
+
+    for range in batches {
+        let batch = &pending_votes[range];
+        let result = vote_add_many_instructions(batch).and_then(|instructions| {
+            let request = instructions.into_iter()
+                .fold(program.request(), |request, instruction| request.instruction(instruction));
+            Ok(request.send()?)
+        });
+
+        match result {
+            Ok(signature) => {
+                for (validator_pubkey, row) in batch {
+                    banned_count += 1;
+                    println!("[{}/{}] ✓ Voted to ban validator {} for reason: \"{}\"",
+                             banned_count, pending_votes.len(), validator_pubkey, row.reason);
+                }
+                println!("        Transaction signature: {}", signature);
+            }
+            Err(err) => {
+                // Record the failed rows and carry on with the next batch
+                for (validator_pubkey, row) in batch {
+                    println!("✗ Failed to vote to ban validator {}", validator_pubkey);
+                    failed_rows.push(row.record.clone());
+                }
+                println!("        Error: {:#}", err);
+            }
+        }
+    }
+
+    if !failed_rows.is_empty() {
+        let failed_file = failed_file.unwrap_or_else(|| format!("{}.failed.csv", csv.trim_end_matches(".csv")));
+        let mut writer = csv::Writer::from_path(&failed_file)?;
+        writer.write_record(["validator_address", "reason", "category", "evidence_hash", "evidence_uri", "vote_account"])?;
+        for record in &failed_rows {
+            // Pad to the header, the columns after the reason are optional
+            writer.write_record((0..6).map(|index| record.get(index).unwrap_or("")))?;
+        }
+        writer.flush()?;
 
-        println!("[{}/{}] ✓ Voted to ban validator {} for reason: \"{}\"",
-                 i + 1, filtered_validators.len(), validator_pubkey, ban_reason);
-        println!("        Transaction signature: {}", signature);
+        return Err(anyhow::anyhow!(
+            "{} of {} validators failed and were written to {}, run batch-ban again with --file {} to retry them",
+            failed_rows.len(), pending_votes.len(), failed_file, failed_file
+        ));
     }
 
     println!("\n✅ Batch ban completed successfully!");
     Ok(())
 }
+
+/// Serialized size of a transaction holding `instructions`, signed by `payer` alone
+fn transaction_size(instructions: &[Instruction], payer: &Pubkey) -> Result<usize> {
+    let transaction = Transaction::new_unsigned(Message::new(instructions, Some(payer)));
+    Ok(bincode::serialized_size(&transaction)? as usize)
+}
+
+/// Split `count` items into consecutive batches of at most `max_batch_size` items, each as large
+/// as still fits into one packet once `build_instructions` turns it into a transaction. An item
+/// too large for a transaction of its own still gets a batch, which then fails by itself.
+fn pack_by_transaction_size(
+    count: usize,
+    max_batch_size: usize,
+    payer: &Pubkey,
+    build_instructions: impl Fn(Range<usize>) -> Result<Vec<Instruction>>,
+) -> Result<Vec<Range<usize>>> {
+    let mut batches = Vec::new();
+    let mut start = 0;
+
+    while start < count {
+        let mut end = start + 1;
+        while end < count
+            && end - start < max_batch_size
+            && transaction_size(&build_instructions(start..end + 1)?, payer)? <= PACKET_DATA_SIZE
+        {
+            end += 1;
+        }
+        batches.push(start..end);
+        start = end;
+    }
+
+    Ok(batches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_config() {
+        let program_id = Pubkey::new_unique();
+        let (local_config, _) = Pubkey::find_program_address(&[b"config", b"local"], &program_id);
+        assert_eq!(resolve_config(&program_id, None, "local").unwrap(), local_config);
+        assert_ne!(resolve_config(&program_id, None, "default").unwrap(), local_config);
+
+        let config = Pubkey::new_unique();
+        assert_eq!(resolve_config(&program_id, Some(config.to_string()), "local").unwrap(), config);
+        assert!(resolve_config(&program_id, Some("not-a-pubkey".to_string()), "local").is_err());
+    }
+
+    #[test]
+    fn test_blacklist_vote_account_offset() {
+        let vote_account = Pubkey::new_unique();
+        let blacklist = Blacklist {
+            validator_identity_address: Pubkey::new_unique(),
+            tally_add: 1,
+            tally_remove: 0,
+            stake_tally_add: 1,
+            stake_tally_remove: 0,
+            status: validator_blacklist::state::BlacklistStatus::Pending,
+            created_at: 1,
+            updated_at: 1,
+            config: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+            vote_account: Some(vote_account),
+            vetoed: false,
+        };
+
+        let mut data = Vec::new();
+        anchor_lang::AccountSerialize::try_serialize(&blacklist, &mut data).unwrap();
+        assert_eq!(data[Blacklist::VOTE_ACCOUNT_OFFSET], 1);
+        assert_eq!(&data[Blacklist::VOTE_ACCOUNT_OFFSET + 1..Blacklist::VOTE_ACCOUNT_OFFSET + 33], vote_account.as_ref());
+    }
+
+    #[test]
+    fn test_pack_by_transaction_size() {
+        use anchor_lang::{InstructionData, ToAccountMetas};
+
+        let program_id = validator_blacklist::ID;
+        let payer = Pubkey::new_unique();
+        let validators: Vec<Pubkey> = (0..12).map(|_| Pubkey::new_unique()).collect();
+
+        // Batches of the default size with typical reasons exceed a packet, see BATCH_BAN_USAGE.md
+        let build_instructions = |range: Range<usize>| -> Result<Vec<Instruction>> {
+            let mut accounts = validator_blacklist::accounts::VoteAddMany {
+                config: Pubkey::new_unique(),
+                stake_pool: Pubkey::new_unique(),
+                pool_override: Pubkey::new_unique(),
+                delegation: None,
+                authority: payer,
+                system_program: system_program::id(),
+                event_authority: Pubkey::new_unique(),
+                program: program_id,
+            }.to_account_metas(None);
+            let votes = validators[range].iter().map(|validator| {
+                accounts.extend((0..3).map(|_| AccountMeta::new(Pubkey::new_unique(), false)));
+                BatchVote {
+                    validator_identity_address: *validator,
+                    reason: "Sandwiching users on the Jito bundles of several epochs".to_string(),
+                    category: ReasonCategory::Sandwiching,
+                    evidence: None,
+                    vote_account: None,
+                }
+            }).collect();
+
+            Ok(vec![Instruction {
+                program_id,
+                accounts,
+                data: validator_blacklist::instruction::VoteAddMany { votes }.data(),
+            }])
+        };
+
+        assert!(transaction_size(&build_instructions(0..5).unwrap(), &payer).unwrap() > PACKET_DATA_SIZE);
+
+        let batches = pack_by_transaction_size(validators.len(), 5, &payer, build_instructions).unwrap();
+        assert_eq!(batches.first().unwrap().start, 0);
+        assert_eq!(batches.last().unwrap().end, validators.len());
+        for (batch, next) in batches.iter().zip(batches.iter().skip(1)) {
+            assert_eq!(batch.end, next.start);
+        }
+        for batch in &batches {
+            assert!(batch.len() < 5);
+            assert!(transaction_size(&build_instructions(batch.clone()).unwrap(), &payer).unwrap() <= PACKET_DATA_SIZE);
+        }
+
+        // The batch size stays an upper bound
+        let batches = pack_by_transaction_size(validators.len(), 2, &payer, build_instructions).unwrap();
+        assert_eq!(batches.len(), 6);
+    }
+
+    #[test]
+    fn test_parse_allowed_programs() {
+        let entries = vec![
+            "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy".to_string(),
+            "SP12tWFxD9oJsVWNavTTBZvMbA6gkAmxtVgxdqvyvhY:5000000000".to_string(),
+        ];
+
+        let allowed_programs = parse_allowed_programs(&entries).unwrap();
+        assert_eq!(allowed_programs.len(), 2);
+        assert_eq!(allowed_programs[0].program_id.to_string(), "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+        assert_eq!(allowed_programs[0].min_tvl, None);
+        assert_eq!(allowed_programs[1].program_id.to_string(), "SP12tWFxD9oJsVWNavTTBZvMbA6gkAmxtVgxdqvyvhY");
+        assert_eq!(allowed_programs[1].min_tvl, Some(5_000_000_000));
+        assert_eq!(allowed_programs[1].adapter, PoolAdapter::SplStakePool);
+    }
+
+    #[test]
+    fn test_parse_allowed_programs_adapter() {
+        let entries = vec![
+            "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy::spl-stake-pool".to_string(),
+            "SP12tWFxD9oJsVWNavTTBZvMbA6gkAmxtVgxdqvyvhY:5000000000:spl-stake-pool".to_string(),
+        ];
+
+        let allowed_programs = parse_allowed_programs(&entries).unwrap();
+        assert_eq!(allowed_programs[0].min_tvl, None);
+        assert_eq!(allowed_programs[0].adapter, PoolAdapter::SplStakePool);
+        assert_eq!(allowed_programs[1].min_tvl, Some(5_000_000_000));
+
+        let entries = vec!["MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD::marinade".to_string()];
+        assert_eq!(parse_allowed_programs(&entries).unwrap()[0].adapter, PoolAdapter::Marinade);
+
+        let entries = vec!["SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy::lido".to_string()];
+        assert!(parse_allowed_programs(&entries).is_err());
+    }
+
+    #[test]
+    fn test_parse_allowed_programs_invalid_min_tvl() {
+        let entries = vec!["SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy:lots".to_string()];
+        assert!(parse_allowed_programs(&entries).is_err());
+    }
+
+    #[test]
+    fn test_parse_authority_policy() {
+        assert_eq!(parse_authority_policy("manager-only").unwrap(), AuthorityPolicy::ManagerOnly);
+        assert_eq!(parse_authority_policy("staker-only").unwrap(), AuthorityPolicy::StakerOnly);
+        assert_eq!(parse_authority_policy("either").unwrap(), AuthorityPolicy::Either);
+        assert!(parse_authority_policy("operator").is_err());
+    }
+
+    #[test]
+    fn test_parse_permissions() {
+        let names = vec!["vote-add".to_string(), "unvote-add".to_string()];
+        assert_eq!(parse_permissions(&names).unwrap(), Delegation::PERMISSION_VOTE_ADD | Delegation::PERMISSION_UNVOTE_ADD);
+        assert_eq!(parse_permissions(&["all".to_string()]).unwrap(), Delegation::PERMISSION_ALL);
+        assert!(parse_permissions(&["vote".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_parse_evidence() {
+        let hash = "ab".repeat(32);
+        let evidence = parse_evidence(Some(&hash), Some("ar://evidence")).unwrap().unwrap();
+        assert_eq!(evidence.hash, [0xab; 32]);
+        assert_eq!(evidence.uri, "ar://evidence");
+        assert_eq!(parse_evidence(None, None).unwrap(), None);
+        assert!(parse_evidence(Some(&hash), None).is_err());
+        assert!(parse_evidence(Some("abcd"), Some("ar://evidence")).is_err());
+        assert_eq!(parse_category("commission-rug").unwrap(), ReasonCategory::CommissionRug);
+        assert!(parse_category("rug").is_err());
+    }
+}
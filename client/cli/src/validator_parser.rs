@@ -6,6 +6,7 @@ use std::str::FromStr;
 #[derive(Debug, Clone)]
 pub struct ValidatorEntry {
     pub identity: Pubkey,
+    /// Marked with ⚠️, which `solana validators` uses for delinquent validators
     pub has_warning: bool,
 }
 
@@ -52,15 +53,11 @@ pub fn parse_validator_list(input: &str) -> Result<Vec<ValidatorEntry>> {
 
 /// Parse a single line from the validator list
 fn parse_validator_line(line: &str) -> Result<ValidatorEntry> {
-    // Check if line starts with warning emoji
-    let has_warning = line.starts_with("⚠️");
-
-    // Remove the emoji prefix if present
-    let line_content = if has_warning {
-        // Remove the ⚠️ emoji (which is 3 bytes in UTF-8)
-        &line[3..].trim_start()
-    } else {
-        line.trim_start()
+    // Remove the warning emoji prefix if present. It is U+26A0, usually followed by the
+    // U+FE0F variation selector, so it has to be stripped by character rather than by byte count
+    let (has_warning, line_content) = match line.strip_prefix('\u{26A0}') {
+        Some(rest) => (true, rest.trim_start_matches('\u{FE0F}').trim_start()),
+        None => (false, line.trim_start()),
     };
 
     // Split by whitespace and get the first non-empty part
@@ -103,6 +100,15 @@ mod tests {
         assert!(result.has_warning);
     }
 
+    #[test]
+    fn test_parse_validator_line_with_bare_warning_sign() {
+        // Some terminals drop the variation selector, leaving only U+26A0
+        let line = "\u{26A0} 2XK1YYuLwPCMZSbmfedmso1vmkqrX63M2srNApvAntvw  ENjAU1VZvBTAMCwg9ZayfxLaRQEPExcR2ujH7VdeBkDh  100%";
+        let result = parse_validator_line(line).unwrap();
+        assert_eq!(result.identity.to_string(), "2XK1YYuLwPCMZSbmfedmso1vmkqrX63M2srNApvAntvw");
+        assert!(result.has_warning);
+    }
+
     #[test]
     fn test_parse_validator_list() {
         let input = r#"   Identity                                      Vote Account                            Commission  Last Vote        Root Slot     Skip Rate  Credit
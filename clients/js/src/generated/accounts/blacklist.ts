@@ -17,8 +17,14 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -26,16 +32,24 @@ import {
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getBlacklistStatusDecoder,
+  getBlacklistStatusEncoder,
+  type BlacklistStatus,
+  type BlacklistStatusArgs,
+} from '../types';
 
 export const BLACKLIST_DISCRIMINATOR = new Uint8Array([
   131, 9, 212, 250, 58, 186, 247, 3,
@@ -50,36 +64,72 @@ export type Blacklist = {
   validatorIdentityAddress: Address;
   tallyAdd: bigint;
   tallyRemove: bigint;
+  stakeTallyAdd: bigint;
+  stakeTallyRemove: bigint;
+  status: BlacklistStatus;
+  createdAt: bigint;
+  updatedAt: bigint;
+  config: Address;
+  payer: Address;
+  voteAccount: Option<Address>;
+  vetoed: boolean;
 };
 
 export type BlacklistArgs = {
   validatorIdentityAddress: Address;
   tallyAdd: number | bigint;
   tallyRemove: number | bigint;
+  stakeTallyAdd: number | bigint;
+  stakeTallyRemove: number | bigint;
+  status: BlacklistStatusArgs;
+  createdAt: number | bigint;
+  updatedAt: number | bigint;
+  config: Address;
+  payer: Address;
+  voteAccount: OptionOrNullable<Address>;
+  vetoed: boolean;
 };
 
-export function getBlacklistEncoder(): FixedSizeEncoder<BlacklistArgs> {
+export function getBlacklistEncoder(): Encoder<BlacklistArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['validatorIdentityAddress', getAddressEncoder()],
       ['tallyAdd', getU64Encoder()],
       ['tallyRemove', getU64Encoder()],
+      ['stakeTallyAdd', getU64Encoder()],
+      ['stakeTallyRemove', getU64Encoder()],
+      ['status', getBlacklistStatusEncoder()],
+      ['createdAt', getI64Encoder()],
+      ['updatedAt', getI64Encoder()],
+      ['config', getAddressEncoder()],
+      ['payer', getAddressEncoder()],
+      ['voteAccount', getOptionEncoder(getAddressEncoder())],
+      ['vetoed', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: BLACKLIST_DISCRIMINATOR })
   );
 }
 
-export function getBlacklistDecoder(): FixedSizeDecoder<Blacklist> {
+export function getBlacklistDecoder(): Decoder<Blacklist> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['validatorIdentityAddress', getAddressDecoder()],
     ['tallyAdd', getU64Decoder()],
     ['tallyRemove', getU64Decoder()],
+    ['stakeTallyAdd', getU64Decoder()],
+    ['stakeTallyRemove', getU64Decoder()],
+    ['status', getBlacklistStatusDecoder()],
+    ['createdAt', getI64Decoder()],
+    ['updatedAt', getI64Decoder()],
+    ['config', getAddressDecoder()],
+    ['payer', getAddressDecoder()],
+    ['voteAccount', getOptionDecoder(getAddressDecoder())],
+    ['vetoed', getBooleanDecoder()],
   ]);
 }
 
-export function getBlacklistCodec(): FixedSizeCodec<BlacklistArgs, Blacklist> {
+export function getBlacklistCodec(): Codec<BlacklistArgs, Blacklist> {
   return combineCodec(getBlacklistEncoder(), getBlacklistDecoder());
 }
 
@@ -135,7 +185,3 @@ export async function fetchAllMaybeBlacklist(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeBlacklist(maybeAccount));
 }
-
-export function getBlacklistSize(): number {
-  return 56;
-}
@@ -7,6 +7,8 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   assertAccountExists,
   assertAccountsExist,
   combineCodec,
@@ -19,12 +21,24 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -36,8 +50,20 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getAllowedProgramDecoder,
+  getAllowedProgramEncoder,
+  getAuthorityPolicyDecoder,
+  getAuthorityPolicyEncoder,
+  type AllowedProgram,
+  type AllowedProgramArgs,
+  type AuthorityPolicy,
+  type AuthorityPolicyArgs,
+} from '../types';
 
 export const CONFIG_DISCRIMINATOR = new Uint8Array([
   155, 12, 170, 224, 30, 250, 204, 130,
@@ -49,24 +75,51 @@ export function getConfigDiscriminatorBytes() {
 
 export type Config = {
   discriminator: ReadonlyUint8Array;
+  version: number;
   admin: Address;
   minTvl: bigint;
-  allowedPrograms: Array<Address>;
+  allowedPrograms: Array<AllowedProgram>;
+  minAddVotes: bigint;
+  minNetVotes: bigint;
+  pendingAdmin: Option<Address>;
+  namespace: string;
+  bump: number;
+  paused: boolean;
+  voteLifetime: bigint;
+  authorityPolicy: AuthorityPolicy;
 };
 
 export type ConfigArgs = {
+  version: number;
   admin: Address;
   minTvl: number | bigint;
-  allowedPrograms: Array<Address>;
+  allowedPrograms: Array<AllowedProgramArgs>;
+  minAddVotes: number | bigint;
+  minNetVotes: number | bigint;
+  pendingAdmin: OptionOrNullable<Address>;
+  namespace: string;
+  bump: number;
+  paused: boolean;
+  voteLifetime: number | bigint;
+  authorityPolicy: AuthorityPolicyArgs;
 };
 
 export function getConfigEncoder(): Encoder<ConfigArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['version', getU8Encoder()],
       ['admin', getAddressEncoder()],
       ['minTvl', getU64Encoder()],
-      ['allowedPrograms', getArrayEncoder(getAddressEncoder())],
+      ['allowedPrograms', getArrayEncoder(getAllowedProgramEncoder())],
+      ['minAddVotes', getU64Encoder()],
+      ['minNetVotes', getU64Encoder()],
+      ['pendingAdmin', getOptionEncoder(getAddressEncoder())],
+      ['namespace', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['bump', getU8Encoder()],
+      ['paused', getBooleanEncoder()],
+      ['voteLifetime', getI64Encoder()],
+      ['authorityPolicy', getAuthorityPolicyEncoder()],
     ]),
     (value) => ({ ...value, discriminator: CONFIG_DISCRIMINATOR })
   );
@@ -75,9 +128,18 @@ export function getConfigEncoder(): Encoder<ConfigArgs> {
 export function getConfigDecoder(): Decoder<Config> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['version', getU8Decoder()],
     ['admin', getAddressDecoder()],
     ['minTvl', getU64Decoder()],
-    ['allowedPrograms', getArrayDecoder(getAddressDecoder())],
+    ['allowedPrograms', getArrayDecoder(getAllowedProgramDecoder())],
+    ['minAddVotes', getU64Decoder()],
+    ['minNetVotes', getU64Decoder()],
+    ['pendingAdmin', getOptionDecoder(getAddressDecoder())],
+    ['namespace', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['bump', getU8Decoder()],
+    ['paused', getBooleanDecoder()],
+    ['voteLifetime', getI64Decoder()],
+    ['authorityPolicy', getAuthorityPolicyDecoder()],
   ]);
 }
 
@@ -21,19 +21,25 @@ import {
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 
@@ -51,6 +57,10 @@ export type Delegation = {
   manager: Address;
   delegate: Address;
   timestamp: bigint;
+  config: Address;
+  index: number;
+  permissions: number;
+  expiresAt: Option<bigint>;
 };
 
 export type DelegationArgs = {
@@ -58,9 +68,13 @@ export type DelegationArgs = {
   manager: Address;
   delegate: Address;
   timestamp: number | bigint;
+  config: Address;
+  index: number;
+  permissions: number;
+  expiresAt: OptionOrNullable<number | bigint>;
 };
 
-export function getDelegationEncoder(): FixedSizeEncoder<DelegationArgs> {
+export function getDelegationEncoder(): Encoder<DelegationArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
@@ -68,25 +82,30 @@ export function getDelegationEncoder(): FixedSizeEncoder<DelegationArgs> {
       ['manager', getAddressEncoder()],
       ['delegate', getAddressEncoder()],
       ['timestamp', getI64Encoder()],
+      ['config', getAddressEncoder()],
+      ['index', getU8Encoder()],
+      ['permissions', getU8Encoder()],
+      ['expiresAt', getOptionEncoder(getI64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: DELEGATION_DISCRIMINATOR })
   );
 }
 
-export function getDelegationDecoder(): FixedSizeDecoder<Delegation> {
+export function getDelegationDecoder(): Decoder<Delegation> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['stakePool', getAddressDecoder()],
     ['manager', getAddressDecoder()],
     ['delegate', getAddressDecoder()],
     ['timestamp', getI64Decoder()],
+    ['config', getAddressDecoder()],
+    ['index', getU8Decoder()],
+    ['permissions', getU8Decoder()],
+    ['expiresAt', getOptionDecoder(getI64Decoder())],
   ]);
 }
 
-export function getDelegationCodec(): FixedSizeCodec<
-  DelegationArgs,
-  Delegation
-> {
+export function getDelegationCodec(): Codec<DelegationArgs, Delegation> {
   return combineCodec(getDelegationEncoder(), getDelegationDecoder());
 }
 
@@ -142,7 +161,3 @@ export async function fetchAllMaybeDelegation(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeDelegation(maybeAccount));
 }
-
-export function getDelegationSize(): number {
-  return 112;
-}
@@ -9,5 +9,7 @@
 export * from './blacklist';
 export * from './config';
 export * from './delegation';
+export * from './poolOverride';
+export * from './veto';
 export * from './voteAddToBlacklist';
 export * from './voteRemoveFromBlacklist';
@@ -23,6 +23,8 @@ import {
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -42,8 +44,20 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getEvidenceDecoder,
+  getEvidenceEncoder,
+  getReasonCategoryDecoder,
+  getReasonCategoryEncoder,
+  type Evidence,
+  type EvidenceArgs,
+  type ReasonCategory,
+  type ReasonCategoryArgs,
+} from '../types';
 
 export const VOTE_ADD_TO_BLACKLIST_DISCRIMINATOR = new Uint8Array([
   163, 175, 255, 0, 25, 228, 211, 220,
@@ -62,6 +76,10 @@ export type VoteAddToBlacklist = {
   reason: string;
   timestamp: bigint;
   slot: bigint;
+  stakeLamports: bigint;
+  payer: Address;
+  category: ReasonCategory;
+  evidence: Option<Evidence>;
 };
 
 export type VoteAddToBlacklistArgs = {
@@ -70,6 +88,10 @@ export type VoteAddToBlacklistArgs = {
   reason: string;
   timestamp: number | bigint;
   slot: number | bigint;
+  stakeLamports: number | bigint;
+  payer: Address;
+  category: ReasonCategoryArgs;
+  evidence: OptionOrNullable<EvidenceArgs>;
 };
 
 export function getVoteAddToBlacklistEncoder(): Encoder<VoteAddToBlacklistArgs> {
@@ -81,6 +103,10 @@ export function getVoteAddToBlacklistEncoder(): Encoder<VoteAddToBlacklistArgs>
       ['reason', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['timestamp', getI64Encoder()],
       ['slot', getU64Encoder()],
+      ['stakeLamports', getU64Encoder()],
+      ['payer', getAddressEncoder()],
+      ['category', getReasonCategoryEncoder()],
+      ['evidence', getOptionEncoder(getEvidenceEncoder())],
     ]),
     (value) => ({
       ...value,
@@ -97,6 +123,10 @@ export function getVoteAddToBlacklistDecoder(): Decoder<VoteAddToBlacklist> {
     ['reason', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['timestamp', getI64Decoder()],
     ['slot', getU64Decoder()],
+    ['stakeLamports', getU64Decoder()],
+    ['payer', getAddressDecoder()],
+    ['category', getReasonCategoryDecoder()],
+    ['evidence', getOptionDecoder(getEvidenceDecoder())],
   ]);
 }
 
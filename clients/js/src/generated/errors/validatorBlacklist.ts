@@ -34,34 +34,158 @@ export const VALIDATOR_BLACKLIST_ERROR__INSUFFICIENT_TVL = 0x1777; // 6007
 export const VALIDATOR_BLACKLIST_ERROR__UNAUTHORIZED_STAKE_POOL_PROGRAM = 0x1778; // 6008
 /** UnauthorizedAdmin: Only the admin can perform this action */
 export const VALIDATOR_BLACKLIST_ERROR__UNAUTHORIZED_ADMIN = 0x1779; // 6009
+/** NoPendingAdmin: There is no pending admin proposal */
+export const VALIDATOR_BLACKLIST_ERROR__NO_PENDING_ADMIN = 0x177a; // 6010
+/** InvalidPendingAdmin: The signer is not the pending admin */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_PENDING_ADMIN = 0x177b; // 6011
+/** InvalidNamespace: The namespace must be between 1 and 32 bytes long */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_NAMESPACE = 0x177c; // 6012
+/** TooManyAllowedPrograms: The allowed programs do not fit into the config account, resize it first */
+export const VALIDATOR_BLACKLIST_ERROR__TOO_MANY_ALLOWED_PROGRAMS = 0x177d; // 6013
+/** InvalidConfigCapacity: The config capacity must hold the current allowed programs and not exceed the maximum */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_CONFIG_CAPACITY = 0x177e; // 6014
+/** VotingPaused: Voting and delegating are paused for this config */
+export const VALIDATOR_BLACKLIST_ERROR__VOTING_PAUSED = 0x177f; // 6015
+/** InvalidVoteLifetime: The vote lifetime cannot be negative */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_VOTE_LIFETIME = 0x1780; // 6016
+/** InvalidVoteAccount: Exactly one of the vote_add and vote_remove accounts must be provided */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_VOTE_ACCOUNT = 0x1781; // 6017
+/** VoteNotExpired: The vote has not outlived the vote lifetime */
+export const VALIDATOR_BLACKLIST_ERROR__VOTE_NOT_EXPIRED = 0x1782; // 6018
+/** InvalidPayer: The payer does not match the payer of the vote */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_PAYER = 0x1783; // 6019
+/** StakePoolStillQualifies: The stake pool still meets the config requirements */
+export const VALIDATOR_BLACKLIST_ERROR__STAKE_POOL_STILL_QUALIFIES = 0x1784; // 6020
+/** DelegationAlreadyExists: A delegation by the current manager already exists at this index */
+export const VALIDATOR_BLACKLIST_ERROR__DELEGATION_ALREADY_EXISTS = 0x1785; // 6021
+/** DelegationNotStale: The delegation was created by the current manager of the stake pool */
+export const VALIDATOR_BLACKLIST_ERROR__DELEGATION_NOT_STALE = 0x1786; // 6022
+/** InvalidPermissions: The permissions must be a non-empty combination of the known permission bits */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_PERMISSIONS = 0x1787; // 6023
+/** MissingPermission: The delegation does not grant the permission for this instruction */
+export const VALIDATOR_BLACKLIST_ERROR__MISSING_PERMISSION = 0x1788; // 6024
+/** InvalidDelegationConfig: The delegation belongs to a different config */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_DELEGATION_CONFIG = 0x1789; // 6025
+/** DelegationExpired: The delegation has expired */
+export const VALIDATOR_BLACKLIST_ERROR__DELEGATION_EXPIRED = 0x178a; // 6026
+/** InvalidDelegationExpiry: The delegation expiry must be in the future */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_DELEGATION_EXPIRY = 0x178b; // 6027
+/** DelegationNotExpired: The delegation has not expired */
+export const VALIDATOR_BLACKLIST_ERROR__DELEGATION_NOT_EXPIRED = 0x178c; // 6028
+/** EvidenceUriTooLong: The evidence URI exceeds the maximum allowed length */
+export const VALIDATOR_BLACKLIST_ERROR__EVIDENCE_URI_TOO_LONG = 0x178d; // 6029
+/** BlacklistNotEmpty: The blacklist entry still has votes */
+export const VALIDATOR_BLACKLIST_ERROR__BLACKLIST_NOT_EMPTY = 0x178e; // 6030
+/** ConflictingVote: The stake pool already voted the other way on this validator */
+export const VALIDATOR_BLACKLIST_ERROR__CONFLICTING_VOTE = 0x178f; // 6031
+/** VoteAlreadyCast: The stake pool already voted on this validator */
+export const VALIDATOR_BLACKLIST_ERROR__VOTE_ALREADY_CAST = 0x1790; // 6032
+/** InvalidBatchAccounts: The remaining accounts do not match the votes of the batch */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_BATCH_ACCOUNTS = 0x1791; // 6033
+/** InvalidValidatorVoteAccount: The vote account does not belong to the validator identity */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_VALIDATOR_VOTE_ACCOUNT = 0x1792; // 6034
+/** InvalidPoolAuthority: The signer does not hold a stake pool role allowed by the authority policy */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_POOL_AUTHORITY = 0x1793; // 6035
+/** StakePoolDenied: The stake pool is denied by an admin override */
+export const VALIDATOR_BLACKLIST_ERROR__STAKE_POOL_DENIED = 0x1794; // 6036
+/** InvalidPoolOverride: The pool override account is invalid */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_POOL_OVERRIDE = 0x1795; // 6037
+/** BlacklistVetoed: The blacklist entry is vetoed by the admin */
+export const VALIDATOR_BLACKLIST_ERROR__BLACKLIST_VETOED = 0x1796; // 6038
+/** UnsupportedConfigVersion: The config layout version is not supported, migrate the config with resize_config */
+export const VALIDATOR_BLACKLIST_ERROR__UNSUPPORTED_CONFIG_VERSION = 0x1797; // 6039
+/** InvalidPreviousManager: The previous manager does not match the manager of the delegation */
+export const VALIDATOR_BLACKLIST_ERROR__INVALID_PREVIOUS_MANAGER = 0x1798; // 6040
 
 export type ValidatorBlacklistError =
+  | typeof VALIDATOR_BLACKLIST_ERROR__BLACKLIST_NOT_EMPTY
+  | typeof VALIDATOR_BLACKLIST_ERROR__BLACKLIST_VETOED
+  | typeof VALIDATOR_BLACKLIST_ERROR__CONFLICTING_VOTE
+  | typeof VALIDATOR_BLACKLIST_ERROR__DELEGATION_ALREADY_EXISTS
+  | typeof VALIDATOR_BLACKLIST_ERROR__DELEGATION_EXPIRED
+  | typeof VALIDATOR_BLACKLIST_ERROR__DELEGATION_NOT_EXPIRED
+  | typeof VALIDATOR_BLACKLIST_ERROR__DELEGATION_NOT_STALE
+  | typeof VALIDATOR_BLACKLIST_ERROR__EVIDENCE_URI_TOO_LONG
   | typeof VALIDATOR_BLACKLIST_ERROR__INSUFFICIENT_TVL
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_BATCH_ACCOUNTS
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_CONFIG_CAPACITY
   | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_DELEGATE
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_DELEGATION_CONFIG
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_DELEGATION_EXPIRY
   | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_MANAGER
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_NAMESPACE
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_PAYER
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_PENDING_ADMIN
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_PERMISSIONS
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_POOL_AUTHORITY
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_POOL_OVERRIDE
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_PREVIOUS_MANAGER
   | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_STAKE_POOL
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_VALIDATOR_VOTE_ACCOUNT
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_VOTE_ACCOUNT
+  | typeof VALIDATOR_BLACKLIST_ERROR__INVALID_VOTE_LIFETIME
   | typeof VALIDATOR_BLACKLIST_ERROR__MATH_OVERFLOW
   | typeof VALIDATOR_BLACKLIST_ERROR__MATH_UNDERFLOW
+  | typeof VALIDATOR_BLACKLIST_ERROR__MISSING_PERMISSION
+  | typeof VALIDATOR_BLACKLIST_ERROR__NO_PENDING_ADMIN
   | typeof VALIDATOR_BLACKLIST_ERROR__REASON_TOO_LONG
+  | typeof VALIDATOR_BLACKLIST_ERROR__STAKE_POOL_DENIED
+  | typeof VALIDATOR_BLACKLIST_ERROR__STAKE_POOL_STILL_QUALIFIES
+  | typeof VALIDATOR_BLACKLIST_ERROR__TOO_MANY_ALLOWED_PROGRAMS
   | typeof VALIDATOR_BLACKLIST_ERROR__UNAUTHORIZED_ADMIN
   | typeof VALIDATOR_BLACKLIST_ERROR__UNAUTHORIZED_SIGNER
-  | typeof VALIDATOR_BLACKLIST_ERROR__UNAUTHORIZED_STAKE_POOL_PROGRAM;
+  | typeof VALIDATOR_BLACKLIST_ERROR__UNAUTHORIZED_STAKE_POOL_PROGRAM
+  | typeof VALIDATOR_BLACKLIST_ERROR__UNSUPPORTED_CONFIG_VERSION
+  | typeof VALIDATOR_BLACKLIST_ERROR__VOTE_ALREADY_CAST
+  | typeof VALIDATOR_BLACKLIST_ERROR__VOTE_NOT_EXPIRED
+  | typeof VALIDATOR_BLACKLIST_ERROR__VOTING_PAUSED;
 
 let validatorBlacklistErrorMessages:
   | Record<ValidatorBlacklistError, string>
   | undefined;
 if (process.env.NODE_ENV !== 'production') {
   validatorBlacklistErrorMessages = {
+    [VALIDATOR_BLACKLIST_ERROR__BLACKLIST_NOT_EMPTY]: `The blacklist entry still has votes`,
+    [VALIDATOR_BLACKLIST_ERROR__BLACKLIST_VETOED]: `The blacklist entry is vetoed by the admin`,
+    [VALIDATOR_BLACKLIST_ERROR__CONFLICTING_VOTE]: `The stake pool already voted the other way on this validator`,
+    [VALIDATOR_BLACKLIST_ERROR__DELEGATION_ALREADY_EXISTS]: `A delegation by the current manager already exists at this index`,
+    [VALIDATOR_BLACKLIST_ERROR__DELEGATION_EXPIRED]: `The delegation has expired`,
+    [VALIDATOR_BLACKLIST_ERROR__DELEGATION_NOT_EXPIRED]: `The delegation has not expired`,
+    [VALIDATOR_BLACKLIST_ERROR__DELEGATION_NOT_STALE]: `The delegation was created by the current manager of the stake pool`,
+    [VALIDATOR_BLACKLIST_ERROR__EVIDENCE_URI_TOO_LONG]: `The evidence URI exceeds the maximum allowed length`,
     [VALIDATOR_BLACKLIST_ERROR__INSUFFICIENT_TVL]: `Stake pool does not meet minimum TVL requirement`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_BATCH_ACCOUNTS]: `The remaining accounts do not match the votes of the batch`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_CONFIG_CAPACITY]: `The config capacity must hold the current allowed programs and not exceed the maximum`,
     [VALIDATOR_BLACKLIST_ERROR__INVALID_DELEGATE]: `Invalid delegate for this delegation`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_DELEGATION_CONFIG]: `The delegation belongs to a different config`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_DELEGATION_EXPIRY]: `The delegation expiry must be in the future`,
     [VALIDATOR_BLACKLIST_ERROR__INVALID_MANAGER]: `The signer is not the manager of the stake pool`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_NAMESPACE]: `The namespace must be between 1 and 32 bytes long`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_PAYER]: `The payer does not match the payer of the vote`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_PENDING_ADMIN]: `The signer is not the pending admin`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_PERMISSIONS]: `The permissions must be a non-empty combination of the known permission bits`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_POOL_AUTHORITY]: `The signer does not hold a stake pool role allowed by the authority policy`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_POOL_OVERRIDE]: `The pool override account is invalid`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_PREVIOUS_MANAGER]: `The previous manager does not match the manager of the delegation`,
     [VALIDATOR_BLACKLIST_ERROR__INVALID_STAKE_POOL]: `Invalid stake pool for this delegation`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_VALIDATOR_VOTE_ACCOUNT]: `The vote account does not belong to the validator identity`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_VOTE_ACCOUNT]: `Exactly one of the vote_add and vote_remove accounts must be provided`,
+    [VALIDATOR_BLACKLIST_ERROR__INVALID_VOTE_LIFETIME]: `The vote lifetime cannot be negative`,
     [VALIDATOR_BLACKLIST_ERROR__MATH_OVERFLOW]: `Math overflow occurred`,
     [VALIDATOR_BLACKLIST_ERROR__MATH_UNDERFLOW]: `Math underflow occurred`,
+    [VALIDATOR_BLACKLIST_ERROR__MISSING_PERMISSION]: `The delegation does not grant the permission for this instruction`,
+    [VALIDATOR_BLACKLIST_ERROR__NO_PENDING_ADMIN]: `There is no pending admin proposal`,
     [VALIDATOR_BLACKLIST_ERROR__REASON_TOO_LONG]: `The reason field exceeds the maximum allowed length`,
+    [VALIDATOR_BLACKLIST_ERROR__STAKE_POOL_DENIED]: `The stake pool is denied by an admin override`,
+    [VALIDATOR_BLACKLIST_ERROR__STAKE_POOL_STILL_QUALIFIES]: `The stake pool still meets the config requirements`,
+    [VALIDATOR_BLACKLIST_ERROR__TOO_MANY_ALLOWED_PROGRAMS]: `The allowed programs do not fit into the config account, resize it first`,
     [VALIDATOR_BLACKLIST_ERROR__UNAUTHORIZED_ADMIN]: `Only the admin can perform this action`,
     [VALIDATOR_BLACKLIST_ERROR__UNAUTHORIZED_SIGNER]: `Unauthorized signer - must be manager or valid delegate`,
     [VALIDATOR_BLACKLIST_ERROR__UNAUTHORIZED_STAKE_POOL_PROGRAM]: `Stake pool program is not in the allowed programs list`,
+    [VALIDATOR_BLACKLIST_ERROR__UNSUPPORTED_CONFIG_VERSION]: `The config layout version is not supported, migrate the config with resize_config`,
+    [VALIDATOR_BLACKLIST_ERROR__VOTE_ALREADY_CAST]: `The stake pool already voted on this validator`,
+    [VALIDATOR_BLACKLIST_ERROR__VOTE_NOT_EXPIRED]: `The vote has not outlived the vote lifetime`,
+    [VALIDATOR_BLACKLIST_ERROR__VOTING_PAUSED]: `Voting and delegating are paused for this config`,
   };
 }
 
@@ -10,3 +10,4 @@ export * from './accounts';
 export * from './errors';
 export * from './instructions';
 export * from './programs';
+export * from './types';
@@ -13,19 +13,27 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -51,12 +59,16 @@ export type DelegateInstruction<
   TProgram extends string = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountStakePool extends string | AccountMeta<string> = string,
+  TAccountPoolOverride extends string | AccountMeta<string> = string,
   TAccountDelegation extends string | AccountMeta<string> = string,
   TAccountManager extends string | AccountMeta<string> = string,
+  TAccountPreviousManager extends string | AccountMeta<string> = string,
   TAccountDelegate extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -68,6 +80,9 @@ export type DelegateInstruction<
       TAccountStakePool extends string
         ? ReadonlyAccount<TAccountStakePool>
         : TAccountStakePool,
+      TAccountPoolOverride extends string
+        ? ReadonlyAccount<TAccountPoolOverride>
+        : TAccountPoolOverride,
       TAccountDelegation extends string
         ? WritableAccount<TAccountDelegation>
         : TAccountDelegation,
@@ -75,34 +90,60 @@ export type DelegateInstruction<
         ? WritableSignerAccount<TAccountManager> &
             AccountSignerMeta<TAccountManager>
         : TAccountManager,
+      TAccountPreviousManager extends string
+        ? WritableAccount<TAccountPreviousManager>
+        : TAccountPreviousManager,
       TAccountDelegate extends string
         ? ReadonlyAccount<TAccountDelegate>
         : TAccountDelegate,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
-export type DelegateInstructionData = { discriminator: ReadonlyUint8Array };
+export type DelegateInstructionData = {
+  discriminator: ReadonlyUint8Array;
+  index: number;
+  permissions: number;
+  expiresAt: Option<bigint>;
+};
 
-export type DelegateInstructionDataArgs = {};
+export type DelegateInstructionDataArgs = {
+  index: number;
+  permissions: number;
+  expiresAt: OptionOrNullable<number | bigint>;
+};
 
-export function getDelegateInstructionDataEncoder(): FixedSizeEncoder<DelegateInstructionDataArgs> {
+export function getDelegateInstructionDataEncoder(): Encoder<DelegateInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['index', getU8Encoder()],
+      ['permissions', getU8Encoder()],
+      ['expiresAt', getOptionEncoder(getI64Encoder())],
+    ]),
     (value) => ({ ...value, discriminator: DELEGATE_DISCRIMINATOR })
   );
 }
 
-export function getDelegateInstructionDataDecoder(): FixedSizeDecoder<DelegateInstructionData> {
+export function getDelegateInstructionDataDecoder(): Decoder<DelegateInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['index', getU8Decoder()],
+    ['permissions', getU8Decoder()],
+    ['expiresAt', getOptionDecoder(getI64Decoder())],
   ]);
 }
 
-export function getDelegateInstructionDataCodec(): FixedSizeCodec<
+export function getDelegateInstructionDataCodec(): Codec<
   DelegateInstructionDataArgs,
   DelegateInstructionData
 > {
@@ -115,39 +156,63 @@ export function getDelegateInstructionDataCodec(): FixedSizeCodec<
 export type DelegateAsyncInput<
   TAccountConfig extends string = string,
   TAccountStakePool extends string = string,
+  TAccountPoolOverride extends string = string,
   TAccountDelegation extends string = string,
   TAccountManager extends string = string,
+  TAccountPreviousManager extends string = string,
   TAccountDelegate extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
-  /** Global configuration account */
+  /** Global configuration account, voting and delegating are rejected while it is paused */
   config: Address<TAccountConfig>;
   /** The stake pool account to validate the manager */
   stakePool: Address<TAccountStakePool>;
-  delegation?: Address<TAccountDelegation>;
+  /** Admin override of the stake pool, consulted before the program and TVL rules of the config */
+  poolOverride?: Address<TAccountPoolOverride>;
+  delegation: Address<TAccountDelegation>;
   /** The manager of the stake pool (must match the stake pool's manager field) */
   manager: TransactionSigner<TAccountManager>;
+  /**
+   * The manager who created the existing delegation, refunded its rent when it is overwritten.
+   * Only needed if the delegation exists.
+   */
+  previousManager?: Address<TAccountPreviousManager>;
   /** The address to delegate authority to */
   delegate: Address<TAccountDelegate>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  index: DelegateInstructionDataArgs['index'];
+  permissions: DelegateInstructionDataArgs['permissions'];
+  expiresAt: DelegateInstructionDataArgs['expiresAt'];
 };
 
 export async function getDelegateInstructionAsync<
   TAccountConfig extends string,
   TAccountStakePool extends string,
+  TAccountPoolOverride extends string,
   TAccountDelegation extends string,
   TAccountManager extends string,
+  TAccountPreviousManager extends string,
   TAccountDelegate extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: DelegateAsyncInput<
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountDelegation,
     TAccountManager,
+    TAccountPreviousManager,
     TAccountDelegate,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -155,10 +220,14 @@ export async function getDelegateInstructionAsync<
     TProgramAddress,
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountDelegation,
     TAccountManager,
+    TAccountPreviousManager,
     TAccountDelegate,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -169,23 +238,32 @@ export async function getDelegateInstructionAsync<
   const originalAccounts = {
     config: { value: input.config ?? null, isWritable: false },
     stakePool: { value: input.stakePool ?? null, isWritable: false },
+    poolOverride: { value: input.poolOverride ?? null, isWritable: false },
     delegation: { value: input.delegation ?? null, isWritable: true },
     manager: { value: input.manager ?? null, isWritable: true },
+    previousManager: { value: input.previousManager ?? null, isWritable: true },
     delegate: { value: input.delegate ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
-  if (!accounts.delegation.value) {
-    accounts.delegation.value = await getProgramDerivedAddress({
+  if (!accounts.poolOverride.value) {
+    accounts.poolOverride.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([100, 101, 108, 101, 103, 97, 116, 105, 111, 110])
+          new Uint8Array([
+            112, 111, 111, 108, 95, 111, 118, 101, 114, 114, 105, 100, 101,
+          ])
         ),
         getAddressEncoder().encode(expectAddress(accounts.config.value)),
         getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
@@ -196,76 +274,127 @@ export async function getDelegateInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.stakePool),
+      getAccountMeta(accounts.poolOverride),
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.previousManager),
       getAccountMeta(accounts.delegate),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
-    data: getDelegateInstructionDataEncoder().encode({}),
+    data: getDelegateInstructionDataEncoder().encode(
+      args as DelegateInstructionDataArgs
+    ),
     programAddress,
   } as DelegateInstruction<
     TProgramAddress,
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountDelegation,
     TAccountManager,
+    TAccountPreviousManager,
     TAccountDelegate,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type DelegateInput<
   TAccountConfig extends string = string,
   TAccountStakePool extends string = string,
+  TAccountPoolOverride extends string = string,
   TAccountDelegation extends string = string,
   TAccountManager extends string = string,
+  TAccountPreviousManager extends string = string,
   TAccountDelegate extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
-  /** Global configuration account */
+  /** Global configuration account, voting and delegating are rejected while it is paused */
   config: Address<TAccountConfig>;
   /** The stake pool account to validate the manager */
   stakePool: Address<TAccountStakePool>;
+  /** Admin override of the stake pool, consulted before the program and TVL rules of the config */
+  poolOverride: Address<TAccountPoolOverride>;
   delegation: Address<TAccountDelegation>;
   /** The manager of the stake pool (must match the stake pool's manager field) */
   manager: TransactionSigner<TAccountManager>;
+  /**
+   * The manager who created the existing delegation, refunded its rent when it is overwritten.
+   * Only needed if the delegation exists.
+   */
+  previousManager?: Address<TAccountPreviousManager>;
   /** The address to delegate authority to */
   delegate: Address<TAccountDelegate>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  index: DelegateInstructionDataArgs['index'];
+  permissions: DelegateInstructionDataArgs['permissions'];
+  expiresAt: DelegateInstructionDataArgs['expiresAt'];
 };
 
 export function getDelegateInstruction<
   TAccountConfig extends string,
   TAccountStakePool extends string,
+  TAccountPoolOverride extends string,
   TAccountDelegation extends string,
   TAccountManager extends string,
+  TAccountPreviousManager extends string,
   TAccountDelegate extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: DelegateInput<
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountDelegation,
     TAccountManager,
+    TAccountPreviousManager,
     TAccountDelegate,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): DelegateInstruction<
   TProgramAddress,
   TAccountConfig,
   TAccountStakePool,
+  TAccountPoolOverride,
   TAccountDelegation,
   TAccountManager,
+  TAccountPreviousManager,
   TAccountDelegate,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -275,16 +404,23 @@ export function getDelegateInstruction<
   const originalAccounts = {
     config: { value: input.config ?? null, isWritable: false },
     stakePool: { value: input.stakePool ?? null, isWritable: false },
+    poolOverride: { value: input.poolOverride ?? null, isWritable: false },
     delegation: { value: input.delegation ?? null, isWritable: true },
     manager: { value: input.manager ?? null, isWritable: true },
+    previousManager: { value: input.previousManager ?? null, isWritable: true },
     delegate: { value: input.delegate ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -296,21 +432,31 @@ export function getDelegateInstruction<
     accounts: [
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.stakePool),
+      getAccountMeta(accounts.poolOverride),
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.previousManager),
       getAccountMeta(accounts.delegate),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
-    data: getDelegateInstructionDataEncoder().encode({}),
+    data: getDelegateInstructionDataEncoder().encode(
+      args as DelegateInstructionDataArgs
+    ),
     programAddress,
   } as DelegateInstruction<
     TProgramAddress,
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountDelegation,
     TAccountManager,
+    TAccountPreviousManager,
     TAccountDelegate,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -320,16 +466,25 @@ export type ParsedDelegateInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** Global configuration account */
+    /** Global configuration account, voting and delegating are rejected while it is paused */
     config: TAccountMetas[0];
     /** The stake pool account to validate the manager */
     stakePool: TAccountMetas[1];
-    delegation: TAccountMetas[2];
+    /** Admin override of the stake pool, consulted before the program and TVL rules of the config */
+    poolOverride: TAccountMetas[2];
+    delegation: TAccountMetas[3];
     /** The manager of the stake pool (must match the stake pool's manager field) */
-    manager: TAccountMetas[3];
+    manager: TAccountMetas[4];
+    /**
+     * The manager who created the existing delegation, refunded its rent when it is overwritten.
+     * Only needed if the delegation exists.
+     */
+    previousManager?: TAccountMetas[5] | undefined;
     /** The address to delegate authority to */
-    delegate: TAccountMetas[4];
-    systemProgram: TAccountMetas[5];
+    delegate: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: DelegateInstructionData;
 };
@@ -342,7 +497,7 @@ export function parseDelegateInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedDelegateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -352,15 +507,25 @@ export function parseDelegateInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === VALIDATOR_BLACKLIST_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       config: getNextAccount(),
       stakePool: getNextAccount(),
+      poolOverride: getNextAccount(),
       delegation: getNextAccount(),
       manager: getNextAccount(),
+      previousManager: getNextOptionalAccount(),
       delegate: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getDelegateInstructionDataDecoder().decode(instruction.data),
   };
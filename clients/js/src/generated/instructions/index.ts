@@ -6,12 +6,31 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptAdmin';
+export * from './cancelAdminProposal';
+export * from './changeVote';
+export * from './closeBlacklist';
+export * from './closeExpiredDelegation';
+export * from './closeStaleDelegation';
 export * from './delegate';
+export * from './editReason';
+export * from './expireVote';
 export * from './initConfig';
+export * from './isBlacklisted';
+export * from './liftVeto';
+export * from './proposeAdmin';
+export * from './pruneVote';
+export * from './reaffirmVote';
+export * from './removePoolOverride';
+export * from './resizeConfig';
+export * from './setPaused';
+export * from './setPoolOverride';
 export * from './undelegate';
 export * from './unvoteAdd';
 export * from './unvoteRemove';
 export * from './updateConfig';
-export * from './updateConfigAdmin';
+export * from './updateDelegate';
+export * from './veto';
 export * from './voteAdd';
+export * from './voteAddMany';
 export * from './voteRemove';
@@ -7,19 +7,24 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
-  getAddressDecoder,
-  getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -33,10 +38,21 @@ import {
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
+  type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/kit';
 import { VALIDATOR_BLACKLIST_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectSome,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+import {
+  getAllowedProgramDecoder,
+  getAllowedProgramEncoder,
+  type AllowedProgram,
+  type AllowedProgramArgs,
+} from '../types';
 
 export const INIT_CONFIG_DISCRIMINATOR = new Uint8Array([
   23, 235, 115, 232, 168, 96, 1, 231,
@@ -53,14 +69,15 @@ export type InitConfigInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
       TAccountConfig extends string
-        ? WritableSignerAccount<TAccountConfig> &
-            AccountSignerMeta<TAccountConfig>
+        ? WritableAccount<TAccountConfig>
         : TAccountConfig,
       TAccountAdmin extends string
         ? WritableSignerAccount<TAccountAdmin> &
@@ -69,27 +86,42 @@ export type InitConfigInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
 export type InitConfigInstructionData = {
   discriminator: ReadonlyUint8Array;
+  namespace: string;
   minTvl: bigint;
-  allowedPrograms: Array<Address>;
+  allowedPrograms: Array<AllowedProgram>;
+  minAddVotes: bigint;
+  minNetVotes: bigint;
 };
 
 export type InitConfigInstructionDataArgs = {
+  namespace: string;
   minTvl: number | bigint;
-  allowedPrograms: Array<Address>;
+  allowedPrograms: Array<AllowedProgramArgs>;
+  minAddVotes: number | bigint;
+  minNetVotes: number | bigint;
 };
 
 export function getInitConfigInstructionDataEncoder(): Encoder<InitConfigInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['namespace', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['minTvl', getU64Encoder()],
-      ['allowedPrograms', getArrayEncoder(getAddressEncoder())],
+      ['allowedPrograms', getArrayEncoder(getAllowedProgramEncoder())],
+      ['minAddVotes', getU64Encoder()],
+      ['minNetVotes', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: INIT_CONFIG_DISCRIMINATOR })
   );
@@ -98,8 +130,11 @@ export function getInitConfigInstructionDataEncoder(): Encoder<InitConfigInstruc
 export function getInitConfigInstructionDataDecoder(): Decoder<InitConfigInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['namespace', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['minTvl', getU64Decoder()],
-    ['allowedPrograms', getArrayDecoder(getAddressDecoder())],
+    ['allowedPrograms', getArrayDecoder(getAllowedProgramDecoder())],
+    ['minAddVotes', getU64Decoder()],
+    ['minNetVotes', getU64Decoder()],
   ]);
 }
 
@@ -113,31 +148,164 @@ export function getInitConfigInstructionDataCodec(): Codec<
   );
 }
 
+export type InitConfigAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  namespace: InitConfigInstructionDataArgs['namespace'];
+  minTvl: InitConfigInstructionDataArgs['minTvl'];
+  allowedPrograms: InitConfigInstructionDataArgs['allowedPrograms'];
+  minAddVotes: InitConfigInstructionDataArgs['minAddVotes'];
+  minNetVotes: InitConfigInstructionDataArgs['minNetVotes'];
+};
+
+export async function getInitConfigInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
+>(
+  input: InitConfigAsyncInput<
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  InitConfigInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? VALIDATOR_BLACKLIST_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
+        getUtf8Encoder().encode(expectSome(args.namespace)),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    data: getInitConfigInstructionDataEncoder().encode(
+      args as InitConfigInstructionDataArgs
+    ),
+    programAddress,
+  } as InitConfigInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >);
+}
+
 export type InitConfigInput<
   TAccountConfig extends string = string,
   TAccountAdmin extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
-  config: TransactionSigner<TAccountConfig>;
+  config: Address<TAccountConfig>;
   admin: TransactionSigner<TAccountAdmin>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  namespace: InitConfigInstructionDataArgs['namespace'];
   minTvl: InitConfigInstructionDataArgs['minTvl'];
   allowedPrograms: InitConfigInstructionDataArgs['allowedPrograms'];
+  minAddVotes: InitConfigInstructionDataArgs['minAddVotes'];
+  minNetVotes: InitConfigInstructionDataArgs['minNetVotes'];
 };
 
 export function getInitConfigInstruction<
   TAccountConfig extends string,
   TAccountAdmin extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
-  input: InitConfigInput<TAccountConfig, TAccountAdmin, TAccountSystemProgram>,
+  input: InitConfigInput<
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
   config?: { programAddress?: TProgramAddress }
 ): InitConfigInstruction<
   TProgramAddress,
   TAccountConfig,
   TAccountAdmin,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -148,6 +316,8 @@ export function getInitConfigInstruction<
     config: { value: input.config ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -169,6 +339,8 @@ export function getInitConfigInstruction<
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getInitConfigInstructionDataEncoder().encode(
       args as InitConfigInstructionDataArgs
@@ -178,7 +350,9 @@ export function getInitConfigInstruction<
     TProgramAddress,
     TAccountConfig,
     TAccountAdmin,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -191,6 +365,8 @@ export type ParsedInitConfigInstruction<
     config: TAccountMetas[0];
     admin: TAccountMetas[1];
     systemProgram: TAccountMetas[2];
+    eventAuthority: TAccountMetas[3];
+    program: TAccountMetas[4];
   };
   data: InitConfigInstructionData;
 };
@@ -203,7 +379,7 @@ export function parseInitConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -219,6 +395,8 @@ export function parseInitConfigInstruction<
       config: getNextAccount(),
       admin: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getInitConfigInstructionDataDecoder().decode(instruction.data),
   };
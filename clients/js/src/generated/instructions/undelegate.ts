@@ -10,7 +10,6 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
-  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
@@ -33,11 +32,7 @@ import {
   type WritableSignerAccount,
 } from '@solana/kit';
 import { VALIDATOR_BLACKLIST_PROGRAM_ADDRESS } from '../programs';
-import {
-  expectAddress,
-  getAccountMetaFactory,
-  type ResolvedAccount,
-} from '../shared';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
 export const UNDELEGATE_DISCRIMINATOR = new Uint8Array([
   131, 148, 180, 198, 91, 104, 42, 238,
@@ -53,6 +48,8 @@ export type UndelegateInstruction<
   TAccountDelegation extends string | AccountMeta<string> = string,
   TAccountStakePool extends string | AccountMeta<string> = string,
   TAccountManager extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -71,6 +68,12 @@ export type UndelegateInstruction<
         ? WritableSignerAccount<TAccountManager> &
             AccountSignerMeta<TAccountManager>
         : TAccountManager,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -107,14 +110,18 @@ export type UndelegateAsyncInput<
   TAccountDelegation extends string = string,
   TAccountStakePool extends string = string,
   TAccountManager extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   /** Global configuration account */
   config: Address<TAccountConfig>;
-  delegation?: Address<TAccountDelegation>;
+  delegation: Address<TAccountDelegation>;
   /** The stake pool account to validate the manager */
   stakePool: Address<TAccountStakePool>;
   /** The manager of the stake pool (must match the delegation's manager field) */
   manager: TransactionSigner<TAccountManager>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export async function getUndelegateInstructionAsync<
@@ -122,13 +129,17 @@ export async function getUndelegateInstructionAsync<
   TAccountDelegation extends string,
   TAccountStakePool extends string,
   TAccountManager extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: UndelegateAsyncInput<
     TAccountConfig,
     TAccountDelegation,
     TAccountStakePool,
-    TAccountManager
+    TAccountManager,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -137,7 +148,9 @@ export async function getUndelegateInstructionAsync<
     TAccountConfig,
     TAccountDelegation,
     TAccountStakePool,
-    TAccountManager
+    TAccountManager,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -150,6 +163,8 @@ export async function getUndelegateInstructionAsync<
     delegation: { value: input.delegation ?? null, isWritable: true },
     stakePool: { value: input.stakePool ?? null, isWritable: false },
     manager: { value: input.manager ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -157,15 +172,16 @@ export async function getUndelegateInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.delegation.value) {
-    accounts.delegation.value = await getProgramDerivedAddress({
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([100, 101, 108, 101, 103, 97, 116, 105, 111, 110])
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.config.value)),
-        getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
       ],
     });
   }
@@ -177,6 +193,8 @@ export async function getUndelegateInstructionAsync<
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getUndelegateInstructionDataEncoder().encode({}),
     programAddress,
@@ -185,7 +203,9 @@ export async function getUndelegateInstructionAsync<
     TAccountConfig,
     TAccountDelegation,
     TAccountStakePool,
-    TAccountManager
+    TAccountManager,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -194,6 +214,8 @@ export type UndelegateInput<
   TAccountDelegation extends string = string,
   TAccountStakePool extends string = string,
   TAccountManager extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   /** Global configuration account */
   config: Address<TAccountConfig>;
@@ -202,6 +224,8 @@ export type UndelegateInput<
   stakePool: Address<TAccountStakePool>;
   /** The manager of the stake pool (must match the delegation's manager field) */
   manager: TransactionSigner<TAccountManager>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
 };
 
 export function getUndelegateInstruction<
@@ -209,13 +233,17 @@ export function getUndelegateInstruction<
   TAccountDelegation extends string,
   TAccountStakePool extends string,
   TAccountManager extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: UndelegateInput<
     TAccountConfig,
     TAccountDelegation,
     TAccountStakePool,
-    TAccountManager
+    TAccountManager,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): UndelegateInstruction<
@@ -223,7 +251,9 @@ export function getUndelegateInstruction<
   TAccountConfig,
   TAccountDelegation,
   TAccountStakePool,
-  TAccountManager
+  TAccountManager,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -235,6 +265,8 @@ export function getUndelegateInstruction<
     delegation: { value: input.delegation ?? null, isWritable: true },
     stakePool: { value: input.stakePool ?? null, isWritable: false },
     manager: { value: input.manager ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -248,6 +280,8 @@ export function getUndelegateInstruction<
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.stakePool),
       getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getUndelegateInstructionDataEncoder().encode({}),
     programAddress,
@@ -256,7 +290,9 @@ export function getUndelegateInstruction<
     TAccountConfig,
     TAccountDelegation,
     TAccountStakePool,
-    TAccountManager
+    TAccountManager,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -273,6 +309,8 @@ export type ParsedUndelegateInstruction<
     stakePool: TAccountMetas[2];
     /** The manager of the stake pool (must match the delegation's manager field) */
     manager: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: UndelegateInstructionData;
 };
@@ -285,7 +323,7 @@ export function parseUndelegateInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUndelegateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -302,6 +340,8 @@ export function parseUndelegateInstruction<
       delegation: getNextAccount(),
       stakePool: getNextAccount(),
       manager: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getUndelegateInstructionDataDecoder().decode(instruction.data),
   };
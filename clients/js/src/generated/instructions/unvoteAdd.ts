@@ -28,10 +28,10 @@ import {
   type InstructionWithAccounts,
   type InstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
-  type WritableSignerAccount,
 } from '@solana/kit';
 import { VALIDATOR_BLACKLIST_PROGRAM_ADDRESS } from '../programs';
 import {
@@ -57,6 +57,9 @@ export type UnvoteAddInstruction<
   TAccountVoteAdd extends string | AccountMeta<string> = string,
   TAccountDelegation extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
+  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -78,9 +81,18 @@ export type UnvoteAddInstruction<
         ? ReadonlyAccount<TAccountDelegation>
         : TAccountDelegation,
       TAccountAuthority extends string
-        ? WritableSignerAccount<TAccountAuthority> &
+        ? ReadonlySignerAccount<TAccountAuthority> &
             AccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
+      TAccountPayer extends string
+        ? WritableAccount<TAccountPayer>
+        : TAccountPayer,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -128,6 +140,9 @@ export type UnvoteAddAsyncInput<
   TAccountVoteAdd extends string = string,
   TAccountDelegation extends string = string,
   TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   /** Global configuration account */
   config: Address<TAccountConfig>;
@@ -135,10 +150,17 @@ export type UnvoteAddAsyncInput<
   stakePool: Address<TAccountStakePool>;
   blacklist?: Address<TAccountBlacklist>;
   voteAdd?: Address<TAccountVoteAdd>;
-  /** Optional delegation account - if present, authority must be the delegate */
+  /**
+   * Optional delegation account - if present, authority must be the delegate and the
+   * delegation must grant the permission for this instruction
+   */
   delegation?: Address<TAccountDelegation>;
   /** The authority (either manager or delegated authority) */
   authority: TransactionSigner<TAccountAuthority>;
+  /** Receives the rent of the closed vote */
+  payer: Address<TAccountPayer>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   validatorIdentityAddress: UnvoteAddInstructionDataArgs['validatorIdentityAddress'];
 };
 
@@ -149,6 +171,9 @@ export async function getUnvoteAddInstructionAsync<
   TAccountVoteAdd extends string,
   TAccountDelegation extends string,
   TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: UnvoteAddAsyncInput<
@@ -157,7 +182,10 @@ export async function getUnvoteAddInstructionAsync<
     TAccountBlacklist,
     TAccountVoteAdd,
     TAccountDelegation,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -168,7 +196,10 @@ export async function getUnvoteAddInstructionAsync<
     TAccountBlacklist,
     TAccountVoteAdd,
     TAccountDelegation,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -182,7 +213,10 @@ export async function getUnvoteAddInstructionAsync<
     blacklist: { value: input.blacklist ?? null, isWritable: true },
     voteAdd: { value: input.voteAdd ?? null, isWritable: true },
     delegation: { value: input.delegation ?? null, isWritable: false },
-    authority: { value: input.authority ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -218,15 +252,16 @@ export async function getUnvoteAddInstructionAsync<
       ],
     });
   }
-  if (!accounts.delegation.value) {
-    accounts.delegation.value = await getProgramDerivedAddress({
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([100, 101, 108, 101, 103, 97, 116, 105, 111, 110])
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.config.value)),
-        getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
       ],
     });
   }
@@ -240,6 +275,9 @@ export async function getUnvoteAddInstructionAsync<
       getAccountMeta(accounts.voteAdd),
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getUnvoteAddInstructionDataEncoder().encode(
       args as UnvoteAddInstructionDataArgs
@@ -252,7 +290,10 @@ export async function getUnvoteAddInstructionAsync<
     TAccountBlacklist,
     TAccountVoteAdd,
     TAccountDelegation,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -263,6 +304,9 @@ export type UnvoteAddInput<
   TAccountVoteAdd extends string = string,
   TAccountDelegation extends string = string,
   TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   /** Global configuration account */
   config: Address<TAccountConfig>;
@@ -270,10 +314,17 @@ export type UnvoteAddInput<
   stakePool: Address<TAccountStakePool>;
   blacklist: Address<TAccountBlacklist>;
   voteAdd: Address<TAccountVoteAdd>;
-  /** Optional delegation account - if present, authority must be the delegate */
+  /**
+   * Optional delegation account - if present, authority must be the delegate and the
+   * delegation must grant the permission for this instruction
+   */
   delegation?: Address<TAccountDelegation>;
   /** The authority (either manager or delegated authority) */
   authority: TransactionSigner<TAccountAuthority>;
+  /** Receives the rent of the closed vote */
+  payer: Address<TAccountPayer>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   validatorIdentityAddress: UnvoteAddInstructionDataArgs['validatorIdentityAddress'];
 };
 
@@ -284,6 +335,9 @@ export function getUnvoteAddInstruction<
   TAccountVoteAdd extends string,
   TAccountDelegation extends string,
   TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: UnvoteAddInput<
@@ -292,7 +346,10 @@ export function getUnvoteAddInstruction<
     TAccountBlacklist,
     TAccountVoteAdd,
     TAccountDelegation,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): UnvoteAddInstruction<
@@ -302,7 +359,10 @@ export function getUnvoteAddInstruction<
   TAccountBlacklist,
   TAccountVoteAdd,
   TAccountDelegation,
-  TAccountAuthority
+  TAccountAuthority,
+  TAccountPayer,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -315,7 +375,10 @@ export function getUnvoteAddInstruction<
     blacklist: { value: input.blacklist ?? null, isWritable: true },
     voteAdd: { value: input.voteAdd ?? null, isWritable: true },
     delegation: { value: input.delegation ?? null, isWritable: false },
-    authority: { value: input.authority ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -334,6 +397,9 @@ export function getUnvoteAddInstruction<
       getAccountMeta(accounts.voteAdd),
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getUnvoteAddInstructionDataEncoder().encode(
       args as UnvoteAddInstructionDataArgs
@@ -346,7 +412,10 @@ export function getUnvoteAddInstruction<
     TAccountBlacklist,
     TAccountVoteAdd,
     TAccountDelegation,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -362,10 +431,17 @@ export type ParsedUnvoteAddInstruction<
     stakePool: TAccountMetas[1];
     blacklist: TAccountMetas[2];
     voteAdd: TAccountMetas[3];
-    /** Optional delegation account - if present, authority must be the delegate */
+    /**
+     * Optional delegation account - if present, authority must be the delegate and the
+     * delegation must grant the permission for this instruction
+     */
     delegation?: TAccountMetas[4] | undefined;
     /** The authority (either manager or delegated authority) */
     authority: TAccountMetas[5];
+    /** Receives the rent of the closed vote */
+    payer: TAccountMetas[6];
+    eventAuthority: TAccountMetas[7];
+    program: TAccountMetas[8];
   };
   data: UnvoteAddInstructionData;
 };
@@ -378,7 +454,7 @@ export function parseUnvoteAddInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUnvoteAddInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -403,6 +479,9 @@ export function parseUnvoteAddInstruction<
       voteAdd: getNextAccount(),
       delegation: getNextOptionalAccount(),
       authority: getNextAccount(),
+      payer: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getUnvoteAddInstructionDataDecoder().decode(instruction.data),
   };
@@ -28,10 +28,10 @@ import {
   type InstructionWithAccounts,
   type InstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
-  type WritableSignerAccount,
 } from '@solana/kit';
 import { VALIDATOR_BLACKLIST_PROGRAM_ADDRESS } from '../programs';
 import {
@@ -59,6 +59,9 @@ export type UnvoteRemoveInstruction<
   TAccountVoteRemove extends string | AccountMeta<string> = string,
   TAccountDelegation extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
+  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -80,9 +83,18 @@ export type UnvoteRemoveInstruction<
         ? ReadonlyAccount<TAccountDelegation>
         : TAccountDelegation,
       TAccountAuthority extends string
-        ? WritableSignerAccount<TAccountAuthority> &
+        ? ReadonlySignerAccount<TAccountAuthority> &
             AccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
+      TAccountPayer extends string
+        ? WritableAccount<TAccountPayer>
+        : TAccountPayer,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -130,6 +142,9 @@ export type UnvoteRemoveAsyncInput<
   TAccountVoteRemove extends string = string,
   TAccountDelegation extends string = string,
   TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   /** Global configuration account */
   config: Address<TAccountConfig>;
@@ -137,10 +152,17 @@ export type UnvoteRemoveAsyncInput<
   stakePool: Address<TAccountStakePool>;
   blacklist?: Address<TAccountBlacklist>;
   voteRemove?: Address<TAccountVoteRemove>;
-  /** Optional delegation account - if present, authority must be the delegate */
+  /**
+   * Optional delegation account - if present, authority must be the delegate and the
+   * delegation must grant the permission for this instruction
+   */
   delegation?: Address<TAccountDelegation>;
   /** The authority (either manager or delegated authority) */
   authority: TransactionSigner<TAccountAuthority>;
+  /** Receives the rent of the closed vote */
+  payer: Address<TAccountPayer>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   validatorIdentityAddress: UnvoteRemoveInstructionDataArgs['validatorIdentityAddress'];
 };
 
@@ -151,6 +173,9 @@ export async function getUnvoteRemoveInstructionAsync<
   TAccountVoteRemove extends string,
   TAccountDelegation extends string,
   TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: UnvoteRemoveAsyncInput<
@@ -159,7 +184,10 @@ export async function getUnvoteRemoveInstructionAsync<
     TAccountBlacklist,
     TAccountVoteRemove,
     TAccountDelegation,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -170,7 +198,10 @@ export async function getUnvoteRemoveInstructionAsync<
     TAccountBlacklist,
     TAccountVoteRemove,
     TAccountDelegation,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -184,7 +215,10 @@ export async function getUnvoteRemoveInstructionAsync<
     blacklist: { value: input.blacklist ?? null, isWritable: true },
     voteRemove: { value: input.voteRemove ?? null, isWritable: true },
     delegation: { value: input.delegation ?? null, isWritable: false },
-    authority: { value: input.authority ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -220,15 +254,16 @@ export async function getUnvoteRemoveInstructionAsync<
       ],
     });
   }
-  if (!accounts.delegation.value) {
-    accounts.delegation.value = await getProgramDerivedAddress({
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([100, 101, 108, 101, 103, 97, 116, 105, 111, 110])
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.config.value)),
-        getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
       ],
     });
   }
@@ -242,6 +277,9 @@ export async function getUnvoteRemoveInstructionAsync<
       getAccountMeta(accounts.voteRemove),
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getUnvoteRemoveInstructionDataEncoder().encode(
       args as UnvoteRemoveInstructionDataArgs
@@ -254,7 +292,10 @@ export async function getUnvoteRemoveInstructionAsync<
     TAccountBlacklist,
     TAccountVoteRemove,
     TAccountDelegation,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -265,6 +306,9 @@ export type UnvoteRemoveInput<
   TAccountVoteRemove extends string = string,
   TAccountDelegation extends string = string,
   TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   /** Global configuration account */
   config: Address<TAccountConfig>;
@@ -272,10 +316,17 @@ export type UnvoteRemoveInput<
   stakePool: Address<TAccountStakePool>;
   blacklist: Address<TAccountBlacklist>;
   voteRemove: Address<TAccountVoteRemove>;
-  /** Optional delegation account - if present, authority must be the delegate */
+  /**
+   * Optional delegation account - if present, authority must be the delegate and the
+   * delegation must grant the permission for this instruction
+   */
   delegation?: Address<TAccountDelegation>;
   /** The authority (either manager or delegated authority) */
   authority: TransactionSigner<TAccountAuthority>;
+  /** Receives the rent of the closed vote */
+  payer: Address<TAccountPayer>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   validatorIdentityAddress: UnvoteRemoveInstructionDataArgs['validatorIdentityAddress'];
 };
 
@@ -286,6 +337,9 @@ export function getUnvoteRemoveInstruction<
   TAccountVoteRemove extends string,
   TAccountDelegation extends string,
   TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: UnvoteRemoveInput<
@@ -294,7 +348,10 @@ export function getUnvoteRemoveInstruction<
     TAccountBlacklist,
     TAccountVoteRemove,
     TAccountDelegation,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): UnvoteRemoveInstruction<
@@ -304,7 +361,10 @@ export function getUnvoteRemoveInstruction<
   TAccountBlacklist,
   TAccountVoteRemove,
   TAccountDelegation,
-  TAccountAuthority
+  TAccountAuthority,
+  TAccountPayer,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -317,7 +377,10 @@ export function getUnvoteRemoveInstruction<
     blacklist: { value: input.blacklist ?? null, isWritable: true },
     voteRemove: { value: input.voteRemove ?? null, isWritable: true },
     delegation: { value: input.delegation ?? null, isWritable: false },
-    authority: { value: input.authority ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -336,6 +399,9 @@ export function getUnvoteRemoveInstruction<
       getAccountMeta(accounts.voteRemove),
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getUnvoteRemoveInstructionDataEncoder().encode(
       args as UnvoteRemoveInstructionDataArgs
@@ -348,7 +414,10 @@ export function getUnvoteRemoveInstruction<
     TAccountBlacklist,
     TAccountVoteRemove,
     TAccountDelegation,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -364,10 +433,17 @@ export type ParsedUnvoteRemoveInstruction<
     stakePool: TAccountMetas[1];
     blacklist: TAccountMetas[2];
     voteRemove: TAccountMetas[3];
-    /** Optional delegation account - if present, authority must be the delegate */
+    /**
+     * Optional delegation account - if present, authority must be the delegate and the
+     * delegation must grant the permission for this instruction
+     */
     delegation?: TAccountMetas[4] | undefined;
     /** The authority (either manager or delegated authority) */
     authority: TAccountMetas[5];
+    /** Receives the rent of the closed vote */
+    payer: TAccountMetas[6];
+    eventAuthority: TAccountMetas[7];
+    program: TAccountMetas[8];
   };
   data: UnvoteRemoveInstructionData;
 };
@@ -380,7 +456,7 @@ export function parseUnvoteRemoveInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUnvoteRemoveInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -405,6 +481,9 @@ export function parseUnvoteRemoveInstruction<
       voteRemove: getNextAccount(),
       delegation: getNextOptionalAccount(),
       authority: getNextAccount(),
+      payer: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getUnvoteRemoveInstructionDataDecoder().decode(instruction.data),
   };
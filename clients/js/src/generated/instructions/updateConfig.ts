@@ -10,14 +10,15 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
-  getAddressDecoder,
-  getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getOptionDecoder,
   getOptionEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -34,6 +35,7 @@ import {
   type InstructionWithData,
   type Option,
   type OptionOrNullable,
+  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -41,6 +43,16 @@ import {
 } from '@solana/kit';
 import { VALIDATOR_BLACKLIST_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getAllowedProgramDecoder,
+  getAllowedProgramEncoder,
+  getAuthorityPolicyDecoder,
+  getAuthorityPolicyEncoder,
+  type AllowedProgram,
+  type AllowedProgramArgs,
+  type AuthorityPolicy,
+  type AuthorityPolicyArgs,
+} from '../types';
 
 export const UPDATE_CONFIG_DISCRIMINATOR = new Uint8Array([
   29, 158, 252, 191, 10, 83, 219, 99,
@@ -56,6 +68,8 @@ export type UpdateConfigInstruction<
   TProgram extends string = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -68,6 +82,12 @@ export type UpdateConfigInstruction<
         ? WritableSignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -75,12 +95,20 @@ export type UpdateConfigInstruction<
 export type UpdateConfigInstructionData = {
   discriminator: ReadonlyUint8Array;
   minTvl: Option<bigint>;
-  allowedPrograms: Option<Array<Address>>;
+  allowedPrograms: Option<Array<AllowedProgram>>;
+  minAddVotes: Option<bigint>;
+  minNetVotes: Option<bigint>;
+  voteLifetime: Option<bigint>;
+  authorityPolicy: Option<AuthorityPolicy>;
 };
 
 export type UpdateConfigInstructionDataArgs = {
   minTvl: OptionOrNullable<number | bigint>;
-  allowedPrograms: OptionOrNullable<Array<Address>>;
+  allowedPrograms: OptionOrNullable<Array<AllowedProgramArgs>>;
+  minAddVotes: OptionOrNullable<number | bigint>;
+  minNetVotes: OptionOrNullable<number | bigint>;
+  voteLifetime: OptionOrNullable<number | bigint>;
+  authorityPolicy: OptionOrNullable<AuthorityPolicyArgs>;
 };
 
 export function getUpdateConfigInstructionDataEncoder(): Encoder<UpdateConfigInstructionDataArgs> {
@@ -90,8 +118,12 @@ export function getUpdateConfigInstructionDataEncoder(): Encoder<UpdateConfigIns
       ['minTvl', getOptionEncoder(getU64Encoder())],
       [
         'allowedPrograms',
-        getOptionEncoder(getArrayEncoder(getAddressEncoder())),
+        getOptionEncoder(getArrayEncoder(getAllowedProgramEncoder())),
       ],
+      ['minAddVotes', getOptionEncoder(getU64Encoder())],
+      ['minNetVotes', getOptionEncoder(getU64Encoder())],
+      ['voteLifetime', getOptionEncoder(getI64Encoder())],
+      ['authorityPolicy', getOptionEncoder(getAuthorityPolicyEncoder())],
     ]),
     (value) => ({ ...value, discriminator: UPDATE_CONFIG_DISCRIMINATOR })
   );
@@ -101,7 +133,14 @@ export function getUpdateConfigInstructionDataDecoder(): Decoder<UpdateConfigIns
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['minTvl', getOptionDecoder(getU64Decoder())],
-    ['allowedPrograms', getOptionDecoder(getArrayDecoder(getAddressDecoder()))],
+    [
+      'allowedPrograms',
+      getOptionDecoder(getArrayDecoder(getAllowedProgramDecoder())),
+    ],
+    ['minAddVotes', getOptionDecoder(getU64Decoder())],
+    ['minNetVotes', getOptionDecoder(getU64Decoder())],
+    ['voteLifetime', getOptionDecoder(getI64Decoder())],
+    ['authorityPolicy', getOptionDecoder(getAuthorityPolicyDecoder())],
   ]);
 }
 
@@ -115,24 +154,141 @@ export function getUpdateConfigInstructionDataCodec(): Codec<
   );
 }
 
+export type UpdateConfigAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  minTvl: UpdateConfigInstructionDataArgs['minTvl'];
+  allowedPrograms: UpdateConfigInstructionDataArgs['allowedPrograms'];
+  minAddVotes: UpdateConfigInstructionDataArgs['minAddVotes'];
+  minNetVotes: UpdateConfigInstructionDataArgs['minNetVotes'];
+  voteLifetime: UpdateConfigInstructionDataArgs['voteLifetime'];
+  authorityPolicy: UpdateConfigInstructionDataArgs['authorityPolicy'];
+};
+
+export async function getUpdateConfigInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
+>(
+  input: UpdateConfigAsyncInput<
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  UpdateConfigInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? VALIDATOR_BLACKLIST_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    data: getUpdateConfigInstructionDataEncoder().encode(
+      args as UpdateConfigInstructionDataArgs
+    ),
+    programAddress,
+  } as UpdateConfigInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountEventAuthority,
+    TAccountProgram
+  >);
+}
+
 export type UpdateConfigInput<
   TAccountConfig extends string = string,
   TAccountAdmin extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   config: Address<TAccountConfig>;
   admin: TransactionSigner<TAccountAdmin>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   minTvl: UpdateConfigInstructionDataArgs['minTvl'];
   allowedPrograms: UpdateConfigInstructionDataArgs['allowedPrograms'];
+  minAddVotes: UpdateConfigInstructionDataArgs['minAddVotes'];
+  minNetVotes: UpdateConfigInstructionDataArgs['minNetVotes'];
+  voteLifetime: UpdateConfigInstructionDataArgs['voteLifetime'];
+  authorityPolicy: UpdateConfigInstructionDataArgs['authorityPolicy'];
 };
 
 export function getUpdateConfigInstruction<
   TAccountConfig extends string,
   TAccountAdmin extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
-  input: UpdateConfigInput<TAccountConfig, TAccountAdmin>,
+  input: UpdateConfigInput<
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
   config?: { programAddress?: TProgramAddress }
-): UpdateConfigInstruction<TProgramAddress, TAccountConfig, TAccountAdmin> {
+): UpdateConfigInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountAdmin,
+  TAccountEventAuthority,
+  TAccountProgram
+> {
   // Program address.
   const programAddress =
     config?.programAddress ?? VALIDATOR_BLACKLIST_PROGRAM_ADDRESS;
@@ -141,6 +297,8 @@ export function getUpdateConfigInstruction<
   const originalAccounts = {
     config: { value: input.config ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -152,12 +310,23 @@ export function getUpdateConfigInstruction<
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
-    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
     data: getUpdateConfigInstructionDataEncoder().encode(
       args as UpdateConfigInstructionDataArgs
     ),
     programAddress,
-  } as UpdateConfigInstruction<TProgramAddress, TAccountConfig, TAccountAdmin>);
+  } as UpdateConfigInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountEventAuthority,
+    TAccountProgram
+  >);
 }
 
 export type ParsedUpdateConfigInstruction<
@@ -168,6 +337,8 @@ export type ParsedUpdateConfigInstruction<
   accounts: {
     config: TAccountMetas[0];
     admin: TAccountMetas[1];
+    eventAuthority: TAccountMetas[2];
+    program: TAccountMetas[3];
   };
   data: UpdateConfigInstructionData;
 };
@@ -180,7 +351,7 @@ export function parseUpdateConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdateConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -192,7 +363,12 @@ export function parseUpdateConfigInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { config: getNextAccount(), admin: getNextAccount() },
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
+    },
     data: getUpdateConfigInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -1,188 +0,0 @@
-/**
- * This code was AUTOGENERATED using the Codama library.
- * Please DO NOT EDIT THIS FILE, instead use visitors
- * to add features, then rerun Codama to update it.
- *
- * @see https://github.com/codama-idl/codama
- */
-
-import {
-  combineCodec,
-  fixDecoderSize,
-  fixEncoderSize,
-  getAddressDecoder,
-  getAddressEncoder,
-  getBytesDecoder,
-  getBytesEncoder,
-  getStructDecoder,
-  getStructEncoder,
-  transformEncoder,
-  type AccountMeta,
-  type AccountSignerMeta,
-  type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
-  type Instruction,
-  type InstructionWithAccounts,
-  type InstructionWithData,
-  type ReadonlyUint8Array,
-  type TransactionSigner,
-  type WritableAccount,
-  type WritableSignerAccount,
-} from '@solana/kit';
-import { VALIDATOR_BLACKLIST_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
-
-export const UPDATE_CONFIG_ADMIN_DISCRIMINATOR = new Uint8Array([
-  30, 254, 195, 1, 169, 26, 214, 39,
-]);
-
-export function getUpdateConfigAdminDiscriminatorBytes() {
-  return fixEncoderSize(getBytesEncoder(), 8).encode(
-    UPDATE_CONFIG_ADMIN_DISCRIMINATOR
-  );
-}
-
-export type UpdateConfigAdminInstruction<
-  TProgram extends string = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
-  TAccountConfig extends string | AccountMeta<string> = string,
-  TAccountAdmin extends string | AccountMeta<string> = string,
-  TRemainingAccounts extends readonly AccountMeta<string>[] = [],
-> = Instruction<TProgram> &
-  InstructionWithData<ReadonlyUint8Array> &
-  InstructionWithAccounts<
-    [
-      TAccountConfig extends string
-        ? WritableAccount<TAccountConfig>
-        : TAccountConfig,
-      TAccountAdmin extends string
-        ? WritableSignerAccount<TAccountAdmin> &
-            AccountSignerMeta<TAccountAdmin>
-        : TAccountAdmin,
-      ...TRemainingAccounts,
-    ]
-  >;
-
-export type UpdateConfigAdminInstructionData = {
-  discriminator: ReadonlyUint8Array;
-  newAdmin: Address;
-};
-
-export type UpdateConfigAdminInstructionDataArgs = { newAdmin: Address };
-
-export function getUpdateConfigAdminInstructionDataEncoder(): FixedSizeEncoder<UpdateConfigAdminInstructionDataArgs> {
-  return transformEncoder(
-    getStructEncoder([
-      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
-      ['newAdmin', getAddressEncoder()],
-    ]),
-    (value) => ({ ...value, discriminator: UPDATE_CONFIG_ADMIN_DISCRIMINATOR })
-  );
-}
-
-export function getUpdateConfigAdminInstructionDataDecoder(): FixedSizeDecoder<UpdateConfigAdminInstructionData> {
-  return getStructDecoder([
-    ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
-    ['newAdmin', getAddressDecoder()],
-  ]);
-}
-
-export function getUpdateConfigAdminInstructionDataCodec(): FixedSizeCodec<
-  UpdateConfigAdminInstructionDataArgs,
-  UpdateConfigAdminInstructionData
-> {
-  return combineCodec(
-    getUpdateConfigAdminInstructionDataEncoder(),
-    getUpdateConfigAdminInstructionDataDecoder()
-  );
-}
-
-export type UpdateConfigAdminInput<
-  TAccountConfig extends string = string,
-  TAccountAdmin extends string = string,
-> = {
-  config: Address<TAccountConfig>;
-  admin: TransactionSigner<TAccountAdmin>;
-  newAdmin: UpdateConfigAdminInstructionDataArgs['newAdmin'];
-};
-
-export function getUpdateConfigAdminInstruction<
-  TAccountConfig extends string,
-  TAccountAdmin extends string,
-  TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
->(
-  input: UpdateConfigAdminInput<TAccountConfig, TAccountAdmin>,
-  config?: { programAddress?: TProgramAddress }
-): UpdateConfigAdminInstruction<
-  TProgramAddress,
-  TAccountConfig,
-  TAccountAdmin
-> {
-  // Program address.
-  const programAddress =
-    config?.programAddress ?? VALIDATOR_BLACKLIST_PROGRAM_ADDRESS;
-
-  // Original accounts.
-  const originalAccounts = {
-    config: { value: input.config ?? null, isWritable: true },
-    admin: { value: input.admin ?? null, isWritable: true },
-  };
-  const accounts = originalAccounts as Record<
-    keyof typeof originalAccounts,
-    ResolvedAccount
-  >;
-
-  // Original args.
-  const args = { ...input };
-
-  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
-  return Object.freeze({
-    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
-    data: getUpdateConfigAdminInstructionDataEncoder().encode(
-      args as UpdateConfigAdminInstructionDataArgs
-    ),
-    programAddress,
-  } as UpdateConfigAdminInstruction<
-    TProgramAddress,
-    TAccountConfig,
-    TAccountAdmin
-  >);
-}
-
-export type ParsedUpdateConfigAdminInstruction<
-  TProgram extends string = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
-  TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[],
-> = {
-  programAddress: Address<TProgram>;
-  accounts: {
-    config: TAccountMetas[0];
-    admin: TAccountMetas[1];
-  };
-  data: UpdateConfigAdminInstructionData;
-};
-
-export function parseUpdateConfigAdminInstruction<
-  TProgram extends string,
-  TAccountMetas extends readonly AccountMeta[],
->(
-  instruction: Instruction<TProgram> &
-    InstructionWithAccounts<TAccountMetas> &
-    InstructionWithData<ReadonlyUint8Array>
-): ParsedUpdateConfigAdminInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
-    // TODO: Coded error.
-    throw new Error('Not enough accounts');
-  }
-  let accountIndex = 0;
-  const getNextAccount = () => {
-    const accountMeta = (instruction.accounts as TAccountMetas)[accountIndex]!;
-    accountIndex += 1;
-    return accountMeta;
-  };
-  return {
-    programAddress: instruction.programAddress,
-    accounts: { config: getNextAccount(), admin: getNextAccount() },
-    data: getUpdateConfigAdminInstructionDataDecoder().decode(instruction.data),
-  };
-}
@@ -16,6 +16,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -33,6 +35,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -46,6 +50,16 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getEvidenceDecoder,
+  getEvidenceEncoder,
+  getReasonCategoryDecoder,
+  getReasonCategoryEncoder,
+  type Evidence,
+  type EvidenceArgs,
+  type ReasonCategory,
+  type ReasonCategoryArgs,
+} from '../types';
 
 export const VOTE_ADD_DISCRIMINATOR = new Uint8Array([
   182, 101, 189, 107, 95, 99, 36, 137,
@@ -59,13 +73,18 @@ export type VoteAddInstruction<
   TProgram extends string = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountStakePool extends string | AccountMeta<string> = string,
+  TAccountPoolOverride extends string | AccountMeta<string> = string,
   TAccountBlacklist extends string | AccountMeta<string> = string,
   TAccountVoteAdd extends string | AccountMeta<string> = string,
+  TAccountVoteRemove extends string | AccountMeta<string> = string,
+  TAccountValidatorVoteAccount extends string | AccountMeta<string> = string,
   TAccountDelegation extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -77,12 +96,21 @@ export type VoteAddInstruction<
       TAccountStakePool extends string
         ? ReadonlyAccount<TAccountStakePool>
         : TAccountStakePool,
+      TAccountPoolOverride extends string
+        ? ReadonlyAccount<TAccountPoolOverride>
+        : TAccountPoolOverride,
       TAccountBlacklist extends string
         ? WritableAccount<TAccountBlacklist>
         : TAccountBlacklist,
       TAccountVoteAdd extends string
         ? WritableAccount<TAccountVoteAdd>
         : TAccountVoteAdd,
+      TAccountVoteRemove extends string
+        ? ReadonlyAccount<TAccountVoteRemove>
+        : TAccountVoteRemove,
+      TAccountValidatorVoteAccount extends string
+        ? ReadonlyAccount<TAccountValidatorVoteAccount>
+        : TAccountValidatorVoteAccount,
       TAccountDelegation extends string
         ? ReadonlyAccount<TAccountDelegation>
         : TAccountDelegation,
@@ -93,6 +121,12 @@ export type VoteAddInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -101,11 +135,15 @@ export type VoteAddInstructionData = {
   discriminator: ReadonlyUint8Array;
   validatorIdentityAddress: Address;
   reason: string;
+  category: ReasonCategory;
+  evidence: Option<Evidence>;
 };
 
 export type VoteAddInstructionDataArgs = {
   validatorIdentityAddress: Address;
   reason: string;
+  category: ReasonCategoryArgs;
+  evidence: OptionOrNullable<EvidenceArgs>;
 };
 
 export function getVoteAddInstructionDataEncoder(): Encoder<VoteAddInstructionDataArgs> {
@@ -114,6 +152,8 @@ export function getVoteAddInstructionDataEncoder(): Encoder<VoteAddInstructionDa
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['validatorIdentityAddress', getAddressEncoder()],
       ['reason', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['category', getReasonCategoryEncoder()],
+      ['evidence', getOptionEncoder(getEvidenceEncoder())],
     ]),
     (value) => ({ ...value, discriminator: VOTE_ADD_DISCRIMINATOR })
   );
@@ -124,6 +164,8 @@ export function getVoteAddInstructionDataDecoder(): Decoder<VoteAddInstructionDa
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['validatorIdentityAddress', getAddressDecoder()],
     ['reason', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['category', getReasonCategoryDecoder()],
+    ['evidence', getOptionDecoder(getEvidenceDecoder())],
   ]);
 }
 
@@ -140,45 +182,73 @@ export function getVoteAddInstructionDataCodec(): Codec<
 export type VoteAddAsyncInput<
   TAccountConfig extends string = string,
   TAccountStakePool extends string = string,
+  TAccountPoolOverride extends string = string,
   TAccountBlacklist extends string = string,
   TAccountVoteAdd extends string = string,
+  TAccountVoteRemove extends string = string,
+  TAccountValidatorVoteAccount extends string = string,
   TAccountDelegation extends string = string,
   TAccountAuthority extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
-  /** Global configuration account */
+  /** Global configuration account, voting and delegating are rejected while it is paused */
   config: Address<TAccountConfig>;
   /** The stake pool account for stake pool that is casting the vote */
   stakePool: Address<TAccountStakePool>;
+  /** Admin override of the stake pool, consulted before the program and TVL rules of the config */
+  poolOverride?: Address<TAccountPoolOverride>;
   blacklist?: Address<TAccountBlacklist>;
   voteAdd?: Address<TAccountVoteAdd>;
-  /** Optional delegation account - if present, authority must be the delegate */
+  /** The remove vote of the same stake pool, which must not exist */
+  voteRemove?: Address<TAccountVoteRemove>;
+  /** Optional vote account of the validator, its `node_pubkey` must be the validator identity */
+  validatorVoteAccount?: Address<TAccountValidatorVoteAccount>;
+  /**
+   * Optional delegation account - if present, authority must be the delegate and the
+   * delegation must grant the permission for this instruction
+   */
   delegation?: Address<TAccountDelegation>;
   /** The authority (either manager or delegated authority) */
   authority: TransactionSigner<TAccountAuthority>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   validatorIdentityAddress: VoteAddInstructionDataArgs['validatorIdentityAddress'];
   reason: VoteAddInstructionDataArgs['reason'];
+  category: VoteAddInstructionDataArgs['category'];
+  evidence: VoteAddInstructionDataArgs['evidence'];
 };
 
 export async function getVoteAddInstructionAsync<
   TAccountConfig extends string,
   TAccountStakePool extends string,
+  TAccountPoolOverride extends string,
   TAccountBlacklist extends string,
   TAccountVoteAdd extends string,
+  TAccountVoteRemove extends string,
+  TAccountValidatorVoteAccount extends string,
   TAccountDelegation extends string,
   TAccountAuthority extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: VoteAddAsyncInput<
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountBlacklist,
     TAccountVoteAdd,
+    TAccountVoteRemove,
+    TAccountValidatorVoteAccount,
     TAccountDelegation,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -186,11 +256,16 @@ export async function getVoteAddInstructionAsync<
     TProgramAddress,
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountBlacklist,
     TAccountVoteAdd,
+    TAccountVoteRemove,
+    TAccountValidatorVoteAccount,
     TAccountDelegation,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -201,11 +276,19 @@ export async function getVoteAddInstructionAsync<
   const originalAccounts = {
     config: { value: input.config ?? null, isWritable: false },
     stakePool: { value: input.stakePool ?? null, isWritable: false },
+    poolOverride: { value: input.poolOverride ?? null, isWritable: false },
     blacklist: { value: input.blacklist ?? null, isWritable: true },
     voteAdd: { value: input.voteAdd ?? null, isWritable: true },
+    voteRemove: { value: input.voteRemove ?? null, isWritable: false },
+    validatorVoteAccount: {
+      value: input.validatorVoteAccount ?? null,
+      isWritable: false,
+    },
     delegation: { value: input.delegation ?? null, isWritable: false },
     authority: { value: input.authority ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -216,6 +299,20 @@ export async function getVoteAddInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.poolOverride.value) {
+    accounts.poolOverride.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 111, 111, 108, 95, 111, 118, 101, 114, 114, 105, 100, 101,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.config.value)),
+        getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
+      ],
+    });
+  }
   if (!accounts.blacklist.value) {
     accounts.blacklist.value = await getProgramDerivedAddress({
       programAddress,
@@ -241,15 +338,16 @@ export async function getVoteAddInstructionAsync<
       ],
     });
   }
-  if (!accounts.delegation.value) {
-    accounts.delegation.value = await getProgramDerivedAddress({
+  if (!accounts.voteRemove.value) {
+    accounts.voteRemove.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([100, 101, 108, 101, 103, 97, 116, 105, 111, 110])
+          new Uint8Array([118, 111, 116, 101, 95, 114, 101, 109, 111, 118, 101])
         ),
         getAddressEncoder().encode(expectAddress(accounts.config.value)),
         getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
+        getAddressEncoder().encode(expectSome(args.validatorIdentityAddress)),
       ],
     });
   }
@@ -257,17 +355,35 @@ export async function getVoteAddInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.stakePool),
+      getAccountMeta(accounts.poolOverride),
       getAccountMeta(accounts.blacklist),
       getAccountMeta(accounts.voteAdd),
+      getAccountMeta(accounts.voteRemove),
+      getAccountMeta(accounts.validatorVoteAccount),
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getVoteAddInstructionDataEncoder().encode(
       args as VoteAddInstructionDataArgs
@@ -277,67 +393,105 @@ export async function getVoteAddInstructionAsync<
     TProgramAddress,
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountBlacklist,
     TAccountVoteAdd,
+    TAccountVoteRemove,
+    TAccountValidatorVoteAccount,
     TAccountDelegation,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type VoteAddInput<
   TAccountConfig extends string = string,
   TAccountStakePool extends string = string,
+  TAccountPoolOverride extends string = string,
   TAccountBlacklist extends string = string,
   TAccountVoteAdd extends string = string,
+  TAccountVoteRemove extends string = string,
+  TAccountValidatorVoteAccount extends string = string,
   TAccountDelegation extends string = string,
   TAccountAuthority extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
-  /** Global configuration account */
+  /** Global configuration account, voting and delegating are rejected while it is paused */
   config: Address<TAccountConfig>;
   /** The stake pool account for stake pool that is casting the vote */
   stakePool: Address<TAccountStakePool>;
+  /** Admin override of the stake pool, consulted before the program and TVL rules of the config */
+  poolOverride: Address<TAccountPoolOverride>;
   blacklist: Address<TAccountBlacklist>;
   voteAdd: Address<TAccountVoteAdd>;
-  /** Optional delegation account - if present, authority must be the delegate */
+  /** The remove vote of the same stake pool, which must not exist */
+  voteRemove: Address<TAccountVoteRemove>;
+  /** Optional vote account of the validator, its `node_pubkey` must be the validator identity */
+  validatorVoteAccount?: Address<TAccountValidatorVoteAccount>;
+  /**
+   * Optional delegation account - if present, authority must be the delegate and the
+   * delegation must grant the permission for this instruction
+   */
   delegation?: Address<TAccountDelegation>;
   /** The authority (either manager or delegated authority) */
   authority: TransactionSigner<TAccountAuthority>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   validatorIdentityAddress: VoteAddInstructionDataArgs['validatorIdentityAddress'];
   reason: VoteAddInstructionDataArgs['reason'];
+  category: VoteAddInstructionDataArgs['category'];
+  evidence: VoteAddInstructionDataArgs['evidence'];
 };
 
 export function getVoteAddInstruction<
   TAccountConfig extends string,
   TAccountStakePool extends string,
+  TAccountPoolOverride extends string,
   TAccountBlacklist extends string,
   TAccountVoteAdd extends string,
+  TAccountVoteRemove extends string,
+  TAccountValidatorVoteAccount extends string,
   TAccountDelegation extends string,
   TAccountAuthority extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: VoteAddInput<
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountBlacklist,
     TAccountVoteAdd,
+    TAccountVoteRemove,
+    TAccountValidatorVoteAccount,
     TAccountDelegation,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): VoteAddInstruction<
   TProgramAddress,
   TAccountConfig,
   TAccountStakePool,
+  TAccountPoolOverride,
   TAccountBlacklist,
   TAccountVoteAdd,
+  TAccountVoteRemove,
+  TAccountValidatorVoteAccount,
   TAccountDelegation,
   TAccountAuthority,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -347,11 +501,19 @@ export function getVoteAddInstruction<
   const originalAccounts = {
     config: { value: input.config ?? null, isWritable: false },
     stakePool: { value: input.stakePool ?? null, isWritable: false },
+    poolOverride: { value: input.poolOverride ?? null, isWritable: false },
     blacklist: { value: input.blacklist ?? null, isWritable: true },
     voteAdd: { value: input.voteAdd ?? null, isWritable: true },
+    voteRemove: { value: input.voteRemove ?? null, isWritable: false },
+    validatorVoteAccount: {
+      value: input.validatorVoteAccount ?? null,
+      isWritable: false,
+    },
     delegation: { value: input.delegation ?? null, isWritable: false },
     authority: { value: input.authority ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -372,11 +534,16 @@ export function getVoteAddInstruction<
     accounts: [
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.stakePool),
+      getAccountMeta(accounts.poolOverride),
       getAccountMeta(accounts.blacklist),
       getAccountMeta(accounts.voteAdd),
+      getAccountMeta(accounts.voteRemove),
+      getAccountMeta(accounts.validatorVoteAccount),
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getVoteAddInstructionDataEncoder().encode(
       args as VoteAddInstructionDataArgs
@@ -386,11 +553,16 @@ export function getVoteAddInstruction<
     TProgramAddress,
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountBlacklist,
     TAccountVoteAdd,
+    TAccountVoteRemove,
+    TAccountValidatorVoteAccount,
     TAccountDelegation,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -400,17 +572,28 @@ export type ParsedVoteAddInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** Global configuration account */
+    /** Global configuration account, voting and delegating are rejected while it is paused */
     config: TAccountMetas[0];
     /** The stake pool account for stake pool that is casting the vote */
     stakePool: TAccountMetas[1];
-    blacklist: TAccountMetas[2];
-    voteAdd: TAccountMetas[3];
-    /** Optional delegation account - if present, authority must be the delegate */
-    delegation?: TAccountMetas[4] | undefined;
+    /** Admin override of the stake pool, consulted before the program and TVL rules of the config */
+    poolOverride: TAccountMetas[2];
+    blacklist: TAccountMetas[3];
+    voteAdd: TAccountMetas[4];
+    /** The remove vote of the same stake pool, which must not exist */
+    voteRemove: TAccountMetas[5];
+    /** Optional vote account of the validator, its `node_pubkey` must be the validator identity */
+    validatorVoteAccount?: TAccountMetas[6] | undefined;
+    /**
+     * Optional delegation account - if present, authority must be the delegate and the
+     * delegation must grant the permission for this instruction
+     */
+    delegation?: TAccountMetas[7] | undefined;
     /** The authority (either manager or delegated authority) */
-    authority: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
+    authority: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
+    eventAuthority: TAccountMetas[10];
+    program: TAccountMetas[11];
   };
   data: VoteAddInstructionData;
 };
@@ -423,7 +606,7 @@ export function parseVoteAddInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedVoteAddInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -444,11 +627,16 @@ export function parseVoteAddInstruction<
     accounts: {
       config: getNextAccount(),
       stakePool: getNextAccount(),
+      poolOverride: getNextAccount(),
       blacklist: getNextAccount(),
       voteAdd: getNextAccount(),
+      voteRemove: getNextAccount(),
+      validatorVoteAccount: getNextOptionalAccount(),
       delegation: getNextOptionalAccount(),
       authority: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getVoteAddInstructionDataDecoder().decode(instruction.data),
   };
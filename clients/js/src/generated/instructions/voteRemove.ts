@@ -16,6 +16,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -33,6 +35,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -46,6 +50,16 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getEvidenceDecoder,
+  getEvidenceEncoder,
+  getReasonCategoryDecoder,
+  getReasonCategoryEncoder,
+  type Evidence,
+  type EvidenceArgs,
+  type ReasonCategory,
+  type ReasonCategoryArgs,
+} from '../types';
 
 export const VOTE_REMOVE_DISCRIMINATOR = new Uint8Array([
   203, 107, 232, 11, 28, 70, 64, 36,
@@ -59,13 +73,17 @@ export type VoteRemoveInstruction<
   TProgram extends string = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountStakePool extends string | AccountMeta<string> = string,
+  TAccountPoolOverride extends string | AccountMeta<string> = string,
   TAccountBlacklist extends string | AccountMeta<string> = string,
   TAccountVoteRemove extends string | AccountMeta<string> = string,
+  TAccountVoteAdd extends string | AccountMeta<string> = string,
   TAccountDelegation extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -77,12 +95,18 @@ export type VoteRemoveInstruction<
       TAccountStakePool extends string
         ? ReadonlyAccount<TAccountStakePool>
         : TAccountStakePool,
+      TAccountPoolOverride extends string
+        ? ReadonlyAccount<TAccountPoolOverride>
+        : TAccountPoolOverride,
       TAccountBlacklist extends string
         ? WritableAccount<TAccountBlacklist>
         : TAccountBlacklist,
       TAccountVoteRemove extends string
         ? WritableAccount<TAccountVoteRemove>
         : TAccountVoteRemove,
+      TAccountVoteAdd extends string
+        ? ReadonlyAccount<TAccountVoteAdd>
+        : TAccountVoteAdd,
       TAccountDelegation extends string
         ? ReadonlyAccount<TAccountDelegation>
         : TAccountDelegation,
@@ -93,6 +117,12 @@ export type VoteRemoveInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -101,11 +131,15 @@ export type VoteRemoveInstructionData = {
   discriminator: ReadonlyUint8Array;
   validatorIdentityAddress: Address;
   reason: string;
+  category: ReasonCategory;
+  evidence: Option<Evidence>;
 };
 
 export type VoteRemoveInstructionDataArgs = {
   validatorIdentityAddress: Address;
   reason: string;
+  category: ReasonCategoryArgs;
+  evidence: OptionOrNullable<EvidenceArgs>;
 };
 
 export function getVoteRemoveInstructionDataEncoder(): Encoder<VoteRemoveInstructionDataArgs> {
@@ -114,6 +148,8 @@ export function getVoteRemoveInstructionDataEncoder(): Encoder<VoteRemoveInstruc
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['validatorIdentityAddress', getAddressEncoder()],
       ['reason', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['category', getReasonCategoryEncoder()],
+      ['evidence', getOptionEncoder(getEvidenceEncoder())],
     ]),
     (value) => ({ ...value, discriminator: VOTE_REMOVE_DISCRIMINATOR })
   );
@@ -124,6 +160,8 @@ export function getVoteRemoveInstructionDataDecoder(): Decoder<VoteRemoveInstruc
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['validatorIdentityAddress', getAddressDecoder()],
     ['reason', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['category', getReasonCategoryDecoder()],
+    ['evidence', getOptionDecoder(getEvidenceDecoder())],
   ]);
 }
 
@@ -140,45 +178,68 @@ export function getVoteRemoveInstructionDataCodec(): Codec<
 export type VoteRemoveAsyncInput<
   TAccountConfig extends string = string,
   TAccountStakePool extends string = string,
+  TAccountPoolOverride extends string = string,
   TAccountBlacklist extends string = string,
   TAccountVoteRemove extends string = string,
+  TAccountVoteAdd extends string = string,
   TAccountDelegation extends string = string,
   TAccountAuthority extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
-  /** Global configuration account */
+  /** Global configuration account, voting and delegating are rejected while it is paused */
   config: Address<TAccountConfig>;
   /** The stake pool account to validate the authority */
   stakePool: Address<TAccountStakePool>;
+  /** Admin override of the stake pool, consulted before the program and TVL rules of the config */
+  poolOverride?: Address<TAccountPoolOverride>;
   blacklist?: Address<TAccountBlacklist>;
   voteRemove?: Address<TAccountVoteRemove>;
-  /** Optional delegation account - if present, authority must be the delegate */
+  /** The add vote of the same stake pool, which must not exist */
+  voteAdd?: Address<TAccountVoteAdd>;
+  /**
+   * Optional delegation account - if present, authority must be the delegate and the
+   * delegation must grant the permission for this instruction
+   */
   delegation?: Address<TAccountDelegation>;
   /** The authority (either manager or delegated authority) */
   authority: TransactionSigner<TAccountAuthority>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   validatorIdentityAddress: VoteRemoveInstructionDataArgs['validatorIdentityAddress'];
   reason: VoteRemoveInstructionDataArgs['reason'];
+  category: VoteRemoveInstructionDataArgs['category'];
+  evidence: VoteRemoveInstructionDataArgs['evidence'];
 };
 
 export async function getVoteRemoveInstructionAsync<
   TAccountConfig extends string,
   TAccountStakePool extends string,
+  TAccountPoolOverride extends string,
   TAccountBlacklist extends string,
   TAccountVoteRemove extends string,
+  TAccountVoteAdd extends string,
   TAccountDelegation extends string,
   TAccountAuthority extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: VoteRemoveAsyncInput<
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountBlacklist,
     TAccountVoteRemove,
+    TAccountVoteAdd,
     TAccountDelegation,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -186,11 +247,15 @@ export async function getVoteRemoveInstructionAsync<
     TProgramAddress,
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountBlacklist,
     TAccountVoteRemove,
+    TAccountVoteAdd,
     TAccountDelegation,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -201,11 +266,15 @@ export async function getVoteRemoveInstructionAsync<
   const originalAccounts = {
     config: { value: input.config ?? null, isWritable: false },
     stakePool: { value: input.stakePool ?? null, isWritable: false },
+    poolOverride: { value: input.poolOverride ?? null, isWritable: false },
     blacklist: { value: input.blacklist ?? null, isWritable: true },
     voteRemove: { value: input.voteRemove ?? null, isWritable: true },
+    voteAdd: { value: input.voteAdd ?? null, isWritable: false },
     delegation: { value: input.delegation ?? null, isWritable: false },
     authority: { value: input.authority ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -216,6 +285,20 @@ export async function getVoteRemoveInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.poolOverride.value) {
+    accounts.poolOverride.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 111, 111, 108, 95, 111, 118, 101, 114, 114, 105, 100, 101,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.config.value)),
+        getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
+      ],
+    });
+  }
   if (!accounts.blacklist.value) {
     accounts.blacklist.value = await getProgramDerivedAddress({
       programAddress,
@@ -241,15 +324,16 @@ export async function getVoteRemoveInstructionAsync<
       ],
     });
   }
-  if (!accounts.delegation.value) {
-    accounts.delegation.value = await getProgramDerivedAddress({
+  if (!accounts.voteAdd.value) {
+    accounts.voteAdd.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([100, 101, 108, 101, 103, 97, 116, 105, 111, 110])
+          new Uint8Array([118, 111, 116, 101, 95, 97, 100, 100])
         ),
         getAddressEncoder().encode(expectAddress(accounts.config.value)),
         getAddressEncoder().encode(expectAddress(accounts.stakePool.value)),
+        getAddressEncoder().encode(expectSome(args.validatorIdentityAddress)),
       ],
     });
   }
@@ -257,17 +341,34 @@ export async function getVoteRemoveInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.stakePool),
+      getAccountMeta(accounts.poolOverride),
       getAccountMeta(accounts.blacklist),
       getAccountMeta(accounts.voteRemove),
+      getAccountMeta(accounts.voteAdd),
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getVoteRemoveInstructionDataEncoder().encode(
       args as VoteRemoveInstructionDataArgs
@@ -277,67 +378,98 @@ export async function getVoteRemoveInstructionAsync<
     TProgramAddress,
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountBlacklist,
     TAccountVoteRemove,
+    TAccountVoteAdd,
     TAccountDelegation,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type VoteRemoveInput<
   TAccountConfig extends string = string,
   TAccountStakePool extends string = string,
+  TAccountPoolOverride extends string = string,
   TAccountBlacklist extends string = string,
   TAccountVoteRemove extends string = string,
+  TAccountVoteAdd extends string = string,
   TAccountDelegation extends string = string,
   TAccountAuthority extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
-  /** Global configuration account */
+  /** Global configuration account, voting and delegating are rejected while it is paused */
   config: Address<TAccountConfig>;
   /** The stake pool account to validate the authority */
   stakePool: Address<TAccountStakePool>;
+  /** Admin override of the stake pool, consulted before the program and TVL rules of the config */
+  poolOverride: Address<TAccountPoolOverride>;
   blacklist: Address<TAccountBlacklist>;
   voteRemove: Address<TAccountVoteRemove>;
-  /** Optional delegation account - if present, authority must be the delegate */
+  /** The add vote of the same stake pool, which must not exist */
+  voteAdd: Address<TAccountVoteAdd>;
+  /**
+   * Optional delegation account - if present, authority must be the delegate and the
+   * delegation must grant the permission for this instruction
+   */
   delegation?: Address<TAccountDelegation>;
   /** The authority (either manager or delegated authority) */
   authority: TransactionSigner<TAccountAuthority>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   validatorIdentityAddress: VoteRemoveInstructionDataArgs['validatorIdentityAddress'];
   reason: VoteRemoveInstructionDataArgs['reason'];
+  category: VoteRemoveInstructionDataArgs['category'];
+  evidence: VoteRemoveInstructionDataArgs['evidence'];
 };
 
 export function getVoteRemoveInstruction<
   TAccountConfig extends string,
   TAccountStakePool extends string,
+  TAccountPoolOverride extends string,
   TAccountBlacklist extends string,
   TAccountVoteRemove extends string,
+  TAccountVoteAdd extends string,
   TAccountDelegation extends string,
   TAccountAuthority extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof VALIDATOR_BLACKLIST_PROGRAM_ADDRESS,
 >(
   input: VoteRemoveInput<
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountBlacklist,
     TAccountVoteRemove,
+    TAccountVoteAdd,
     TAccountDelegation,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): VoteRemoveInstruction<
   TProgramAddress,
   TAccountConfig,
   TAccountStakePool,
+  TAccountPoolOverride,
   TAccountBlacklist,
   TAccountVoteRemove,
+  TAccountVoteAdd,
   TAccountDelegation,
   TAccountAuthority,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -347,11 +479,15 @@ export function getVoteRemoveInstruction<
   const originalAccounts = {
     config: { value: input.config ?? null, isWritable: false },
     stakePool: { value: input.stakePool ?? null, isWritable: false },
+    poolOverride: { value: input.poolOverride ?? null, isWritable: false },
     blacklist: { value: input.blacklist ?? null, isWritable: true },
     voteRemove: { value: input.voteRemove ?? null, isWritable: true },
+    voteAdd: { value: input.voteAdd ?? null, isWritable: false },
     delegation: { value: input.delegation ?? null, isWritable: false },
     authority: { value: input.authority ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -372,11 +508,15 @@ export function getVoteRemoveInstruction<
     accounts: [
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.stakePool),
+      getAccountMeta(accounts.poolOverride),
       getAccountMeta(accounts.blacklist),
       getAccountMeta(accounts.voteRemove),
+      getAccountMeta(accounts.voteAdd),
       getAccountMeta(accounts.delegation),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getVoteRemoveInstructionDataEncoder().encode(
       args as VoteRemoveInstructionDataArgs
@@ -386,11 +526,15 @@ export function getVoteRemoveInstruction<
     TProgramAddress,
     TAccountConfig,
     TAccountStakePool,
+    TAccountPoolOverride,
     TAccountBlacklist,
     TAccountVoteRemove,
+    TAccountVoteAdd,
     TAccountDelegation,
     TAccountAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -400,17 +544,26 @@ export type ParsedVoteRemoveInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** Global configuration account */
+    /** Global configuration account, voting and delegating are rejected while it is paused */
     config: TAccountMetas[0];
     /** The stake pool account to validate the authority */
     stakePool: TAccountMetas[1];
-    blacklist: TAccountMetas[2];
-    voteRemove: TAccountMetas[3];
-    /** Optional delegation account - if present, authority must be the delegate */
-    delegation?: TAccountMetas[4] | undefined;
+    /** Admin override of the stake pool, consulted before the program and TVL rules of the config */
+    poolOverride: TAccountMetas[2];
+    blacklist: TAccountMetas[3];
+    voteRemove: TAccountMetas[4];
+    /** The add vote of the same stake pool, which must not exist */
+    voteAdd: TAccountMetas[5];
+    /**
+     * Optional delegation account - if present, authority must be the delegate and the
+     * delegation must grant the permission for this instruction
+     */
+    delegation?: TAccountMetas[6] | undefined;
     /** The authority (either manager or delegated authority) */
-    authority: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
+    authority: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
+    eventAuthority: TAccountMetas[9];
+    program: TAccountMetas[10];
   };
   data: VoteRemoveInstructionData;
 };
@@ -423,7 +576,7 @@ export function parseVoteRemoveInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedVoteRemoveInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -444,11 +597,15 @@ export function parseVoteRemoveInstruction<
     accounts: {
       config: getNextAccount(),
       stakePool: getNextAccount(),
+      poolOverride: getNextAccount(),
       blacklist: getNextAccount(),
       voteRemove: getNextAccount(),
+      voteAdd: getNextAccount(),
       delegation: getNextOptionalAccount(),
       authority: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getVoteRemoveInstructionDataDecoder().decode(instruction.data),
   };
@@ -14,24 +14,45 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptAdminInstruction,
+  type ParsedCancelAdminProposalInstruction,
+  type ParsedChangeVoteInstruction,
+  type ParsedCloseBlacklistInstruction,
+  type ParsedCloseExpiredDelegationInstruction,
+  type ParsedCloseStaleDelegationInstruction,
   type ParsedDelegateInstruction,
+  type ParsedEditReasonInstruction,
+  type ParsedExpireVoteInstruction,
   type ParsedInitConfigInstruction,
+  type ParsedIsBlacklistedInstruction,
+  type ParsedLiftVetoInstruction,
+  type ParsedProposeAdminInstruction,
+  type ParsedPruneVoteInstruction,
+  type ParsedReaffirmVoteInstruction,
+  type ParsedRemovePoolOverrideInstruction,
+  type ParsedResizeConfigInstruction,
+  type ParsedSetPausedInstruction,
+  type ParsedSetPoolOverrideInstruction,
   type ParsedUndelegateInstruction,
   type ParsedUnvoteAddInstruction,
   type ParsedUnvoteRemoveInstruction,
-  type ParsedUpdateConfigAdminInstruction,
   type ParsedUpdateConfigInstruction,
+  type ParsedUpdateDelegateInstruction,
+  type ParsedVetoInstruction,
   type ParsedVoteAddInstruction,
+  type ParsedVoteAddManyInstruction,
   type ParsedVoteRemoveInstruction,
 } from '../instructions';
 
 export const VALIDATOR_BLACKLIST_PROGRAM_ADDRESS =
-  'C7662BVQCwLuorurd8vXohNczQuMHMhDqZ4JcMMge77d' as Address<'C7662BVQCwLuorurd8vXohNczQuMHMhDqZ4JcMMge77d'>;
+  'Fu4zvEKjgxWjaQifp7fyghKJfk6HzUCaJRvoGffJBm6Q' as Address<'Fu4zvEKjgxWjaQifp7fyghKJfk6HzUCaJRvoGffJBm6Q'>;
 
 export enum ValidatorBlacklistAccount {
   Blacklist,
   Config,
   Delegation,
+  PoolOverride,
+  Veto,
   VoteAddToBlacklist,
   VoteRemoveFromBlacklist,
 }
@@ -73,6 +94,28 @@ export function identifyValidatorBlacklistAccount(
   ) {
     return ValidatorBlacklistAccount.Delegation;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([246, 211, 80, 28, 41, 193, 225, 67])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistAccount.PoolOverride;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([34, 9, 41, 60, 176, 6, 213, 231])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistAccount.Veto;
+  }
   if (
     containsBytes(
       data,
@@ -101,14 +144,33 @@ export function identifyValidatorBlacklistAccount(
 }
 
 export enum ValidatorBlacklistInstruction {
+  AcceptAdmin,
+  CancelAdminProposal,
+  ChangeVote,
+  CloseBlacklist,
+  CloseExpiredDelegation,
+  CloseStaleDelegation,
   Delegate,
+  EditReason,
+  ExpireVote,
   InitConfig,
+  IsBlacklisted,
+  LiftVeto,
+  ProposeAdmin,
+  PruneVote,
+  ReaffirmVote,
+  RemovePoolOverride,
+  ResizeConfig,
+  SetPaused,
+  SetPoolOverride,
   Undelegate,
   UnvoteAdd,
   UnvoteRemove,
   UpdateConfig,
-  UpdateConfigAdmin,
+  UpdateDelegate,
+  Veto,
   VoteAdd,
+  VoteAddMany,
   VoteRemove,
 }
 
@@ -116,6 +178,72 @@ export function identifyValidatorBlacklistInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): ValidatorBlacklistInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 42, 45, 90, 116, 181, 13, 170])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.AcceptAdmin;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([68, 6, 145, 131, 16, 73, 182, 229])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.CancelAdminProposal;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([184, 39, 97, 137, 83, 108, 185, 75])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.ChangeVote;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([238, 228, 49, 157, 79, 195, 209, 72])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.CloseBlacklist;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([218, 143, 241, 99, 54, 63, 216, 201])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.CloseExpiredDelegation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([19, 212, 116, 212, 135, 224, 112, 168])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.CloseStaleDelegation;
+  }
   if (
     containsBytes(
       data,
@@ -127,6 +255,28 @@ export function identifyValidatorBlacklistInstruction(
   ) {
     return ValidatorBlacklistInstruction.Delegate;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 224, 15, 31, 237, 162, 238, 79])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.EditReason;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([210, 217, 219, 119, 252, 165, 158, 205])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.ExpireVote;
+  }
   if (
     containsBytes(
       data,
@@ -138,6 +288,105 @@ export function identifyValidatorBlacklistInstruction(
   ) {
     return ValidatorBlacklistInstruction.InitConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([161, 58, 62, 208, 128, 170, 119, 18])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.IsBlacklisted;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([99, 231, 218, 155, 42, 95, 245, 14])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.LiftVeto;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([121, 214, 199, 212, 87, 39, 117, 234])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.ProposeAdmin;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([86, 56, 158, 170, 235, 107, 230, 96])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.PruneVote;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([195, 192, 117, 158, 246, 177, 185, 53])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.ReaffirmVote;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([39, 119, 175, 244, 36, 57, 185, 215])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.RemovePoolOverride;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([198, 164, 124, 15, 186, 11, 212, 150])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.ResizeConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([91, 60, 125, 192, 176, 225, 166, 218])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.SetPaused;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([203, 124, 47, 114, 184, 58, 170, 194])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.SetPoolOverride;
+  }
   if (
     containsBytes(
       data,
@@ -186,12 +435,23 @@ export function identifyValidatorBlacklistInstruction(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([30, 254, 195, 1, 169, 26, 214, 39])
+        new Uint8Array([190, 202, 103, 138, 167, 197, 25, 9])
       ),
       0
     )
   ) {
-    return ValidatorBlacklistInstruction.UpdateConfigAdmin;
+    return ValidatorBlacklistInstruction.UpdateDelegate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([190, 152, 69, 226, 61, 150, 107, 215])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.Veto;
   }
   if (
     containsBytes(
@@ -204,6 +464,17 @@ export function identifyValidatorBlacklistInstruction(
   ) {
     return ValidatorBlacklistInstruction.VoteAdd;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([110, 197, 15, 208, 159, 97, 83, 114])
+      ),
+      0
+    )
+  ) {
+    return ValidatorBlacklistInstruction.VoteAddMany;
+  }
   if (
     containsBytes(
       data,
@@ -221,14 +492,65 @@ export function identifyValidatorBlacklistInstruction(
 }
 
 export type ParsedValidatorBlacklistInstruction<
-  TProgram extends string = 'C7662BVQCwLuorurd8vXohNczQuMHMhDqZ4JcMMge77d',
+  TProgram extends string = 'Fu4zvEKjgxWjaQifp7fyghKJfk6HzUCaJRvoGffJBm6Q',
 > =
+  | ({
+      instructionType: ValidatorBlacklistInstruction.AcceptAdmin;
+    } & ParsedAcceptAdminInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.CancelAdminProposal;
+    } & ParsedCancelAdminProposalInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.ChangeVote;
+    } & ParsedChangeVoteInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.CloseBlacklist;
+    } & ParsedCloseBlacklistInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.CloseExpiredDelegation;
+    } & ParsedCloseExpiredDelegationInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.CloseStaleDelegation;
+    } & ParsedCloseStaleDelegationInstruction<TProgram>)
   | ({
       instructionType: ValidatorBlacklistInstruction.Delegate;
     } & ParsedDelegateInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.EditReason;
+    } & ParsedEditReasonInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.ExpireVote;
+    } & ParsedExpireVoteInstruction<TProgram>)
   | ({
       instructionType: ValidatorBlacklistInstruction.InitConfig;
     } & ParsedInitConfigInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.IsBlacklisted;
+    } & ParsedIsBlacklistedInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.LiftVeto;
+    } & ParsedLiftVetoInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.ProposeAdmin;
+    } & ParsedProposeAdminInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.PruneVote;
+    } & ParsedPruneVoteInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.ReaffirmVote;
+    } & ParsedReaffirmVoteInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.RemovePoolOverride;
+    } & ParsedRemovePoolOverrideInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.ResizeConfig;
+    } & ParsedResizeConfigInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.SetPaused;
+    } & ParsedSetPausedInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.SetPoolOverride;
+    } & ParsedSetPoolOverrideInstruction<TProgram>)
   | ({
       instructionType: ValidatorBlacklistInstruction.Undelegate;
     } & ParsedUndelegateInstruction<TProgram>)
@@ -242,11 +564,17 @@ export type ParsedValidatorBlacklistInstruction<
       instructionType: ValidatorBlacklistInstruction.UpdateConfig;
     } & ParsedUpdateConfigInstruction<TProgram>)
   | ({
-      instructionType: ValidatorBlacklistInstruction.UpdateConfigAdmin;
-    } & ParsedUpdateConfigAdminInstruction<TProgram>)
+      instructionType: ValidatorBlacklistInstruction.UpdateDelegate;
+    } & ParsedUpdateDelegateInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.Veto;
+    } & ParsedVetoInstruction<TProgram>)
   | ({
       instructionType: ValidatorBlacklistInstruction.VoteAdd;
     } & ParsedVoteAddInstruction<TProgram>)
+  | ({
+      instructionType: ValidatorBlacklistInstruction.VoteAddMany;
+    } & ParsedVoteAddManyInstruction<TProgram>)
   | ({
       instructionType: ValidatorBlacklistInstruction.VoteRemove;
     } & ParsedVoteRemoveInstruction<TProgram>);
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+use crate::error::ValidatorBlacklistError;
+
+/// Create a program owned PDA of `space` bytes, paid by `payer`. Like Anchor's `init`, this
+/// also works when the address was already funded by someone else.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer = &[signer_seeds];
+
+    if account.lamports() == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: account.clone() },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+        return Ok(());
+    }
+
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate { account_to_allocate: account.clone() },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign { account_to_assign: account.clone() },
+            signer,
+        ),
+        &crate::ID,
+    )?;
+
+    Ok(())
+}
+
+/// Resize a program owned account to `space` bytes, topping up the rent from `payer` or
+/// refunding the excess to `refund_recipient`. Same as Anchor's `realloc` constraint, for
+/// accounts that are not deserialized into an `Account` or whose rent belongs to someone else.
+pub fn resize_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    refund_recipient: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+
+    if rent > lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            rent - lamports,
+        )?;
+    } else if lamports > rent {
+        **refund_recipient.try_borrow_mut_lamports()? = refund_recipient.lamports()
+            .checked_add(lamports - rent)
+            .ok_or(ValidatorBlacklistError::MathOverflow)?;
+        **account.try_borrow_mut_lamports()? = rent;
+    }
+    account.resize(space)?;
+
+    Ok(())
+}
+
+/// Close a program owned account, moving its rent to `destination`. Same as Anchor's `close`
+/// constraint, for accounts that are not deserialized into an `Account`.
+pub fn close_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination.lamports()
+        .checked_add(lamports)
+        .ok_or(ValidatorBlacklistError::MathOverflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&system_program::ID);
+    account.resize(0)?;
+
+    Ok(())
+}
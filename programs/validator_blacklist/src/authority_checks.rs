@@ -1,13 +1,21 @@
 use anchor_lang::prelude::*;
-use spl_stake_pool::state::StakePool;
+use crate::{state::{AuthorityPolicy, Delegation}, error::ValidatorBlacklistError, pool_adapters::PoolInfo};
 
-use crate::{state::Delegation, error::ValidatorBlacklistError};
-
-pub fn check_authority(delegation: Option<&Delegation>, stake_pool_address: &Pubkey, stake_pool: &StakePool, authority: &Pubkey) -> Result<()> {
+/// Check that `authority` may act for the stake pool, either directly in a role allowed by `policy`
+/// or as a delegate holding `permission` (one of the `Delegation::PERMISSION_*` bits)
+pub fn check_authority(delegation: Option<&Delegation>, config: &Pubkey, policy: AuthorityPolicy, stake_pool_address: &Pubkey, stake_pool: &PoolInfo, authority: &Pubkey, permission: u8) -> Result<()> {
 
     if let Some(delegation) = delegation {
 
         // Using delegated authority
+
+        // Delegations are no longer derived from the instruction accounts, so make sure
+        // the delegation belongs to this config
+        require_keys_eq!(
+            delegation.config,
+            *config,
+            ValidatorBlacklistError::InvalidDelegationConfig
+        );
    
         // Make sure the delegation is valid for the stake pool         
         require_keys_eq!(
@@ -27,20 +35,39 @@ pub fn check_authority(delegation: Option<&Delegation>, stake_pool_address: &Pub
         // delegate instruction, but adding for safety)
         require_keys_eq!(
             delegation.manager,
-            stake_pool.manager,
+            stake_pool.authority,
             ValidatorBlacklistError::InvalidDelegate
         );
 
+        // Make sure the delegation has not run out
+        require!(
+            !delegation.is_expired(Clock::get()?.unix_timestamp),
+            ValidatorBlacklistError::DelegationExpired
+        );
+
+        // Make sure the delegation is scoped to this instruction
+        require!(
+            delegation.permissions & permission == permission,
+            ValidatorBlacklistError::MissingPermission
+        );
+
     } else {
         
-        // Direct authority, i.e. it should be signed by the stake pool manager
-        
-        require_keys_eq!(
-            stake_pool.manager,
-            *authority,
-            ValidatorBlacklistError::InvalidManager
-        );
+        // Direct authority, i.e. it should be signed by a stake pool role allowed by the policy
+
+        if policy == AuthorityPolicy::ManagerOnly {
+            require_keys_eq!(
+                stake_pool.authority,
+                *authority,
+                ValidatorBlacklistError::InvalidManager
+            );
+        } else {
+            require!(
+                policy.allows(stake_pool, authority),
+                ValidatorBlacklistError::InvalidPoolAuthority
+            );
+        }
     };
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ValidatorBlacklistError;
+use crate::state::{AllowedProgram, AuthorityPolicy, Config, PoolAdapter, CONFIG_VERSION};
+
+/// Size of a config created before namespaces and layout versions were introduced, a keypair
+/// account with room for 10 allowed programs. No versioned layout has this size.
+pub const LEGACY_CONFIG_LEN: usize = 8 + 32 + 8 + 4 + (32 * 10); // discriminator + admin + min_tvl + vec len + allowed_programs
+
+/// `Config` before namespaces and layout versions were introduced
+#[derive(AnchorDeserialize)]
+struct LegacyConfig {
+    admin: Pubkey,
+    min_tvl: u64,
+    allowed_programs: Vec<Pubkey>,
+}
+
+/// Read a config account and bring it to the current layout. Besides the current layout this
+/// accepts the unversioned legacy layout, which has no version byte and is told apart by its
+/// size. Fields it did not have get the defaults of the CLI's `create-config`.
+pub fn read_migrated_config(data: &[u8]) -> Result<Config> {
+    require!(
+        data.len() >= 8 && data[..8] == *Config::DISCRIMINATOR,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    if data.len() != LEGACY_CONFIG_LEN {
+        let config = Config::try_deserialize(&mut &data[..])?;
+        require!(
+            config.version == CONFIG_VERSION,
+            ValidatorBlacklistError::UnsupportedConfigVersion
+        );
+        return Ok(config);
+    }
+
+    let legacy = LegacyConfig::deserialize(&mut &data[8..])?;
+
+    Ok(Config {
+        version: CONFIG_VERSION,
+        admin: legacy.admin,
+        min_tvl: legacy.min_tvl,
+        allowed_programs: legacy.allowed_programs
+            .into_iter()
+            .map(|program_id| AllowedProgram {
+                program_id,
+                min_tvl: None,
+                adapter: PoolAdapter::SplStakePool,
+            })
+            .collect(),
+        min_add_votes: 1,
+        min_net_votes: 1,
+        pending_admin: None,
+        // A keypair account has no namespace and no bump
+        namespace: String::new(),
+        bump: 0,
+        paused: false,
+        vote_lifetime: 0,
+        authority_policy: AuthorityPolicy::default(),
+    })
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::state::BlacklistInfo;
+
+/// Derive the blacklist account address of a validator for the given config
+pub fn find_blacklist_address(config: &Pubkey, validator_identity_address: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"blacklist", config.as_ref(), validator_identity_address.as_ref()],
+        &crate::ID,
+    ).0
+}
+
+/// Query the blacklist status of a validator via CPI and decode the returned data
+pub fn is_blacklisted<'info>(
+    validator_blacklist_program: AccountInfo<'info>,
+    config: AccountInfo<'info>,
+    blacklist: AccountInfo<'info>,
+    validator_identity_address: Pubkey,
+) -> Result<BlacklistInfo> {
+    let cpi_ctx = CpiContext::new(
+        validator_blacklist_program,
+        crate::cpi::accounts::IsBlacklisted { config, blacklist },
+    );
+
+    Ok(crate::cpi::is_blacklisted(cpi_ctx, validator_identity_address)?.get())
+}
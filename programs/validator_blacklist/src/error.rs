@@ -22,4 +22,66 @@ pub enum ValidatorBlacklistError {
     UnauthorizedStakePoolProgram,
     #[msg("Only the admin can perform this action")]
     UnauthorizedAdmin,
+    #[msg("There is no pending admin proposal")]
+    NoPendingAdmin,
+    #[msg("The signer is not the pending admin")]
+    InvalidPendingAdmin,
+    #[msg("The namespace must be between 1 and 32 bytes long")]
+    InvalidNamespace,
+    #[msg("The allowed programs do not fit into the config account, resize it first")]
+    TooManyAllowedPrograms,
+    #[msg("The config capacity must hold the current allowed programs and not exceed the maximum")]
+    InvalidConfigCapacity,
+    #[msg("Voting and delegating are paused for this config")]
+    VotingPaused,
+    #[msg("The vote lifetime cannot be negative")]
+    InvalidVoteLifetime,
+    #[msg("Exactly one of the vote_add and vote_remove accounts must be provided")]
+    InvalidVoteAccount,
+    #[msg("The vote has not outlived the vote lifetime")]
+    VoteNotExpired,
+    #[msg("The payer does not match the payer of the vote")]
+    InvalidPayer,
+    #[msg("The stake pool still meets the config requirements")]
+    StakePoolStillQualifies,
+    #[msg("A delegation by the current manager already exists at this index")]
+    DelegationAlreadyExists,
+    #[msg("The delegation was created by the current manager of the stake pool")]
+    DelegationNotStale,
+    #[msg("The permissions must be a non-empty combination of the known permission bits")]
+    InvalidPermissions,
+    #[msg("The delegation does not grant the permission for this instruction")]
+    MissingPermission,
+    #[msg("The delegation belongs to a different config")]
+    InvalidDelegationConfig,
+    #[msg("The delegation has expired")]
+    DelegationExpired,
+    #[msg("The delegation expiry must be in the future")]
+    InvalidDelegationExpiry,
+    #[msg("The delegation has not expired")]
+    DelegationNotExpired,
+    #[msg("The evidence URI exceeds the maximum allowed length")]
+    EvidenceUriTooLong,
+    #[msg("The blacklist entry still has votes")]
+    BlacklistNotEmpty,
+    #[msg("The stake pool already voted the other way on this validator")]
+    ConflictingVote,
+    #[msg("The stake pool already voted on this validator")]
+    VoteAlreadyCast,
+    #[msg("The remaining accounts do not match the votes of the batch")]
+    InvalidBatchAccounts,
+    #[msg("The vote account does not belong to the validator identity")]
+    InvalidValidatorVoteAccount,
+    #[msg("The signer does not hold a stake pool role allowed by the authority policy")]
+    InvalidPoolAuthority,
+    #[msg("The stake pool is denied by an admin override")]
+    StakePoolDenied,
+    #[msg("The pool override account is invalid")]
+    InvalidPoolOverride,
+    #[msg("The blacklist entry is vetoed by the admin")]
+    BlacklistVetoed,
+    #[msg("The config layout version is not supported, migrate the config with resize_config")]
+    UnsupportedConfigVersion,
+    #[msg("The previous manager does not match the manager of the delegation")]
+    InvalidPreviousManager,
 }
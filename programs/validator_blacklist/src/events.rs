@@ -0,0 +1,334 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AllowedProgram, AuthorityPolicy, BlacklistStatus, PoolOverrideKind, Evidence, ReasonCategory, VoteDirection};
+
+/// Emitted when a config is initialized
+#[event]
+pub struct InitConfigEvent {
+    pub config: Pubkey,
+    pub namespace: String,
+    pub admin: Pubkey,
+    pub min_tvl: u64,
+    pub allowed_programs: Vec<AllowedProgram>,
+    pub min_add_votes: u64,
+    pub min_net_votes: u64,
+    pub slot: u64,
+}
+
+/// Emitted when the config settings are updated, carrying the resulting settings
+#[event]
+pub struct UpdateConfigEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub min_tvl: u64,
+    pub allowed_programs: Vec<AllowedProgram>,
+    pub min_add_votes: u64,
+    pub min_net_votes: u64,
+    pub vote_lifetime: i64,
+    pub authority_policy: AuthorityPolicy,
+    pub slot: u64,
+}
+
+/// Emitted when the config account is resized
+#[event]
+pub struct ResizeConfigEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub capacity: u16,
+    pub version: u8,
+    pub slot: u64,
+}
+
+/// Emitted when the admin pauses or resumes voting
+#[event]
+pub struct SetPausedEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub slot: u64,
+}
+
+/// Emitted when the admin allows or denies a single stake pool
+#[event]
+pub struct SetPoolOverrideEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub stake_pool: Pubkey,
+    pub kind: PoolOverrideKind,
+    pub slot: u64,
+}
+
+/// Emitted when the admin removes the override of a stake pool
+#[event]
+pub struct RemovePoolOverrideEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub stake_pool: Pubkey,
+    pub kind: PoolOverrideKind,
+    pub slot: u64,
+}
+
+/// Emitted when the admin vetoes a blacklist entry
+#[event]
+pub struct VetoEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub reason: String,
+    pub status: BlacklistStatus,
+    pub slot: u64,
+}
+
+/// Emitted when the admin lifts the veto of a blacklist entry
+#[event]
+pub struct LiftVetoEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub status: BlacklistStatus,
+    pub slot: u64,
+}
+
+/// Emitted when the admin proposes a new admin
+#[event]
+pub struct ProposeAdminEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub slot: u64,
+}
+
+/// Emitted when the pending admin accepts and becomes the admin
+#[event]
+pub struct AcceptAdminEvent {
+    pub config: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub slot: u64,
+}
+
+/// Emitted when the admin cancels a pending admin proposal
+#[event]
+pub struct CancelAdminProposalEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+    pub slot: u64,
+}
+
+/// Emitted when a stake pool manager delegates its voting authority
+#[event]
+pub struct DelegateEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub manager: Pubkey,
+    pub delegate: Pubkey,
+    pub index: u8,
+    pub permissions: u8,
+    pub expires_at: Option<i64>,
+    pub slot: u64,
+}
+
+/// Emitted when a stake pool manager removes a delegation
+#[event]
+pub struct UndelegateEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub manager: Pubkey,
+    pub delegate: Pubkey,
+    pub index: u8,
+    pub slot: u64,
+}
+
+/// Emitted when a stake pool manager replaces the delegate of a delegation
+#[event]
+pub struct UpdateDelegateEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub manager: Pubkey,
+    pub index: u8,
+    pub old_delegate: Pubkey,
+    pub new_delegate: Pubkey,
+    pub slot: u64,
+}
+
+/// Emitted when a delegation created by a previous stake pool manager is closed
+#[event]
+pub struct CloseStaleDelegationEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub manager: Pubkey,
+    pub delegate: Pubkey,
+    pub index: u8,
+    pub slot: u64,
+}
+
+/// Emitted when a delegation that ran out is closed
+#[event]
+pub struct CloseExpiredDelegationEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub manager: Pubkey,
+    pub delegate: Pubkey,
+    pub index: u8,
+    pub expires_at: i64,
+    pub slot: u64,
+}
+
+/// Emitted when a vote to add a validator to the blacklist is cast
+#[event]
+pub struct VoteAddEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub authority: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub reason: String,
+    pub category: ReasonCategory,
+    pub evidence: Option<Evidence>,
+    pub stake_lamports: u64,
+    pub tally_add: u64,
+    pub tally_remove: u64,
+    pub stake_tally_add: u64,
+    pub stake_tally_remove: u64,
+    pub status: BlacklistStatus,
+    pub slot: u64,
+}
+
+/// Emitted when a vote to remove a validator from the blacklist is cast
+#[event]
+pub struct VoteRemoveEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub authority: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub reason: String,
+    pub category: ReasonCategory,
+    pub evidence: Option<Evidence>,
+    pub stake_lamports: u64,
+    pub tally_add: u64,
+    pub tally_remove: u64,
+    pub stake_tally_add: u64,
+    pub stake_tally_remove: u64,
+    pub status: BlacklistStatus,
+    pub slot: u64,
+}
+
+/// Emitted when a stake pool flips its vote on a validator, `direction` is the new vote
+#[event]
+pub struct ChangeVoteEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub authority: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub direction: VoteDirection,
+    pub reason: String,
+    pub category: ReasonCategory,
+    pub evidence: Option<Evidence>,
+    pub stake_lamports: u64,
+    pub tally_add: u64,
+    pub tally_remove: u64,
+    pub stake_tally_add: u64,
+    pub stake_tally_remove: u64,
+    pub status: BlacklistStatus,
+    pub slot: u64,
+}
+
+/// Emitted when a vote to add a validator is withdrawn, carrying the reason of the withdrawn vote
+#[event]
+pub struct UnvoteAddEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub authority: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub reason: String,
+    pub stake_lamports: u64,
+    pub tally_add: u64,
+    pub tally_remove: u64,
+    pub stake_tally_add: u64,
+    pub stake_tally_remove: u64,
+    pub status: BlacklistStatus,
+    pub slot: u64,
+}
+
+/// Emitted when a vote to remove a validator is withdrawn, carrying the reason of the withdrawn vote
+#[event]
+pub struct UnvoteRemoveEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub authority: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub reason: String,
+    pub stake_lamports: u64,
+    pub tally_add: u64,
+    pub tally_remove: u64,
+    pub stake_tally_add: u64,
+    pub stake_tally_remove: u64,
+    pub status: BlacklistStatus,
+    pub slot: u64,
+}
+
+/// Emitted when a vote is reaffirmed, restarting its lifetime
+#[event]
+pub struct ReaffirmVoteEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub authority: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub direction: VoteDirection,
+    pub slot: u64,
+}
+
+/// Emitted when the reason of a vote is replaced
+#[event]
+pub struct EditReasonEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub authority: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub direction: VoteDirection,
+    pub reason: String,
+    pub slot: u64,
+}
+
+/// Emitted when a vote that outlived the vote lifetime is closed
+#[event]
+pub struct ExpireVoteEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub direction: VoteDirection,
+    pub payer: Pubkey,
+    pub stake_lamports: u64,
+    pub tally_add: u64,
+    pub tally_remove: u64,
+    pub stake_tally_add: u64,
+    pub stake_tally_remove: u64,
+    pub status: BlacklistStatus,
+    pub slot: u64,
+}
+
+/// Emitted when a blacklist entry without votes is closed
+#[event]
+pub struct CloseBlacklistEvent {
+    pub config: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub payer: Pubkey,
+    pub slot: u64,
+}
+
+/// Emitted when a vote of a stake pool that no longer qualifies is closed
+#[event]
+pub struct PruneVoteEvent {
+    pub config: Pubkey,
+    pub stake_pool: Pubkey,
+    pub validator_identity_address: Pubkey,
+    pub direction: VoteDirection,
+    pub payer: Pubkey,
+    pub stake_lamports: u64,
+    pub tally_add: u64,
+    pub tally_remove: u64,
+    pub stake_tally_add: u64,
+    pub stake_tally_remove: u64,
+    pub status: BlacklistStatus,
+    pub slot: u64,
+}
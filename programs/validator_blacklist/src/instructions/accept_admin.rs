@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::AcceptAdminEvent;
+
+/// Accept a pending admin proposal and become the admin of the config
+pub fn accept_admin(
+    ctx: Context<AcceptAdmin>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_admin = config.admin;
+
+    let pending_admin = config.pending_admin
+        .ok_or(ValidatorBlacklistError::NoPendingAdmin)?;
+
+    require_keys_eq!(
+        pending_admin,
+        ctx.accounts.pending_admin.key(),
+        ValidatorBlacklistError::InvalidPendingAdmin
+    );
+
+    config.admin = pending_admin;
+    config.pending_admin = None;
+
+    msg!("Config admin updated to: {}", pending_admin);
+
+    emit_cpi!(AcceptAdminEvent {
+        config: config.key(),
+        previous_admin,
+        new_admin: pending_admin,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The proposed admin (must match the config's pending_admin field)
+    pub pending_admin: Signer<'info>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::CancelAdminProposalEvent;
+
+/// Cancel a pending admin proposal
+pub fn cancel_admin_proposal(
+    ctx: Context<CancelAdminProposal>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let cancelled_admin = config.pending_admin
+        .take()
+        .ok_or(ValidatorBlacklistError::NoPendingAdmin)?;
+
+    msg!("Config admin proposal for {} cancelled", cancelled_admin);
+
+    emit_cpi!(CancelAdminProposalEvent {
+        config: config.key(),
+        admin: config.admin,
+        cancelled_admin,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    #[account(
+        mut,
+        has_one = admin @ ValidatorBlacklistError::UnauthorizedAdmin,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
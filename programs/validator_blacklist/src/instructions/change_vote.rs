@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use crate::account_helpers::{close_pda_account, create_pda_account};
+use crate::authority_checks;
+use crate::pool_adapters::{load_stake_pool, validate_stake_pool_config};
+use crate::state::{Blacklist, Config, Delegation, Evidence, ReasonCategory, VoteAddToBlacklist, VoteDirection, VoteRemoveFromBlacklist, MAX_EVIDENCE_URI_LENGTH, MAX_REASON_LENGTH, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::ChangeVoteEvent;
+
+/// Flip the vote of a stake pool on a validator to `direction`. Closes the vote the other way,
+/// refunding its rent to whoever paid for it, and casts the new vote in the same step.
+pub fn change_vote(
+    ctx: Context<ChangeVote>,
+    validator_identity_address: Pubkey,
+    direction: VoteDirection,
+    reason: String,
+    category: ReasonCategory,
+    evidence: Option<Evidence>,
+) -> Result<()> {
+
+    require!(
+        reason.len() <= MAX_REASON_LENGTH,
+        ValidatorBlacklistError::ReasonTooLong
+    );
+    if let Some(evidence) = &evidence {
+        require!(
+            evidence.uri.len() <= MAX_EVIDENCE_URI_LENGTH,
+            ValidatorBlacklistError::EvidenceUriTooLong
+        );
+    }
+
+    let clock = Clock::get()?;
+    let stake_pool = load_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.config)?;
+
+    // Validate stake pool meets config requirements
+    validate_stake_pool_config(
+        &stake_pool,
+        ctx.accounts.stake_pool.owner,
+        &ctx.accounts.config,
+        &ctx.accounts.pool_override,
+    )?;
+
+    // Withdrawing the old vote and casting the new one need both permissions
+    let permission = match direction {
+        VoteDirection::Add => Delegation::PERMISSION_UNVOTE_REMOVE | Delegation::PERMISSION_VOTE_ADD,
+        VoteDirection::Remove => Delegation::PERMISSION_UNVOTE_ADD | Delegation::PERMISSION_VOTE_REMOVE,
+    };
+
+    // Validate the authority
+    authority_checks::check_authority(
+        ctx.accounts.delegation.as_deref(),
+        &ctx.accounts.config.key(),
+        ctx.accounts.config.authority_policy,
+        &ctx.accounts.stake_pool.key(),
+        &stake_pool,
+        &ctx.accounts.authority.key(),
+        permission)?;
+
+    let config_key = ctx.accounts.config.key();
+    let stake_pool_key = ctx.accounts.stake_pool.key();
+    let vote_add = ctx.accounts.vote_add.to_account_info();
+    let vote_remove = ctx.accounts.vote_remove.to_account_info();
+    let authority = ctx.accounts.authority.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let blacklist = &mut ctx.accounts.blacklist;
+    let evidence_uri_len = evidence.as_ref().map(|evidence| evidence.uri.len());
+
+    match direction {
+        VoteDirection::Add => {
+            require!(vote_add.data_is_empty(), ValidatorBlacklistError::ConflictingVote);
+
+            // Withdraw the remove vote
+            let old_vote = load_vote::<VoteRemoveFromBlacklist>(&vote_remove)?;
+            require_keys_eq!(old_vote.payer, payer.key(), ValidatorBlacklistError::InvalidPayer);
+            blacklist.remove_remove_vote(old_vote.stake_lamports)?;
+            close_pda_account(&vote_remove, &payer)?;
+
+            // Cast the add vote
+            let bump = [ctx.bumps.vote_add];
+            let seeds: &[&[u8]] = &[b"vote_add", config_key.as_ref(), stake_pool_key.as_ref(), validator_identity_address.as_ref(), &bump];
+            create_pda_account(&vote_add, &authority, &system_program, VoteAddToBlacklist::space(reason.len(), evidence_uri_len), seeds)?;
+
+            let new_vote = VoteAddToBlacklist {
+                stake_pool: stake_pool_key,
+                validator_identity_address,
+                reason: reason.clone(),
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+                stake_lamports: stake_pool.tvl,
+                payer: authority.key(),
+                category,
+                evidence: evidence.clone(),
+            };
+            new_vote.try_serialize(&mut &mut vote_add.try_borrow_mut_data()?[..])?;
+
+            blacklist.tally_add = blacklist.tally_add.checked_add(1)
+                .ok_or(ValidatorBlacklistError::MathOverflow)?;
+            blacklist.stake_tally_add = blacklist.stake_tally_add.checked_add(stake_pool.tvl)
+                .ok_or(ValidatorBlacklistError::MathOverflow)?;
+        }
+        VoteDirection::Remove => {
+            require!(vote_remove.data_is_empty(), ValidatorBlacklistError::ConflictingVote);
+
+            // Withdraw the add vote
+            let old_vote = load_vote::<VoteAddToBlacklist>(&vote_add)?;
+            require_keys_eq!(old_vote.payer, payer.key(), ValidatorBlacklistError::InvalidPayer);
+            blacklist.remove_add_vote(old_vote.stake_lamports)?;
+            close_pda_account(&vote_add, &payer)?;
+
+            // Cast the remove vote
+            let bump = [ctx.bumps.vote_remove];
+            let seeds: &[&[u8]] = &[b"vote_remove", config_key.as_ref(), stake_pool_key.as_ref(), validator_identity_address.as_ref(), &bump];
+            create_pda_account(&vote_remove, &authority, &system_program, VoteRemoveFromBlacklist::space(reason.len(), evidence_uri_len), seeds)?;
+
+            let new_vote = VoteRemoveFromBlacklist {
+                stake_pool: stake_pool_key,
+                validator_identity_address,
+                reason: reason.clone(),
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+                stake_lamports: stake_pool.tvl,
+                payer: authority.key(),
+                category,
+                evidence: evidence.clone(),
+            };
+            new_vote.try_serialize(&mut &mut vote_remove.try_borrow_mut_data()?[..])?;
+
+            blacklist.tally_remove = blacklist.tally_remove.checked_add(1)
+                .ok_or(ValidatorBlacklistError::MathOverflow)?;
+            blacklist.stake_tally_remove = blacklist.stake_tally_remove.checked_add(stake_pool.tvl)
+                .ok_or(ValidatorBlacklistError::MathOverflow)?;
+        }
+    }
+
+    blacklist.update_status(&ctx.accounts.config);
+    blacklist.updated_at = clock.unix_timestamp;
+
+    msg!("Vote on validator {} changed to {:?} by stake pool {}",
+         validator_identity_address, direction, stake_pool_key);
+
+    emit_cpi!(ChangeVoteEvent {
+        config: config_key,
+        stake_pool: stake_pool_key,
+        authority: ctx.accounts.authority.key(),
+        validator_identity_address,
+        direction,
+        reason,
+        category,
+        evidence,
+        stake_lamports: stake_pool.tvl,
+        tally_add: blacklist.tally_add,
+        tally_remove: blacklist.tally_remove,
+        stake_tally_add: blacklist.stake_tally_add,
+        stake_tally_remove: blacklist.stake_tally_remove,
+        status: blacklist.status,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+/// Deserialize the existing vote that is being withdrawn
+fn load_vote<T: AccountDeserialize>(vote: &AccountInfo) -> Result<T> {
+    require_keys_eq!(*vote.owner, crate::ID, ValidatorBlacklistError::InvalidVoteAccount);
+    T::try_deserialize(&mut &vote.try_borrow_data()?[..])
+        .map_err(|_| error!(ValidatorBlacklistError::InvalidVoteAccount))
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(validator_identity_address: Pubkey)]
+pub struct ChangeVote<'info> {
+    /// Global configuration account, voting and delegating are rejected while it is paused
+    #[account(
+        constraint = !config.paused @ ValidatorBlacklistError::VotingPaused,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The stake pool account for stake pool that is changing its vote
+    /// CHECK: We manually validate this is a valid stake pool in the instruction logic
+    #[account()]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// Admin override of the stake pool, consulted before the program and TVL rules of the config
+    /// CHECK: Address derived from the seeds, read if it exists
+    #[account(
+        seeds = [b"pool_override", config.key().as_ref(), stake_pool.key().as_ref()],
+        bump
+    )]
+    pub pool_override: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"blacklist", config.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    /// The add vote, closed when changing to a remove vote and created when changing to an add vote
+    /// CHECK: The address is checked by the seeds, the contents in the instruction logic
+    #[account(
+        mut,
+        seeds = [b"vote_add", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub vote_add: UncheckedAccount<'info>,
+
+    /// The remove vote, closed when changing to an add vote and created when changing to a remove vote
+    /// CHECK: The address is checked by the seeds, the contents in the instruction logic
+    #[account(
+        mut,
+        seeds = [b"vote_remove", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub vote_remove: UncheckedAccount<'info>,
+
+    /// Optional delegation account - if present, authority must be the delegate and the
+    /// delegation must grant the permissions for this instruction
+    #[account()]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// The authority (either manager or delegated authority), pays for the new vote
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Receives the rent of the closed vote
+    /// CHECK: Checked against the payer stored in the closed vote
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
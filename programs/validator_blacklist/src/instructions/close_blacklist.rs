@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::{Blacklist, Config, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::CloseBlacklistEvent;
+
+/// Close a blacklist entry whose votes have all been withdrawn or expired, refunding the rent
+/// to whoever paid for it. Anyone can call this, but a vetoed entry has to keep its veto flag
+/// until the veto is lifted.
+pub fn close_blacklist(
+    ctx: Context<CloseBlacklist>,
+    validator_identity_address: Pubkey,
+) -> Result<()> {
+    require!(
+        ctx.accounts.blacklist.is_empty(),
+        ValidatorBlacklistError::BlacklistNotEmpty
+    );
+
+    require!(
+        !ctx.accounts.blacklist.vetoed,
+        ValidatorBlacklistError::BlacklistVetoed
+    );
+
+    let clock = Clock::get()?;
+
+    msg!("Empty blacklist entry of validator {} closed", validator_identity_address);
+
+    emit_cpi!(CloseBlacklistEvent {
+        config: ctx.accounts.config.key(),
+        validator_identity_address,
+        payer: ctx.accounts.payer.key(),
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(validator_identity_address: Pubkey)]
+pub struct CloseBlacklist<'info> {
+    /// Global configuration account
+    #[account(
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"blacklist", config.key().as_ref(), validator_identity_address.as_ref()],
+        bump,
+        has_one = payer @ ValidatorBlacklistError::InvalidPayer,
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    /// Receives the rent of the closed entry
+    /// CHECK: Checked against the payer stored in the blacklist entry
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
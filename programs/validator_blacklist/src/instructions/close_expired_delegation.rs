@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{Delegation, Config, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::CloseExpiredDelegationEvent;
+
+/// Close a delegation whose `expires_at` has passed, refunding the rent to the manager who
+/// created it. Anyone can call this.
+pub fn close_expired_delegation(
+    ctx: Context<CloseExpiredDelegation>,
+) -> Result<()> {
+    let delegation = &ctx.accounts.delegation;
+    let clock = Clock::get()?;
+
+    let expires_at = delegation.expires_at
+        .filter(|_| delegation.is_expired(clock.unix_timestamp))
+        .ok_or(ValidatorBlacklistError::DelegationNotExpired)?;
+
+    msg!("Expired delegation closed: stake pool {} manager {} delegated to {}",
+         ctx.accounts.stake_pool.key(), delegation.manager, delegation.delegate);
+
+    emit_cpi!(CloseExpiredDelegationEvent {
+        config: ctx.accounts.config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        manager: delegation.manager,
+        delegate: delegation.delegate,
+        index: delegation.index,
+        expires_at,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseExpiredDelegation<'info> {
+    /// Global configuration account
+    #[account(
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = manager,
+        seeds = [b"delegation", config.key().as_ref(), stake_pool.key().as_ref(), &[delegation.index]],
+        bump,
+        has_one = manager,
+        has_one = stake_pool,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    /// The stake pool of the delegation
+    /// CHECK: Only the address is used as a seed
+    #[account()]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// The manager who created the delegation, receives the rent
+    /// CHECK: Checked against the manager stored in the delegation
+    #[account(mut)]
+    pub manager: UncheckedAccount<'info>,
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::pool_adapters::load_stake_pool;
+use crate::state::{Delegation, Config, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::CloseStaleDelegationEvent;
+
+/// Close a delegation whose manager is no longer the manager of the stake pool, refunding the
+/// rent to the manager who created it. Anyone can call this.
+pub fn close_stale_delegation(
+    ctx: Context<CloseStaleDelegation>,
+) -> Result<()> {
+    let delegation = &ctx.accounts.delegation;
+
+    // A stake pool that no longer deserializes or whose program is no longer allowed has no
+    // manager, so its delegations are stale as well
+    let current_manager = load_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.config)
+        .ok()
+        .map(|stake_pool| stake_pool.authority);
+
+    require!(
+        current_manager != Some(delegation.manager),
+        ValidatorBlacklistError::DelegationNotStale
+    );
+
+    msg!("Stale delegation closed: stake pool {} manager {} delegated to {}",
+         ctx.accounts.stake_pool.key(), delegation.manager, delegation.delegate);
+
+    emit_cpi!(CloseStaleDelegationEvent {
+        config: ctx.accounts.config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        manager: delegation.manager,
+        delegate: delegation.delegate,
+        index: delegation.index,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseStaleDelegation<'info> {
+    /// Global configuration account
+    #[account(
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = manager,
+        seeds = [b"delegation", config.key().as_ref(), stake_pool.key().as_ref(), &[delegation.index]],
+        bump,
+        has_one = manager,
+        has_one = stake_pool,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    /// The stake pool account to look up the current manager
+    /// CHECK: We manually deserialize the stake pool in the instruction logic
+    #[account()]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// The manager who created the delegation, receives the rent
+    /// CHECK: Checked against the manager stored in the delegation
+    #[account(mut)]
+    pub manager: UncheckedAccount<'info>,
+}
@@ -1,29 +1,41 @@
 use anchor_lang::prelude::*;
-use spl_stake_pool::state::StakePool;
+use anchor_lang::system_program::{self, Transfer};
 
-use crate::stake_pool_helpers::{deserialize_stake_pool_with_checks, validate_stake_pool_config};
-use crate::state::{Delegation, Config};
+use crate::pool_adapters::{load_stake_pool, validate_stake_pool_config};
+use crate::state::{Delegation, Config, CONFIG_VERSION};
 use crate::error::ValidatorBlacklistError;
+use crate::events::DelegateEvent;
 
-/// Delegate authority from a stake pool manager to another address
+/// Delegate authority from a stake pool manager to another address, limited to the instructions
+/// in `permissions` and optionally valid until `expires_at`. A stake pool can have several
+/// delegations, told apart by `index`. An existing delegation can only be overwritten if it was
+/// created by a previous manager of the stake pool, who is refunded its rent.
 pub fn delegate(
     ctx: Context<Delegate>,
+    index: u8,
+    permissions: u8,
+    expires_at: Option<i64>,
 ) -> Result<()> {
 
-    // Deserialize the SPL Stake Pool
-    let stake_pool_state: StakePool = deserialize_stake_pool_with_checks(
-        &ctx.accounts.stake_pool.try_borrow_data()?)?;
+    require!(
+        permissions != 0 && permissions & !Delegation::PERMISSION_ALL == 0,
+        ValidatorBlacklistError::InvalidPermissions
+    );
+
+    // Read the stake pool through the adapter of its program
+    let stake_pool_state = load_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.config)?;
     
     // Validate stake pool meets config requirements
     validate_stake_pool_config(
         &stake_pool_state,
-        &ctx.accounts.stake_pool.owner,
+        ctx.accounts.stake_pool.owner,
         &ctx.accounts.config,
+        &ctx.accounts.pool_override,
     )?;
     
     // Validate the stake pool manager that was passed in to us
     require_keys_eq!(
-        stake_pool_state.manager,
+        stake_pool_state.authority,
         ctx.accounts.manager.key(),
         ValidatorBlacklistError::InvalidManager
     );
@@ -31,22 +43,81 @@ pub fn delegate(
     let delegation = &mut ctx.accounts.delegation;
     let clock = Clock::get()?;
 
+    if let Some(expires_at) = expires_at {
+        require!(
+            expires_at > clock.unix_timestamp,
+            ValidatorBlacklistError::InvalidDelegationExpiry
+        );
+    }
+
+    // The PDA already exists, which is only allowed if the manager of the stake pool has changed since
+    if delegation.stake_pool != Pubkey::default() {
+        require_keys_neq!(
+            delegation.manager,
+            stake_pool_state.authority,
+            ValidatorBlacklistError::DelegationAlreadyExists
+        );
+
+        // The rent of the delegation belongs to the previous manager, the new manager pays it back
+        let previous_manager = ctx.accounts.previous_manager
+            .as_ref()
+            .ok_or(ValidatorBlacklistError::InvalidPreviousManager)?;
+        require_keys_eq!(
+            previous_manager.key(),
+            delegation.manager,
+            ValidatorBlacklistError::InvalidPreviousManager
+        );
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.manager.to_account_info(),
+                    to: previous_manager.to_account_info(),
+                },
+            ),
+            delegation.to_account_info().lamports(),
+        )?;
+
+        msg!("Overwriting stale delegation created by previous manager {}", delegation.manager);
+    }
+
     // Initialize delegation
     delegation.stake_pool = ctx.accounts.stake_pool.key();
     delegation.manager = ctx.accounts.manager.key();
     delegation.delegate = ctx.accounts.delegate.key();
     delegation.timestamp = clock.unix_timestamp;
+    delegation.config = ctx.accounts.config.key();
+    delegation.index = index;
+    delegation.permissions = permissions;
+    delegation.expires_at = expires_at;
 
-    msg!("Delegation created: stake pool {} manager {} delegated to {}", 
-         ctx.accounts.stake_pool.key(), ctx.accounts.manager.key(), ctx.accounts.delegate.key());
+    msg!("Delegation {} created: stake pool {} manager {} delegated to {} with permissions {:#06b}", 
+         index, ctx.accounts.stake_pool.key(), ctx.accounts.manager.key(), ctx.accounts.delegate.key(), permissions);
+
+    emit_cpi!(DelegateEvent {
+        config: ctx.accounts.config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        manager: ctx.accounts.manager.key(),
+        delegate: ctx.accounts.delegate.key(),
+        index,
+        permissions,
+        expires_at,
+        slot: clock.slot,
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(index: u8)]
 pub struct Delegate<'info> {
-    /// Global configuration account
-    #[account()]
+    /// Global configuration account, voting and delegating are rejected while it is paused
+    #[account(
+        constraint = !config.paused @ ValidatorBlacklistError::VotingPaused,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
     pub config: Account<'info, Config>,
 
     /// The stake pool account to validate the manager
@@ -54,11 +125,19 @@ pub struct Delegate<'info> {
     #[account()]
     pub stake_pool: UncheckedAccount<'info>,
 
+    /// Admin override of the stake pool, consulted before the program and TVL rules of the config
+    /// CHECK: Address derived from the seeds, read if it exists
+    #[account(
+        seeds = [b"pool_override", config.key().as_ref(), stake_pool.key().as_ref()],
+        bump
+    )]
+    pub pool_override: UncheckedAccount<'info>,
+
     #[account(
-        init,
+        init_if_needed,
         payer = manager,
         space = Delegation::LEN,
-        seeds = [b"delegation", config.key().as_ref(), stake_pool.key().as_ref()],
+        seeds = [b"delegation", config.key().as_ref(), stake_pool.key().as_ref(), &[index]],
         bump
     )]
     pub delegation: Account<'info, Delegation>,
@@ -67,6 +146,12 @@ pub struct Delegate<'info> {
     #[account(mut)]
     pub manager: Signer<'info>,
 
+    /// The manager who created the existing delegation, refunded its rent when it is overwritten.
+    /// Only needed if the delegation exists.
+    /// CHECK: Checked against the manager stored in the delegation
+    #[account(mut)]
+    pub previous_manager: Option<UncheckedAccount<'info>>,
+
     /// The address to delegate authority to
     /// CHECK: This is just the target of delegation, no validation needed
     pub delegate: UncheckedAccount<'info>,
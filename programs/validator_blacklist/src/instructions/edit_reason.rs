@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use crate::account_helpers::resize_pda_account;
+use crate::authority_checks;
+use crate::pool_adapters::load_stake_pool;
+use crate::state::{Config, Delegation, VoteAddToBlacklist, VoteDirection, VoteRemoveFromBlacklist, MAX_REASON_LENGTH, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::EditReasonEvent;
+
+/// Replace the reason of a vote, resizing the vote account to fit the new reason. Exactly one of
+/// `vote_add` and `vote_remove` has to be provided.
+pub fn edit_reason(
+    ctx: Context<EditReason>,
+    validator_identity_address: Pubkey,
+    reason: String,
+) -> Result<()> {
+
+    require!(
+        reason.len() <= MAX_REASON_LENGTH,
+        ValidatorBlacklistError::ReasonTooLong
+    );
+
+    let stake_pool = load_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.config)?;
+
+    // Validate the authority, editing needs the same permission as casting the vote
+    let permission = if ctx.accounts.vote_add.is_some() {
+        Delegation::PERMISSION_VOTE_ADD
+    } else {
+        Delegation::PERMISSION_VOTE_REMOVE
+    };
+
+    authority_checks::check_authority(
+        ctx.accounts.delegation.as_deref(),
+        &ctx.accounts.config.key(),
+        ctx.accounts.config.authority_policy,
+        &ctx.accounts.stake_pool.key(),
+        &stake_pool,
+        &ctx.accounts.authority.key(),
+        permission)?;
+
+    let authority = ctx.accounts.authority.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let direction = match (&mut ctx.accounts.vote_add, &mut ctx.accounts.vote_remove) {
+        (Some(vote_add), None) => {
+            require_keys_eq!(vote_add.payer, payer.key(), ValidatorBlacklistError::InvalidPayer);
+
+            // The authority pays for a longer reason, a shorter one refunds the payer of the vote
+            resize_pda_account(
+                &vote_add.to_account_info(),
+                &authority,
+                &payer,
+                &system_program,
+                VoteAddToBlacklist::space(reason.len(), vote_add.evidence.as_ref().map(|evidence| evidence.uri.len())),
+            )?;
+            vote_add.reason = reason.clone();
+            VoteDirection::Add
+        }
+        (None, Some(vote_remove)) => {
+            require_keys_eq!(vote_remove.payer, payer.key(), ValidatorBlacklistError::InvalidPayer);
+
+            resize_pda_account(
+                &vote_remove.to_account_info(),
+                &authority,
+                &payer,
+                &system_program,
+                VoteRemoveFromBlacklist::space(reason.len(), vote_remove.evidence.as_ref().map(|evidence| evidence.uri.len())),
+            )?;
+            vote_remove.reason = reason.clone();
+            VoteDirection::Remove
+        }
+        _ => return err!(ValidatorBlacklistError::InvalidVoteAccount),
+    };
+
+    msg!("Reason of vote {:?} on validator {} edited by stake pool {}",
+         direction, validator_identity_address, ctx.accounts.stake_pool.key());
+
+    emit_cpi!(EditReasonEvent {
+        config: ctx.accounts.config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        authority: ctx.accounts.authority.key(),
+        validator_identity_address,
+        direction,
+        reason,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(validator_identity_address: Pubkey)]
+pub struct EditReason<'info> {
+    /// Global configuration account, voting and delegating are rejected while it is paused
+    #[account(
+        constraint = !config.paused @ ValidatorBlacklistError::VotingPaused,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The stake pool account to validate the authority
+    /// CHECK: We manually validate this is a valid stake pool in the instruction logic
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// The add vote to edit, mutually exclusive with `vote_remove`
+    #[account(
+        mut,
+        seeds = [b"vote_add", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub vote_add: Option<Account<'info, VoteAddToBlacklist>>,
+
+    /// The remove vote to edit, mutually exclusive with `vote_add`
+    #[account(
+        mut,
+        seeds = [b"vote_remove", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub vote_remove: Option<Account<'info, VoteRemoveFromBlacklist>>,
+
+    /// Optional delegation account - if present, authority must be the delegate and the
+    /// delegation must grant the permission for this instruction
+    #[account()]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// The authority (either manager or delegated authority), pays for a longer reason
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Receives the excess rent of a shorter reason
+    /// CHECK: Checked against the payer stored in the vote
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
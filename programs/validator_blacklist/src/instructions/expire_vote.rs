@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::{Blacklist, Config, VoteAddToBlacklist, VoteDirection, VoteRemoveFromBlacklist, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::ExpireVoteEvent;
+
+/// Close a vote that has not been cast or reaffirmed within the vote lifetime, taking it out
+/// of the tallies and refunding the rent to whoever paid for the vote. Anyone can call this.
+/// Exactly one of `vote_add` and `vote_remove` has to be provided.
+pub fn expire_vote(
+    ctx: Context<ExpireVote>,
+    validator_identity_address: Pubkey,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let blacklist = &mut ctx.accounts.blacklist;
+    let payer = &ctx.accounts.payer;
+    let clock = Clock::get()?;
+
+    let (direction, stake_lamports) = match (&ctx.accounts.vote_add, &ctx.accounts.vote_remove) {
+        (Some(vote_add), None) => {
+            require!(
+                config.is_vote_expired(vote_add.timestamp, clock.unix_timestamp),
+                ValidatorBlacklistError::VoteNotExpired
+            );
+            require_keys_eq!(vote_add.payer, payer.key(), ValidatorBlacklistError::InvalidPayer);
+
+            blacklist.remove_add_vote(vote_add.stake_lamports)?;
+            vote_add.close(payer.to_account_info())?;
+            (VoteDirection::Add, vote_add.stake_lamports)
+        }
+        (None, Some(vote_remove)) => {
+            require!(
+                config.is_vote_expired(vote_remove.timestamp, clock.unix_timestamp),
+                ValidatorBlacklistError::VoteNotExpired
+            );
+            require_keys_eq!(vote_remove.payer, payer.key(), ValidatorBlacklistError::InvalidPayer);
+
+            blacklist.remove_remove_vote(vote_remove.stake_lamports)?;
+            vote_remove.close(payer.to_account_info())?;
+            (VoteDirection::Remove, vote_remove.stake_lamports)
+        }
+        _ => return err!(ValidatorBlacklistError::InvalidVoteAccount),
+    };
+
+    blacklist.update_status(config);
+    blacklist.updated_at = clock.unix_timestamp;
+
+    msg!("Expired vote {:?} on validator {} cast by stake pool {}",
+         direction, validator_identity_address, ctx.accounts.stake_pool.key());
+
+    emit_cpi!(ExpireVoteEvent {
+        config: config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        validator_identity_address,
+        direction,
+        payer: payer.key(),
+        stake_lamports,
+        tally_add: blacklist.tally_add,
+        tally_remove: blacklist.tally_remove,
+        stake_tally_add: blacklist.stake_tally_add,
+        stake_tally_remove: blacklist.stake_tally_remove,
+        status: blacklist.status,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(validator_identity_address: Pubkey)]
+pub struct ExpireVote<'info> {
+    /// Global configuration account
+    #[account(
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The stake pool that cast the vote, only used to derive the vote address
+    /// CHECK: Only the address is used as a seed
+    pub stake_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"blacklist", config.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    /// The add vote to expire, mutually exclusive with `vote_remove`
+    #[account(
+        mut,
+        seeds = [b"vote_add", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub vote_add: Option<Account<'info, VoteAddToBlacklist>>,
+
+    /// The remove vote to expire, mutually exclusive with `vote_add`
+    #[account(
+        mut,
+        seeds = [b"vote_remove", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub vote_remove: Option<Account<'info, VoteRemoveFromBlacklist>>,
+
+    /// Receives the rent of the closed vote
+    /// CHECK: Checked against the payer stored in the vote
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
@@ -1,29 +1,66 @@
 ﻿use anchor_lang::prelude::*;
-use crate::state::Config;
+use crate::state::{AllowedProgram, Config, CONFIG_VERSION, DEFAULT_ALLOWED_PROGRAMS, MAX_NAMESPACE_LENGTH};
+use crate::error::ValidatorBlacklistError;
+use crate::events::InitConfigEvent;
 
-/// Initialize the global configuration
+/// Initialize the configuration of a blacklist. Each namespace is an independent blacklist
+/// with its own settings, votes and delegations.
 pub fn init_config(
     ctx: Context<InitConfig>,
+    namespace: String,
     min_tvl: u64,
-    allowed_programs: Vec<Pubkey>,
+    allowed_programs: Vec<AllowedProgram>,
+    min_add_votes: u64,
+    min_net_votes: u64,
 ) -> Result<()> {
+    require!(
+        !namespace.is_empty() && namespace.len() <= MAX_NAMESPACE_LENGTH,
+        ValidatorBlacklistError::InvalidNamespace
+    );
+
+    require!(
+        allowed_programs.len() <= DEFAULT_ALLOWED_PROGRAMS,
+        ValidatorBlacklistError::TooManyAllowedPrograms
+    );
+
     let config = &mut ctx.accounts.config;
 
+    config.version = CONFIG_VERSION;
+    config.namespace = namespace;
+    config.bump = ctx.bumps.config;
     config.admin = ctx.accounts.admin.key();
     config.min_tvl = min_tvl;
     config.allowed_programs = allowed_programs;
+    config.min_add_votes = min_add_votes;
+    config.min_net_votes = min_net_votes;
+
+    msg!("Config {} initialized with admin: {}, min_tvl: {}, min_add_votes: {}, min_net_votes: {}",
+         config.namespace, config.admin, min_tvl, min_add_votes, min_net_votes);
 
-    msg!("Config initialized with admin: {}, min_tvl: {}", config.admin, min_tvl);
+    emit_cpi!(InitConfigEvent {
+        config: config.key(),
+        namespace: config.namespace.clone(),
+        admin: config.admin,
+        min_tvl: config.min_tvl,
+        allowed_programs: config.allowed_programs.clone(),
+        min_add_votes: config.min_add_votes,
+        min_net_votes: config.min_net_votes,
+        slot: Clock::get()?.slot,
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(namespace: String)]
 pub struct InitConfig<'info> {
     #[account(
         init,
         payer = admin,
         space = Config::LEN,
+        seeds = [b"config", namespace.as_bytes()],
+        bump
     )]
     pub config: Account<'info, Config>,
 
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{Blacklist, BlacklistInfo, BlacklistStatus, Config, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+
+/// Look up the blacklist status of a validator. The result is returned through the return
+/// data so that other programs can query it via CPI. The blacklist account does not need
+/// to exist, in which case the validator is reported as pending with no votes.
+pub fn is_blacklisted(
+    ctx: Context<IsBlacklisted>,
+    validator_identity_address: Pubkey,
+) -> Result<BlacklistInfo> {
+    let blacklist_info = ctx.accounts.blacklist.to_account_info();
+
+    if blacklist_info.owner != &crate::ID || blacklist_info.data_is_empty() {
+        return Ok(BlacklistInfo {
+            validator_identity_address,
+            vote_account: None,
+            exists: false,
+            status: BlacklistStatus::Pending,
+            tally_add: 0,
+            tally_remove: 0,
+            stake_tally_add: 0,
+            stake_tally_remove: 0,
+            created_at: 0,
+            updated_at: 0,
+        });
+    }
+
+    let mut blacklist = Blacklist::try_deserialize(&mut &blacklist_info.try_borrow_data()?[..])?;
+
+    // Evaluate against the current thresholds, they may have changed since the last vote
+    blacklist.update_status(&ctx.accounts.config);
+
+    Ok(BlacklistInfo {
+        validator_identity_address,
+        vote_account: blacklist.vote_account,
+        exists: true,
+        status: blacklist.status,
+        tally_add: blacklist.tally_add,
+        tally_remove: blacklist.tally_remove,
+        stake_tally_add: blacklist.stake_tally_add,
+        stake_tally_remove: blacklist.stake_tally_remove,
+        created_at: blacklist.created_at,
+        updated_at: blacklist.updated_at,
+    })
+}
+
+#[derive(Accounts)]
+#[instruction(validator_identity_address: Pubkey)]
+pub struct IsBlacklisted<'info> {
+    /// Global configuration account
+    #[account(
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The blacklist account of the validator, which may not exist yet
+    /// CHECK: Only the PDA is validated here, the data is deserialized in the instruction logic if present
+    #[account(
+        seeds = [b"blacklist", config.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub blacklist: UncheckedAccount<'info>,
+}
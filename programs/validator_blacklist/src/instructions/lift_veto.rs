@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{Blacklist, Config, Veto, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::LiftVetoEvent;
+
+/// Lift the veto of a blacklist entry, so that its status follows the tallies again. The rent
+/// of the veto is refunded to the admin who vetoed.
+pub fn lift_veto(
+    ctx: Context<LiftVeto>,
+    validator_identity_address: Pubkey,
+) -> Result<()> {
+    let blacklist = &mut ctx.accounts.blacklist;
+
+    blacklist.vetoed = false;
+    blacklist.update_status(&ctx.accounts.config);
+
+    msg!("Veto of validator {} lifted, status is now {:?}", validator_identity_address, blacklist.status);
+
+    emit_cpi!(LiftVetoEvent {
+        config: ctx.accounts.config.key(),
+        admin: ctx.accounts.admin.key(),
+        validator_identity_address,
+        status: blacklist.status,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(validator_identity_address: Pubkey)]
+pub struct LiftVeto<'info> {
+    #[account(
+        has_one = admin @ ValidatorBlacklistError::UnauthorizedAdmin,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"blacklist", config.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    #[account(
+        mut,
+        close = veto_admin,
+        seeds = [b"veto", config.key().as_ref(), validator_identity_address.as_ref()],
+        bump = veto.bump
+    )]
+    pub veto: Account<'info, Veto>,
+
+    /// The admin who vetoed, receives the rent of the veto
+    /// CHECK: Checked against the admin stored in the veto
+    #[account(
+        mut,
+        address = veto.admin @ ValidatorBlacklistError::InvalidPayer
+    )]
+    pub veto_admin: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+}
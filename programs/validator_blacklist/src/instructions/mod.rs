@@ -1,19 +1,57 @@
 pub mod vote_add;
 pub mod vote_remove;
+pub mod vote_add_many;
 pub mod unvote_add;
 pub mod unvote_remove;
+pub mod change_vote;
+pub mod reaffirm_vote;
+pub mod edit_reason;
+pub mod expire_vote;
+pub mod prune_vote;
+pub mod close_blacklist;
 pub mod delegate;
 pub mod undelegate;
+pub mod update_delegate;
+pub mod close_stale_delegation;
+pub mod close_expired_delegation;
 pub mod init_config;
 pub mod update_config;
-pub mod update_config_admin;
+pub mod resize_config;
+pub mod set_paused;
+pub mod set_pool_override;
+pub mod remove_pool_override;
+pub mod veto;
+pub mod lift_veto;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod cancel_admin_proposal;
+pub mod is_blacklisted;
 
 pub use vote_add::*;
 pub use vote_remove::*;
+pub use vote_add_many::*;
 pub use unvote_add::*;
 pub use unvote_remove::*;
+pub use change_vote::*;
+pub use reaffirm_vote::*;
+pub use edit_reason::*;
+pub use expire_vote::*;
+pub use prune_vote::*;
+pub use close_blacklist::*;
 pub use delegate::*;
 pub use undelegate::*;
+pub use update_delegate::*;
+pub use close_stale_delegation::*;
+pub use close_expired_delegation::*;
 pub use init_config::*;
 pub use update_config::*;
-pub use update_config_admin::*;
+pub use resize_config::*;
+pub use set_paused::*;
+pub use set_pool_override::*;
+pub use remove_pool_override::*;
+pub use veto::*;
+pub use lift_veto::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use cancel_admin_proposal::*;
+pub use is_blacklisted::*;
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::ProposeAdminEvent;
+
+/// Propose a new admin of the config. The admin only changes once the proposed key
+/// accepts, so a mistyped key can never take over the config.
+pub fn propose_admin(
+    ctx: Context<ProposeAdmin>,
+    new_admin: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.pending_admin = Some(new_admin);
+
+    msg!("Config admin proposed: {}", new_admin);
+
+    emit_cpi!(ProposeAdminEvent {
+        config: config.key(),
+        admin: config.admin,
+        pending_admin: new_admin,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(
+        mut,
+        has_one = admin @ ValidatorBlacklistError::UnauthorizedAdmin,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
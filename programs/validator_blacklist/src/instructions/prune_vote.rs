@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::pool_adapters::{load_stake_pool, validate_stake_pool_config};
+use crate::state::{Blacklist, Config, VoteAddToBlacklist, VoteDirection, VoteRemoveFromBlacklist, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::PruneVoteEvent;
+
+/// Close a vote whose stake pool no longer meets the config requirements, e.g. because it fell
+/// below the minimum TVL, its program was removed from the allowed programs or it no longer
+/// deserializes as a stake pool. The vote is taken out of the tallies and the rent is refunded to
+/// whoever paid for the vote. Anyone can call this. Exactly one of `vote_add` and `vote_remove`
+/// has to be provided.
+pub fn prune_vote(
+    ctx: Context<PruneVote>,
+    validator_identity_address: Pubkey,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let blacklist = &mut ctx.accounts.blacklist;
+    let payer = &ctx.accounts.payer;
+    let clock = Clock::get()?;
+
+    // Re-run the checks of the vote instructions against the current state of the stake pool
+    let still_qualifies = load_stake_pool(&ctx.accounts.stake_pool, config)
+        .and_then(|stake_pool| validate_stake_pool_config(&stake_pool, ctx.accounts.stake_pool.owner, config, &ctx.accounts.pool_override))
+        .is_ok();
+
+    require!(!still_qualifies, ValidatorBlacklistError::StakePoolStillQualifies);
+
+    let (direction, stake_lamports) = match (&ctx.accounts.vote_add, &ctx.accounts.vote_remove) {
+        (Some(vote_add), None) => {
+            require_keys_eq!(vote_add.payer, payer.key(), ValidatorBlacklistError::InvalidPayer);
+
+            blacklist.remove_add_vote(vote_add.stake_lamports)?;
+            vote_add.close(payer.to_account_info())?;
+            (VoteDirection::Add, vote_add.stake_lamports)
+        }
+        (None, Some(vote_remove)) => {
+            require_keys_eq!(vote_remove.payer, payer.key(), ValidatorBlacklistError::InvalidPayer);
+
+            blacklist.remove_remove_vote(vote_remove.stake_lamports)?;
+            vote_remove.close(payer.to_account_info())?;
+            (VoteDirection::Remove, vote_remove.stake_lamports)
+        }
+        _ => return err!(ValidatorBlacklistError::InvalidVoteAccount),
+    };
+
+    blacklist.update_status(config);
+    blacklist.updated_at = clock.unix_timestamp;
+
+    msg!("Pruned vote {:?} on validator {} cast by stake pool {} that no longer qualifies",
+         direction, validator_identity_address, ctx.accounts.stake_pool.key());
+
+    emit_cpi!(PruneVoteEvent {
+        config: config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        validator_identity_address,
+        direction,
+        payer: payer.key(),
+        stake_lamports,
+        tally_add: blacklist.tally_add,
+        tally_remove: blacklist.tally_remove,
+        stake_tally_add: blacklist.stake_tally_add,
+        stake_tally_remove: blacklist.stake_tally_remove,
+        status: blacklist.status,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(validator_identity_address: Pubkey)]
+pub struct PruneVote<'info> {
+    /// Global configuration account
+    #[account(
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The stake pool that cast the vote
+    /// CHECK: We manually check whether this is still a qualifying stake pool in the instruction logic
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// Admin override of the stake pool, consulted before the program and TVL rules of the config
+    /// CHECK: Address derived from the seeds, read if it exists
+    #[account(
+        seeds = [b"pool_override", config.key().as_ref(), stake_pool.key().as_ref()],
+        bump
+    )]
+    pub pool_override: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"blacklist", config.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    /// The add vote to prune, mutually exclusive with `vote_remove`
+    #[account(
+        mut,
+        seeds = [b"vote_add", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub vote_add: Option<Account<'info, VoteAddToBlacklist>>,
+
+    /// The remove vote to prune, mutually exclusive with `vote_add`
+    #[account(
+        mut,
+        seeds = [b"vote_remove", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub vote_remove: Option<Account<'info, VoteRemoveFromBlacklist>>,
+
+    /// Receives the rent of the closed vote
+    /// CHECK: Checked against the payer stored in the vote
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::authority_checks;
+use crate::pool_adapters::load_stake_pool;
+use crate::state::{Config, Delegation, VoteAddToBlacklist, VoteDirection, VoteRemoveFromBlacklist, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::ReaffirmVoteEvent;
+
+/// Refresh the timestamp and slot of a vote, restarting its lifetime. Exactly one of
+/// `vote_add` and `vote_remove` has to be provided.
+pub fn reaffirm_vote(
+    ctx: Context<ReaffirmVote>,
+    validator_identity_address: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let stake_pool = load_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.config)?;
+
+    // Validate the authority, reaffirming needs the same permission as casting the vote
+    let permission = if ctx.accounts.vote_add.is_some() {
+        Delegation::PERMISSION_VOTE_ADD
+    } else {
+        Delegation::PERMISSION_VOTE_REMOVE
+    };
+    authority_checks::check_authority(
+        ctx.accounts.delegation.as_deref(),
+        &ctx.accounts.config.key(),
+        ctx.accounts.config.authority_policy,
+        &ctx.accounts.stake_pool.key(),
+        &stake_pool,
+        &ctx.accounts.authority.key(),
+        permission)?;
+
+    let direction = match (&mut ctx.accounts.vote_add, &mut ctx.accounts.vote_remove) {
+        (Some(vote_add), None) => {
+            vote_add.timestamp = clock.unix_timestamp;
+            vote_add.slot = clock.slot;
+            VoteDirection::Add
+        }
+        (None, Some(vote_remove)) => {
+            vote_remove.timestamp = clock.unix_timestamp;
+            vote_remove.slot = clock.slot;
+            VoteDirection::Remove
+        }
+        _ => return err!(ValidatorBlacklistError::InvalidVoteAccount),
+    };
+
+    msg!("Vote {:?} on validator {} reaffirmed by stake pool {}",
+         direction, validator_identity_address, ctx.accounts.stake_pool.key());
+
+    emit_cpi!(ReaffirmVoteEvent {
+        config: ctx.accounts.config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        authority: ctx.accounts.authority.key(),
+        validator_identity_address,
+        direction,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(validator_identity_address: Pubkey)]
+pub struct ReaffirmVote<'info> {
+    /// Global configuration account, voting and delegating are rejected while it is paused
+    #[account(
+        constraint = !config.paused @ ValidatorBlacklistError::VotingPaused,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The stake pool account to validate the authority
+    /// CHECK: We manually validate this is a valid stake pool in the instruction logic
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// The add vote to reaffirm, mutually exclusive with `vote_remove`
+    #[account(
+        mut,
+        seeds = [b"vote_add", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub vote_add: Option<Account<'info, VoteAddToBlacklist>>,
+
+    /// The remove vote to reaffirm, mutually exclusive with `vote_add`
+    #[account(
+        mut,
+        seeds = [b"vote_remove", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub vote_remove: Option<Account<'info, VoteRemoveFromBlacklist>>,
+
+    /// Optional delegation account - if present, authority must be the delegate and the
+    /// delegation must grant the permission for this instruction
+    #[account()]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// The authority (either manager or delegated authority)
+    pub authority: Signer<'info>,
+}
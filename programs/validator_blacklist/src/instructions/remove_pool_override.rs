@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, PoolOverride, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::RemovePoolOverrideEvent;
+
+/// Remove the override of a stake pool, so that the rules of the config apply to it again.
+/// The rent is refunded to the admin.
+pub fn remove_pool_override(
+    ctx: Context<RemovePoolOverride>,
+) -> Result<()> {
+    let pool_override = &ctx.accounts.pool_override;
+
+    msg!("Pool override of stake pool {} removed", pool_override.stake_pool);
+
+    emit_cpi!(RemovePoolOverrideEvent {
+        config: pool_override.config,
+        admin: ctx.accounts.admin.key(),
+        stake_pool: pool_override.stake_pool,
+        kind: pool_override.kind,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemovePoolOverride<'info> {
+    #[account(
+        has_one = admin @ ValidatorBlacklistError::UnauthorizedAdmin,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"pool_override", config.key().as_ref(), pool_override.stake_pool.as_ref()],
+        bump = pool_override.bump
+    )]
+    pub pool_override: Account<'info, PoolOverride>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
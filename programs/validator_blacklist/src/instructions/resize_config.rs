@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, MAX_ALLOWED_PROGRAMS};
+use crate::error::ValidatorBlacklistError;
+use crate::events::ResizeConfigEvent;
+use crate::account_helpers::resize_pda_account;
+use crate::config_migration::read_migrated_config;
+
+/// Reallocate the config account to hold `capacity` allowed programs. The admin pays for
+/// (or is refunded) the rent difference. A config in the legacy layout is migrated to the
+/// current layout, which is the only instruction accepting it.
+pub fn resize_config(
+    ctx: Context<ResizeConfig>,
+    capacity: u16,
+) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+    let config = read_migrated_config(&config_info.try_borrow_data()?)?;
+
+    require_keys_eq!(
+        config.admin,
+        ctx.accounts.admin.key(),
+        ValidatorBlacklistError::UnauthorizedAdmin
+    );
+
+    // Checked before any lamports or data move
+    require!(
+        capacity as usize <= MAX_ALLOWED_PROGRAMS && capacity as usize >= config.allowed_programs.len(),
+        ValidatorBlacklistError::InvalidConfigCapacity
+    );
+
+    let admin = ctx.accounts.admin.to_account_info();
+    resize_pda_account(
+        &config_info,
+        &admin,
+        &admin,
+        &ctx.accounts.system_program.to_account_info(),
+        Config::space(capacity as usize),
+    )?;
+    config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+    msg!("Config resized to hold {} allowed programs", capacity);
+
+    emit_cpi!(ResizeConfigEvent {
+        config: config_info.key(),
+        admin: config.admin,
+        capacity,
+        version: config.version,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResizeConfig<'info> {
+    /// CHECK: Config in the current or the legacy layout, checked and migrated by `read_migrated_config`
+    #[account(mut, owner = crate::ID)]
+    pub config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::SetPausedEvent;
+
+/// Pause or resume voting and delegating. Unvotes and undelegations keep working while
+/// paused so that participants can always exit.
+pub fn set_paused(
+    ctx: Context<SetPaused>,
+    paused: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.paused = paused;
+
+    msg!("Config paused set to: {}", paused);
+
+    emit_cpi!(SetPausedEvent {
+        config: config.key(),
+        admin: config.admin,
+        paused,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        has_one = admin @ ValidatorBlacklistError::UnauthorizedAdmin,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, PoolOverride, PoolOverrideKind, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::SetPoolOverrideEvent;
+
+/// Allow or deny a single stake pool, taking precedence over the minimum TVL of the config.
+/// An existing override of the stake pool is replaced.
+pub fn set_pool_override(
+    ctx: Context<SetPoolOverride>,
+    kind: PoolOverrideKind,
+) -> Result<()> {
+    let pool_override = &mut ctx.accounts.pool_override;
+
+    pool_override.config = ctx.accounts.config.key();
+    pool_override.stake_pool = ctx.accounts.stake_pool.key();
+    pool_override.kind = kind;
+    pool_override.timestamp = Clock::get()?.unix_timestamp;
+    pool_override.bump = ctx.bumps.pool_override;
+
+    msg!("Pool override of stake pool {} set to: {:?}", pool_override.stake_pool, kind);
+
+    emit_cpi!(SetPoolOverrideEvent {
+        config: pool_override.config,
+        admin: ctx.accounts.admin.key(),
+        stake_pool: pool_override.stake_pool,
+        kind,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPoolOverride<'info> {
+    #[account(
+        has_one = admin @ ValidatorBlacklistError::UnauthorizedAdmin,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The stake pool the override applies to, which does not have to exist yet
+    /// CHECK: Only used as PDA seed
+    pub stake_pool: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PoolOverride::LEN,
+        seeds = [b"pool_override", config.key().as_ref(), stake_pool.key().as_ref()],
+        bump
+    )]
+    pub pool_override: Account<'info, PoolOverride>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::{Delegation, Config};
+use crate::state::{Delegation, Config, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::UndelegateEvent;
 
 /// Remove delegation of authority
 pub fn undelegate(
@@ -10,19 +12,31 @@ pub fn undelegate(
     msg!("Delegation removed: stake pool {} manager {} undelegated from {}", 
          ctx.accounts.stake_pool.key(), ctx.accounts.manager.key(), delegation.delegate);
 
+    emit_cpi!(UndelegateEvent {
+        config: ctx.accounts.config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        manager: ctx.accounts.manager.key(),
+        delegate: delegation.delegate,
+        index: delegation.index,
+        slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Undelegate<'info> {
     /// Global configuration account
-    #[account()]
+    #[account(
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
     pub config: Account<'info, Config>,
 
     #[account(
         mut,
         close = manager,
-        seeds = [b"delegation", config.key().as_ref(), stake_pool.key().as_ref()],
+        seeds = [b"delegation", config.key().as_ref(), stake_pool.key().as_ref(), &[delegation.index]],
         bump,
         has_one = manager,
         has_one = stake_pool,
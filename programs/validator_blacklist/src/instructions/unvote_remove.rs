@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::authority_checks;
-use crate::stake_pool_helpers::{deserialize_stake_pool_with_checks};
-use crate::state::{Blacklist, VoteRemoveFromBlacklist, Delegation, Config};
+use crate::pool_adapters::load_stake_pool;
+use crate::state::{Blacklist, VoteRemoveFromBlacklist, Delegation, Config, CONFIG_VERSION};
 use crate::error::ValidatorBlacklistError;
+use crate::events::UnvoteRemoveEvent;
 
 /// Remove a previously cast vote to remove a validator from the blacklist
 pub fn unvote_remove(
@@ -12,30 +13,53 @@ pub fn unvote_remove(
 
     let blacklist = &mut ctx.accounts.blacklist;
 
-    let stake_pool = deserialize_stake_pool_with_checks(&ctx.accounts.stake_pool.try_borrow_data()?)?;
+    let stake_pool = load_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.config)?;
 
     // Validate the authority
     authority_checks::check_authority(
-        ctx.accounts.delegation.as_deref(), 
-        &ctx.accounts.stake_pool.key(), 
+        ctx.accounts.delegation.as_deref(),
+        &ctx.accounts.config.key(),
+        ctx.accounts.config.authority_policy,
+        &ctx.accounts.stake_pool.key(),
         &stake_pool,
-        &ctx.accounts.authority.key())?;
+        &ctx.accounts.authority.key(),
+        Delegation::PERMISSION_UNVOTE_REMOVE)?;
 
-    // Decrease the tally
-    blacklist.tally_remove = blacklist.tally_remove.checked_sub(1)
-        .ok_or(ValidatorBlacklistError::MathUnderflow)?;
+    // Decrease the tallies by what this vote contributed
+    blacklist.remove_remove_vote(ctx.accounts.vote_remove.stake_lamports)?;
+    blacklist.update_status(&ctx.accounts.config);
+    let clock = Clock::get()?;
+    blacklist.updated_at = clock.unix_timestamp;
 
     msg!("Removed vote to remove validator {} cast by stake pool {}", 
          validator_identity_address, ctx.accounts.stake_pool.key());
 
+    emit_cpi!(UnvoteRemoveEvent {
+        config: ctx.accounts.config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        authority: ctx.accounts.authority.key(),
+        validator_identity_address,
+        reason: ctx.accounts.vote_remove.reason.clone(),
+        stake_lamports: ctx.accounts.vote_remove.stake_lamports,
+        tally_add: blacklist.tally_add,
+        tally_remove: blacklist.tally_remove,
+        stake_tally_add: blacklist.stake_tally_add,
+        stake_tally_remove: blacklist.stake_tally_remove,
+        status: blacklist.status,
+        slot: clock.slot,
+    });
+
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(validator_identity_address: Pubkey)]
 pub struct UnvoteRemove<'info> {
     /// Global configuration account
-    #[account()]
+    #[account(
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
     pub config: Account<'info, Config>,
 
     /// The stake pool account to validate the authority
@@ -51,20 +75,23 @@ pub struct UnvoteRemove<'info> {
 
     #[account(
         mut,
-        close = authority,
+        close = payer,
+        has_one = payer @ ValidatorBlacklistError::InvalidPayer,
         seeds = [b"vote_remove", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
         bump
     )]
     pub vote_remove: Account<'info, VoteRemoveFromBlacklist>,
 
-    /// Optional delegation account - if present, authority must be the delegate
-    #[account(
-        seeds = [b"delegation", config.key().as_ref(), stake_pool.key().as_ref()],
-        bump
-    )]
+    /// Optional delegation account - if present, authority must be the delegate and the
+    /// delegation must grant the permission for this instruction
+    #[account()]
     pub delegation: Option<Account<'info, Delegation>>,
 
     /// The authority (either manager or delegated authority)
-    #[account(mut)]
     pub authority: Signer<'info>,
+
+    /// Receives the rent of the closed vote
+    /// CHECK: Checked against the payer stored in the vote
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
 }
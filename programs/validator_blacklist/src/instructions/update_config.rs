@@ -1,12 +1,17 @@
 ﻿use anchor_lang::prelude::*;
-use crate::state::Config;
+use crate::state::{AllowedProgram, AuthorityPolicy, Config, CONFIG_VERSION};
 use crate::error::ValidatorBlacklistError;
+use crate::events::UpdateConfigEvent;
 
-/// Update the config settings (min_tvl and allowed_programs)
+/// Update the config settings (min_tvl, allowed_programs, blacklist thresholds, vote lifetime and authority policy)
 pub fn update_config(
     ctx: Context<UpdateConfig>,
     min_tvl: Option<u64>,
-    allowed_programs: Option<Vec<Pubkey>>,
+    allowed_programs: Option<Vec<AllowedProgram>>,
+    min_add_votes: Option<u64>,
+    min_net_votes: Option<u64>,
+    vote_lifetime: Option<i64>,
+    authority_policy: Option<AuthorityPolicy>,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     
@@ -16,18 +21,59 @@ pub fn update_config(
     }
     
     if let Some(allowed_programs) = allowed_programs {
+        require!(
+            allowed_programs.len() <= Config::capacity(config.to_account_info().data_len()),
+            ValidatorBlacklistError::TooManyAllowedPrograms
+        );
+
         config.allowed_programs = allowed_programs;
         msg!("Config allowed_programs updated");
     }
+
+    if let Some(min_add_votes) = min_add_votes {
+        config.min_add_votes = min_add_votes;
+        msg!("Config min_add_votes updated to: {}", min_add_votes);
+    }
+
+    if let Some(min_net_votes) = min_net_votes {
+        config.min_net_votes = min_net_votes;
+        msg!("Config min_net_votes updated to: {}", min_net_votes);
+    }
+
+    if let Some(vote_lifetime) = vote_lifetime {
+        require!(vote_lifetime >= 0, ValidatorBlacklistError::InvalidVoteLifetime);
+
+        config.vote_lifetime = vote_lifetime;
+        msg!("Config vote_lifetime updated to: {}", vote_lifetime);
+    }
+
+    if let Some(authority_policy) = authority_policy {
+        config.authority_policy = authority_policy;
+        msg!("Config authority_policy updated to: {:?}", authority_policy);
+    }
+
+    emit_cpi!(UpdateConfigEvent {
+        config: config.key(),
+        admin: config.admin,
+        min_tvl: config.min_tvl,
+        allowed_programs: config.allowed_programs.clone(),
+        min_add_votes: config.min_add_votes,
+        min_net_votes: config.min_net_votes,
+        vote_lifetime: config.vote_lifetime,
+        authority_policy: config.authority_policy,
+        slot: Clock::get()?.slot,
+    });
     
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
     #[account(
         mut,
-        has_one = admin @ ValidatorBlacklistError::UnauthorizedAdmin
+        has_one = admin @ ValidatorBlacklistError::UnauthorizedAdmin,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
     )]
     pub config: Account<'info, Config>,
 
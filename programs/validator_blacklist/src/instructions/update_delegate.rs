@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::pool_adapters::load_stake_pool;
+use crate::state::{Delegation, Config, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::UpdateDelegateEvent;
+
+/// Replace the delegate of an existing delegation in place, keeping its permissions and expiry.
+/// Only the current manager of the stake pool can do so, a stale delegation has to be closed.
+pub fn update_delegate(
+    ctx: Context<UpdateDelegate>,
+) -> Result<()> {
+    // Validate that the manager of the delegation is still the manager of the stake pool
+    let stake_pool = load_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.config)?;
+    require_keys_eq!(
+        stake_pool.authority,
+        ctx.accounts.manager.key(),
+        ValidatorBlacklistError::InvalidManager
+    );
+
+    let delegation = &mut ctx.accounts.delegation;
+    let old_delegate = delegation.delegate;
+
+    delegation.delegate = ctx.accounts.new_delegate.key();
+
+    msg!("Delegation {} updated: stake pool {} manager {} delegated to {} instead of {}",
+         delegation.index, ctx.accounts.stake_pool.key(), ctx.accounts.manager.key(), delegation.delegate, old_delegate);
+
+    emit_cpi!(UpdateDelegateEvent {
+        config: ctx.accounts.config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        manager: ctx.accounts.manager.key(),
+        index: delegation.index,
+        old_delegate,
+        new_delegate: delegation.delegate,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateDelegate<'info> {
+    /// Global configuration account, voting and delegating are rejected while it is paused
+    #[account(
+        constraint = !config.paused @ ValidatorBlacklistError::VotingPaused,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"delegation", config.key().as_ref(), stake_pool.key().as_ref(), &[delegation.index]],
+        bump,
+        has_one = manager,
+        has_one = stake_pool,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    /// The stake pool of the delegation, to validate the manager
+    /// CHECK: We manually validate this is a valid stake pool in the instruction logic
+    #[account()]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// The manager of the stake pool (must match the delegation's manager field)
+    pub manager: Signer<'info>,
+
+    /// The address to delegate authority to from now on
+    /// CHECK: This is just the target of delegation, no validation needed
+    pub new_delegate: UncheckedAccount<'info>,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{Blacklist, Config, Veto, MAX_REASON_LENGTH, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::VetoEvent;
+
+/// Veto a blacklist entry, e.g. when colluding stake pools blacklist an honest validator. The
+/// entry is reported as `Overridden` until the veto is lifted, votes keep being tallied meanwhile.
+pub fn veto(
+    ctx: Context<VetoBlacklist>,
+    validator_identity_address: Pubkey,
+    reason: String,
+) -> Result<()> {
+    require!(
+        reason.len() <= MAX_REASON_LENGTH,
+        ValidatorBlacklistError::ReasonTooLong
+    );
+
+    let veto = &mut ctx.accounts.veto;
+    let blacklist = &mut ctx.accounts.blacklist;
+    let clock = Clock::get()?;
+
+    veto.config = ctx.accounts.config.key();
+    veto.validator_identity_address = validator_identity_address;
+    veto.admin = ctx.accounts.admin.key();
+    veto.reason = reason;
+    veto.timestamp = clock.unix_timestamp;
+    veto.bump = ctx.bumps.veto;
+
+    blacklist.vetoed = true;
+    blacklist.update_status(&ctx.accounts.config);
+
+    msg!("Blacklist entry of validator {} vetoed", validator_identity_address);
+
+    emit_cpi!(VetoEvent {
+        config: veto.config,
+        admin: veto.admin,
+        validator_identity_address,
+        reason: veto.reason.clone(),
+        status: blacklist.status,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(validator_identity_address: Pubkey, reason: String)]
+pub struct VetoBlacklist<'info> {
+    #[account(
+        has_one = admin @ ValidatorBlacklistError::UnauthorizedAdmin,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"blacklist", config.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Veto::space(reason.len()),
+        seeds = [b"veto", config.key().as_ref(), validator_identity_address.as_ref()],
+        bump
+    )]
+    pub veto: Account<'info, Veto>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
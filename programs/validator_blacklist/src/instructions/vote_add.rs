@@ -1,40 +1,54 @@
 use anchor_lang::prelude::*;
 use crate::authority_checks;
-use crate::stake_pool_helpers::{deserialize_stake_pool_with_checks, validate_stake_pool_config};
-use crate::state::{Blacklist, Delegation, VoteAddToBlacklist, Config, MAX_REASON_LENGTH};
+use crate::pool_adapters::{load_stake_pool, validate_stake_pool_config};
+use crate::vote_account_helpers::read_node_pubkey;
+use crate::state::{Blacklist, Delegation, VoteAddToBlacklist, Config, Evidence, ReasonCategory, MAX_EVIDENCE_URI_LENGTH, MAX_REASON_LENGTH, CONFIG_VERSION};
 use crate::error::ValidatorBlacklistError;
+use crate::events::VoteAddEvent;
 
 /// Vote to add a validator to the blacklist
 pub fn vote_add(
     ctx: Context<VoteAdd>,
     validator_identity_address: Pubkey,
     reason: String,
+    category: ReasonCategory,
+    evidence: Option<Evidence>,
 ) -> Result<()> {
 
     require!(
         reason.len() <= MAX_REASON_LENGTH,
         ValidatorBlacklistError::ReasonTooLong
     );
+    if let Some(evidence) = &evidence {
+        require!(
+            evidence.uri.len() <= MAX_EVIDENCE_URI_LENGTH,
+            ValidatorBlacklistError::EvidenceUriTooLong
+        );
+    }
 
     let blacklist = &mut ctx.accounts.blacklist;
     let vote_add = &mut ctx.accounts.vote_add;
     let clock = Clock::get()?;
 
-    let stake_pool = deserialize_stake_pool_with_checks(&ctx.accounts.stake_pool.try_borrow_data()?)?;
+    let stake_pool = load_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.config)?;
 
     // Validate stake pool meets config requirements
     validate_stake_pool_config(
         &stake_pool,
-        &ctx.accounts.stake_pool.owner,
+        ctx.accounts.stake_pool.owner,
         &ctx.accounts.config,
+        &ctx.accounts.pool_override,
     )?;
 
     // Validate the authority
     authority_checks::check_authority(
-        ctx.accounts.delegation.as_deref(), 
-        &ctx.accounts.stake_pool.key(), 
+        ctx.accounts.delegation.as_deref(),
+        &ctx.accounts.config.key(),
+        ctx.accounts.config.authority_policy,
+        &ctx.accounts.stake_pool.key(),
         &stake_pool,
-        &ctx.accounts.authority.key())?;
+        &ctx.accounts.authority.key(),
+        Delegation::PERMISSION_VOTE_ADD)?;
     
 
     // Initialize blacklist if this is the first vote for this validator
@@ -42,6 +56,21 @@ pub fn vote_add(
         blacklist.validator_identity_address = validator_identity_address;
         blacklist.tally_add = 0;
         blacklist.tally_remove = 0;
+        blacklist.stake_tally_add = 0;
+        blacklist.stake_tally_remove = 0;
+        blacklist.created_at = clock.unix_timestamp;
+        blacklist.config = ctx.accounts.config.key();
+        blacklist.payer = ctx.accounts.authority.key();
+    }
+
+    // Record the vote account once it is proven to belong to the validator
+    if let Some(validator_vote_account) = &ctx.accounts.validator_vote_account {
+        require_keys_eq!(
+            read_node_pubkey(validator_vote_account)?,
+            validator_identity_address,
+            ValidatorBlacklistError::InvalidValidatorVoteAccount
+        );
+        blacklist.vote_account = Some(validator_vote_account.key());
     }
 
     // Create the vote record
@@ -50,22 +79,51 @@ pub fn vote_add(
     vote_add.reason = reason;
     vote_add.timestamp = clock.unix_timestamp;
     vote_add.slot = clock.slot;
+    vote_add.stake_lamports = stake_pool.tvl;
+    vote_add.payer = ctx.accounts.authority.key();
+    vote_add.category = category;
+    vote_add.evidence = evidence;
 
-    // Update the tally
+    // Update the tallies
     blacklist.tally_add = blacklist.tally_add.checked_add(1)
         .ok_or(ValidatorBlacklistError::MathOverflow)?;
+    blacklist.stake_tally_add = blacklist.stake_tally_add.checked_add(stake_pool.tvl)
+        .ok_or(ValidatorBlacklistError::MathOverflow)?;
+    blacklist.update_status(&ctx.accounts.config);
+    blacklist.updated_at = clock.unix_timestamp;
 
     msg!("Vote to add validator {} to blacklist cast by stake pool {}", 
          validator_identity_address, ctx.accounts.stake_pool.key());
 
+    emit_cpi!(VoteAddEvent {
+        config: ctx.accounts.config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        authority: ctx.accounts.authority.key(),
+        validator_identity_address,
+        reason: vote_add.reason.clone(),
+        category,
+        evidence: vote_add.evidence.clone(),
+        stake_lamports: vote_add.stake_lamports,
+        tally_add: blacklist.tally_add,
+        tally_remove: blacklist.tally_remove,
+        stake_tally_add: blacklist.stake_tally_add,
+        stake_tally_remove: blacklist.stake_tally_remove,
+        status: blacklist.status,
+        slot: clock.slot,
+    });
+
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(validator_identity_address: Pubkey, reason: String)]
+#[instruction(validator_identity_address: Pubkey, reason: String, category: ReasonCategory, evidence: Option<Evidence>)]
 pub struct VoteAdd<'info> {
-    /// Global configuration account
-    #[account()]
+    /// Global configuration account, voting and delegating are rejected while it is paused
+    #[account(
+        constraint = !config.paused @ ValidatorBlacklistError::VotingPaused,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
     pub config: Account<'info, Config>,
 
     /// The stake pool account for stake pool that is casting the vote
@@ -73,6 +131,14 @@ pub struct VoteAdd<'info> {
     #[account()]
     pub stake_pool: UncheckedAccount<'info>,
 
+    /// Admin override of the stake pool, consulted before the program and TVL rules of the config
+    /// CHECK: Address derived from the seeds, read if it exists
+    #[account(
+        seeds = [b"pool_override", config.key().as_ref(), stake_pool.key().as_ref()],
+        bump
+    )]
+    pub pool_override: UncheckedAccount<'info>,
+
     #[account(
         init_if_needed,
         payer = authority,
@@ -85,17 +151,29 @@ pub struct VoteAdd<'info> {
     #[account(
         init,
         payer = authority,
-        space = VoteAddToBlacklist::LEN,
+        space = VoteAddToBlacklist::space(reason.len(), evidence.as_ref().map(|evidence| evidence.uri.len())),
         seeds = [b"vote_add", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
         bump
     )]
     pub vote_add: Account<'info, VoteAddToBlacklist>,
 
-    /// Optional delegation account - if present, authority must be the delegate
+    /// The remove vote of the same stake pool, which must not exist
+    /// CHECK: Only checked to be empty, use `change_vote` to flip a vote
     #[account(
-        seeds = [b"delegation", config.key().as_ref(), stake_pool.key().as_ref()],
-        bump
+        seeds = [b"vote_remove", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump,
+        constraint = vote_remove.data_is_empty() @ ValidatorBlacklistError::ConflictingVote
     )]
+    pub vote_remove: UncheckedAccount<'info>,
+
+    /// Optional vote account of the validator, its `node_pubkey` must be the validator identity
+    /// CHECK: Owner and `node_pubkey` are checked in the instruction logic
+    #[account()]
+    pub validator_vote_account: Option<UncheckedAccount<'info>>,
+
+    /// Optional delegation account - if present, authority must be the delegate and the
+    /// delegation must grant the permission for this instruction
+    #[account()]
     pub delegation: Option<Account<'info, Delegation>>,
 
     /// The authority (either manager or delegated authority)
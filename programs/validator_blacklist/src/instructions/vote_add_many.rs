@@ -0,0 +1,243 @@
+use anchor_lang::prelude::*;
+use crate::account_helpers::create_pda_account;
+use crate::authority_checks;
+use crate::pool_adapters::{load_stake_pool, validate_stake_pool_config};
+use crate::vote_account_helpers::read_node_pubkey;
+use crate::state::{Blacklist, BlacklistStatus, Delegation, VoteAddToBlacklist, Config, Evidence, ReasonCategory, MAX_EVIDENCE_URI_LENGTH, MAX_REASON_LENGTH, CONFIG_VERSION};
+use crate::error::ValidatorBlacklistError;
+use crate::events::VoteAddEvent;
+
+/// Number of remaining accounts per vote: the blacklist entry, the add vote and the remove vote,
+/// followed by the validator's vote account for votes that give one
+pub const ACCOUNTS_PER_BATCH_VOTE: usize = 3;
+
+/// One vote of a `vote_add_many` batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BatchVote {
+    pub validator_identity_address: Pubkey,
+    pub reason: String,
+    pub category: ReasonCategory,
+    pub evidence: Option<Evidence>,
+    /// The validator's vote account to record on the entry, passed after the PDAs of the vote
+    pub vote_account: Option<Pubkey>,
+}
+
+/// Vote to add several validators to the blacklist at once. For every vote the remaining
+/// accounts hold its `blacklist`, `vote_add` and `vote_remove` PDAs, in that order, and its
+/// `vote_account` if it has one. The stake pool and the authority are only checked once for the
+/// whole batch.
+pub fn vote_add_many<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VoteAddMany<'info>>,
+    votes: Vec<BatchVote>,
+) -> Result<()> {
+
+    let expected_accounts: usize = votes.iter()
+        .map(|vote| ACCOUNTS_PER_BATCH_VOTE + vote.vote_account.is_some() as usize)
+        .sum();
+    require!(
+        !votes.is_empty() && ctx.remaining_accounts.len() == expected_accounts,
+        ValidatorBlacklistError::InvalidBatchAccounts
+    );
+
+    let clock = Clock::get()?;
+    let stake_pool = load_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.config)?;
+
+    // Validate stake pool meets config requirements
+    validate_stake_pool_config(
+        &stake_pool,
+        ctx.accounts.stake_pool.owner,
+        &ctx.accounts.config,
+        &ctx.accounts.pool_override,
+    )?;
+
+    // Validate the authority
+    authority_checks::check_authority(
+        ctx.accounts.delegation.as_deref(),
+        &ctx.accounts.config.key(),
+        ctx.accounts.config.authority_policy,
+        &ctx.accounts.stake_pool.key(),
+        &stake_pool,
+        &ctx.accounts.authority.key(),
+        Delegation::PERMISSION_VOTE_ADD)?;
+
+    let config_key = ctx.accounts.config.key();
+    let stake_pool_key = ctx.accounts.stake_pool.key();
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    for vote in votes {
+        let (Some(blacklist_info), Some(vote_add_info), Some(vote_remove_info)) =
+            (remaining_accounts.next(), remaining_accounts.next(), remaining_accounts.next()) else {
+            return err!(ValidatorBlacklistError::InvalidBatchAccounts);
+        };
+        let vote_account_info = match vote.vote_account {
+            Some(vote_account) => {
+                let vote_account_info = remaining_accounts.next()
+                    .ok_or(ValidatorBlacklistError::InvalidBatchAccounts)?;
+                require_keys_eq!(vote_account_info.key(), vote_account, ValidatorBlacklistError::InvalidBatchAccounts);
+                Some(vote_account_info)
+            }
+            None => None,
+        };
+        let validator_identity_address = vote.validator_identity_address;
+
+        require!(
+            vote.reason.len() <= MAX_REASON_LENGTH,
+            ValidatorBlacklistError::ReasonTooLong
+        );
+        if let Some(evidence) = &vote.evidence {
+            require!(
+                evidence.uri.len() <= MAX_EVIDENCE_URI_LENGTH,
+                ValidatorBlacklistError::EvidenceUriTooLong
+            );
+        }
+
+        // The accounts must be the PDAs of this vote
+        let (blacklist_address, blacklist_bump) = Pubkey::find_program_address(
+            &[b"blacklist", config_key.as_ref(), validator_identity_address.as_ref()],
+            &crate::ID,
+        );
+        let (vote_add_address, vote_add_bump) = Pubkey::find_program_address(
+            &[b"vote_add", config_key.as_ref(), stake_pool_key.as_ref(), validator_identity_address.as_ref()],
+            &crate::ID,
+        );
+        let (vote_remove_address, _) = Pubkey::find_program_address(
+            &[b"vote_remove", config_key.as_ref(), stake_pool_key.as_ref(), validator_identity_address.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(blacklist_info.key(), blacklist_address, ValidatorBlacklistError::InvalidBatchAccounts);
+        require_keys_eq!(vote_add_info.key(), vote_add_address, ValidatorBlacklistError::InvalidBatchAccounts);
+        require_keys_eq!(vote_remove_info.key(), vote_remove_address, ValidatorBlacklistError::InvalidBatchAccounts);
+
+        require!(vote_add_info.data_is_empty(), ValidatorBlacklistError::VoteAlreadyCast);
+        require!(vote_remove_info.data_is_empty(), ValidatorBlacklistError::ConflictingVote);
+
+        // Initialize blacklist if this is the first vote for this validator
+        let mut blacklist = if blacklist_info.data_is_empty() {
+            create_pda_account(
+                blacklist_info,
+                &authority,
+                &system_program,
+                Blacklist::LEN,
+                &[b"blacklist", config_key.as_ref(), validator_identity_address.as_ref(), &[blacklist_bump]],
+            )?;
+
+            Blacklist {
+                validator_identity_address,
+                tally_add: 0,
+                tally_remove: 0,
+                stake_tally_add: 0,
+                stake_tally_remove: 0,
+                status: BlacklistStatus::Pending,
+                created_at: clock.unix_timestamp,
+                updated_at: clock.unix_timestamp,
+                config: config_key,
+                payer: authority.key(),
+                vote_account: None,
+                vetoed: false,
+            }
+        } else {
+            require_keys_eq!(*blacklist_info.owner, crate::ID, ValidatorBlacklistError::InvalidBatchAccounts);
+            Blacklist::try_deserialize(&mut &blacklist_info.try_borrow_data()?[..])?
+        };
+
+        // Record the vote account once it is proven to belong to the validator
+        if let Some(vote_account_info) = vote_account_info {
+            require_keys_eq!(
+                read_node_pubkey(vote_account_info)?,
+                validator_identity_address,
+                ValidatorBlacklistError::InvalidValidatorVoteAccount
+            );
+            blacklist.vote_account = Some(vote_account_info.key());
+        }
+
+        // Create the vote record
+        create_pda_account(
+            vote_add_info,
+            &authority,
+            &system_program,
+            VoteAddToBlacklist::space(vote.reason.len(), vote.evidence.as_ref().map(|evidence| evidence.uri.len())),
+            &[b"vote_add", config_key.as_ref(), stake_pool_key.as_ref(), validator_identity_address.as_ref(), &[vote_add_bump]],
+        )?;
+
+        let vote_add = VoteAddToBlacklist {
+            stake_pool: stake_pool_key,
+            validator_identity_address,
+            reason: vote.reason,
+            timestamp: clock.unix_timestamp,
+            slot: clock.slot,
+            stake_lamports: stake_pool.tvl,
+            payer: authority.key(),
+            category: vote.category,
+            evidence: vote.evidence,
+        };
+        vote_add.try_serialize(&mut &mut vote_add_info.try_borrow_mut_data()?[..])?;
+
+        // Update the tallies
+        blacklist.tally_add = blacklist.tally_add.checked_add(1)
+            .ok_or(ValidatorBlacklistError::MathOverflow)?;
+        blacklist.stake_tally_add = blacklist.stake_tally_add.checked_add(stake_pool.tvl)
+            .ok_or(ValidatorBlacklistError::MathOverflow)?;
+        blacklist.update_status(&ctx.accounts.config);
+        blacklist.updated_at = clock.unix_timestamp;
+        blacklist.try_serialize(&mut &mut blacklist_info.try_borrow_mut_data()?[..])?;
+
+        msg!("Vote to add validator {} to blacklist cast by stake pool {}",
+             validator_identity_address, stake_pool_key);
+
+        emit_cpi!(VoteAddEvent {
+            config: config_key,
+            stake_pool: stake_pool_key,
+            authority: authority.key(),
+            validator_identity_address,
+            reason: vote_add.reason,
+            category: vote_add.category,
+            evidence: vote_add.evidence,
+            stake_lamports: vote_add.stake_lamports,
+            tally_add: blacklist.tally_add,
+            tally_remove: blacklist.tally_remove,
+            stake_tally_add: blacklist.stake_tally_add,
+            stake_tally_remove: blacklist.stake_tally_remove,
+            status: blacklist.status,
+            slot: clock.slot,
+        });
+    }
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VoteAddMany<'info> {
+    /// Global configuration account, voting and delegating are rejected while it is paused
+    #[account(
+        constraint = !config.paused @ ValidatorBlacklistError::VotingPaused,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The stake pool account for stake pool that is casting the votes
+    /// CHECK: We manually validate this is a valid stake pool in the instruction logic
+    #[account()]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// Admin override of the stake pool, consulted before the program and TVL rules of the config
+    /// CHECK: Address derived from the seeds, read if it exists
+    #[account(
+        seeds = [b"pool_override", config.key().as_ref(), stake_pool.key().as_ref()],
+        bump
+    )]
+    pub pool_override: UncheckedAccount<'info>,
+
+    /// Optional delegation account - if present, authority must be the delegate and the
+    /// delegation must grant the permission for this instruction
+    #[account()]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// The authority (either manager or delegated authority), pays for the new accounts
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
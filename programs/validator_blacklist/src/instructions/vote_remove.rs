@@ -1,39 +1,52 @@
 use anchor_lang::prelude::*;
 use crate::authority_checks;
-use crate::stake_pool_helpers::{deserialize_stake_pool_with_checks, validate_stake_pool_config};
-use crate::state::{Blacklist, Delegation, VoteRemoveFromBlacklist, Config, MAX_REASON_LENGTH};
+use crate::pool_adapters::{load_stake_pool, validate_stake_pool_config};
+use crate::state::{Blacklist, Delegation, VoteRemoveFromBlacklist, Config, Evidence, ReasonCategory, MAX_EVIDENCE_URI_LENGTH, MAX_REASON_LENGTH, CONFIG_VERSION};
 use crate::error::ValidatorBlacklistError;
+use crate::events::VoteRemoveEvent;
 
 /// Vote to remove a validator from the blacklist
 pub fn vote_remove(
     ctx: Context<VoteRemove>,
     validator_identity_address: Pubkey,
     reason: String,
+    category: ReasonCategory,
+    evidence: Option<Evidence>,
 ) -> Result<()> {
 
     require!(
         reason.len() <= MAX_REASON_LENGTH,
         ValidatorBlacklistError::ReasonTooLong
     );
+    if let Some(evidence) = &evidence {
+        require!(
+            evidence.uri.len() <= MAX_EVIDENCE_URI_LENGTH,
+            ValidatorBlacklistError::EvidenceUriTooLong
+        );
+    }
         
     let blacklist = &mut ctx.accounts.blacklist;
     let vote_remove = &mut ctx.accounts.vote_remove;
     let clock = Clock::get()?;
-    let stake_pool = deserialize_stake_pool_with_checks(&ctx.accounts.stake_pool.try_borrow_data()?)?;
+    let stake_pool = load_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.config)?;
 
     // Validate stake pool meets config requirements
     validate_stake_pool_config(
         &stake_pool,
-        &ctx.accounts.stake_pool.owner,
+        ctx.accounts.stake_pool.owner,
         &ctx.accounts.config,
+        &ctx.accounts.pool_override,
     )?;
 
     // Validate the authority
     authority_checks::check_authority(
-        ctx.accounts.delegation.as_deref(), 
-        &ctx.accounts.stake_pool.key(), 
+        ctx.accounts.delegation.as_deref(),
+        &ctx.accounts.config.key(),
+        ctx.accounts.config.authority_policy,
+        &ctx.accounts.stake_pool.key(),
         &stake_pool,
-        &ctx.accounts.authority.key())?;
+        &ctx.accounts.authority.key(),
+        Delegation::PERMISSION_VOTE_REMOVE)?;
 
     // Create the vote record
     vote_remove.stake_pool = ctx.accounts.stake_pool.key();
@@ -41,22 +54,51 @@ pub fn vote_remove(
     vote_remove.reason = reason;
     vote_remove.timestamp = clock.unix_timestamp;
     vote_remove.slot = clock.slot;
+    vote_remove.stake_lamports = stake_pool.tvl;
+    vote_remove.payer = ctx.accounts.authority.key();
+    vote_remove.category = category;
+    vote_remove.evidence = evidence;
 
-    // Update the tally
+    // Update the tallies
     blacklist.tally_remove = blacklist.tally_remove.checked_add(1)
         .ok_or(ValidatorBlacklistError::MathOverflow)?;
+    blacklist.stake_tally_remove = blacklist.stake_tally_remove.checked_add(stake_pool.tvl)
+        .ok_or(ValidatorBlacklistError::MathOverflow)?;
+    blacklist.update_status(&ctx.accounts.config);
+    blacklist.updated_at = clock.unix_timestamp;
 
     msg!("Vote to remove validator {} from blacklist cast by stake pool {}", 
          validator_identity_address, ctx.accounts.stake_pool.key());
 
+    emit_cpi!(VoteRemoveEvent {
+        config: ctx.accounts.config.key(),
+        stake_pool: ctx.accounts.stake_pool.key(),
+        authority: ctx.accounts.authority.key(),
+        validator_identity_address,
+        reason: vote_remove.reason.clone(),
+        category,
+        evidence: vote_remove.evidence.clone(),
+        stake_lamports: vote_remove.stake_lamports,
+        tally_add: blacklist.tally_add,
+        tally_remove: blacklist.tally_remove,
+        stake_tally_add: blacklist.stake_tally_add,
+        stake_tally_remove: blacklist.stake_tally_remove,
+        status: blacklist.status,
+        slot: clock.slot,
+    });
+
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(validator_identity_address: Pubkey, reason: String)]
+#[instruction(validator_identity_address: Pubkey, reason: String, category: ReasonCategory, evidence: Option<Evidence>)]
 pub struct VoteRemove<'info> {
-    /// Global configuration account
-    #[account()]
+    /// Global configuration account, voting and delegating are rejected while it is paused
+    #[account(
+        constraint = !config.paused @ ValidatorBlacklistError::VotingPaused,
+        constraint = config.version == CONFIG_VERSION @ ValidatorBlacklistError::UnsupportedConfigVersion
+    )]
     pub config: Account<'info, Config>,
 
     /// The stake pool account to validate the authority
@@ -64,6 +106,14 @@ pub struct VoteRemove<'info> {
     #[account()]
     pub stake_pool: UncheckedAccount<'info>,
 
+    /// Admin override of the stake pool, consulted before the program and TVL rules of the config
+    /// CHECK: Address derived from the seeds, read if it exists
+    #[account(
+        seeds = [b"pool_override", config.key().as_ref(), stake_pool.key().as_ref()],
+        bump
+    )]
+    pub pool_override: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [b"blacklist", config.key().as_ref(), validator_identity_address.as_ref()],
@@ -74,17 +124,24 @@ pub struct VoteRemove<'info> {
     #[account(
         init,
         payer = authority,
-        space = VoteRemoveFromBlacklist::LEN,
+        space = VoteRemoveFromBlacklist::space(reason.len(), evidence.as_ref().map(|evidence| evidence.uri.len())),
         seeds = [b"vote_remove", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
         bump
     )]
     pub vote_remove: Account<'info, VoteRemoveFromBlacklist>,
 
-    /// Optional delegation account - if present, authority must be the delegate
+    /// The add vote of the same stake pool, which must not exist
+    /// CHECK: Only checked to be empty, use `change_vote` to flip a vote
     #[account(
-        seeds = [b"delegation", config.key().as_ref(), stake_pool.key().as_ref()],
-        bump
+        seeds = [b"vote_add", config.key().as_ref(), stake_pool.key().as_ref(), validator_identity_address.as_ref()],
+        bump,
+        constraint = vote_add.data_is_empty() @ ValidatorBlacklistError::ConflictingVote
     )]
+    pub vote_add: UncheckedAccount<'info>,
+
+    /// Optional delegation account - if present, authority must be the delegate and the
+    /// delegation must grant the permission for this instruction
+    #[account()]
     pub delegation: Option<Account<'info, Delegation>>,
 
     /// The authority (either manager or delegated authority)
@@ -4,11 +4,19 @@ use anchor_lang::prelude::*;
 
 pub mod instructions;
 pub mod state;
+pub mod events;
 pub mod error;
 pub mod stake_pool_helpers;
+pub mod pool_adapters;
 pub mod authority_checks;
+pub mod account_helpers;
+pub mod config_migration;
+pub mod vote_account_helpers;
+#[cfg(feature = "cpi")]
+pub mod cpi_helpers;
 
 use instructions::*;
+use state::{AllowedProgram, AuthorityPolicy, BlacklistInfo, PoolOverrideKind, Evidence, ReasonCategory, VoteDirection};
 
 declare_id!("Fu4zvEKjgxWjaQifp7fyghKJfk6HzUCaJRvoGffJBm6Q");
 
@@ -16,37 +24,109 @@ declare_id!("Fu4zvEKjgxWjaQifp7fyghKJfk6HzUCaJRvoGffJBm6Q");
 pub mod validator_blacklist {
     use super::*;
 
-    /// Initialize the global configuration
+    /// Initialize the configuration of a named blacklist
     pub fn init_config(
         ctx: Context<InitConfig>,
+        namespace: String,
         min_tvl: u64,
-        allowed_programs: Vec<Pubkey>,
+        allowed_programs: Vec<AllowedProgram>,
+        min_add_votes: u64,
+        min_net_votes: u64,
     ) -> Result<()> {
-        instructions::init_config::init_config(ctx, min_tvl, allowed_programs)
+        instructions::init_config::init_config(ctx, namespace, min_tvl, allowed_programs, min_add_votes, min_net_votes)
     }
 
-    /// Update the admin of the config
-    pub fn update_config_admin(
-        ctx: Context<UpdateConfigAdmin>,
+    /// Reallocate the config to hold a different number of allowed programs
+    pub fn resize_config(
+        ctx: Context<ResizeConfig>,
+        capacity: u16,
+    ) -> Result<()> {
+        instructions::resize_config::resize_config(ctx, capacity)
+    }
+
+    /// Pause or resume voting and delegating
+    pub fn set_paused(
+        ctx: Context<SetPaused>,
+        paused: bool,
+    ) -> Result<()> {
+        instructions::set_paused::set_paused(ctx, paused)
+    }
+
+    /// Allow or deny a single stake pool, taking precedence over the config rules
+    pub fn set_pool_override(
+        ctx: Context<SetPoolOverride>,
+        kind: PoolOverrideKind,
+    ) -> Result<()> {
+        instructions::set_pool_override::set_pool_override(ctx, kind)
+    }
+
+    /// Remove the override of a stake pool
+    pub fn remove_pool_override(
+        ctx: Context<RemovePoolOverride>,
+    ) -> Result<()> {
+        instructions::remove_pool_override::remove_pool_override(ctx)
+    }
+
+    /// Veto a blacklist entry, reporting it as overridden until the veto is lifted
+    pub fn veto(
+        ctx: Context<VetoBlacklist>,
+        validator_identity_address: Pubkey,
+        reason: String,
+    ) -> Result<()> {
+        instructions::veto::veto(ctx, validator_identity_address, reason)
+    }
+
+    /// Lift the veto of a blacklist entry
+    pub fn lift_veto(
+        ctx: Context<LiftVeto>,
+        validator_identity_address: Pubkey,
+    ) -> Result<()> {
+        instructions::lift_veto::lift_veto(ctx, validator_identity_address)
+    }
+
+    /// Propose a new admin of the config, who has to accept before taking over
+    pub fn propose_admin(
+        ctx: Context<ProposeAdmin>,
         new_admin: Pubkey,
     ) -> Result<()> {
-        instructions::update_config_admin::update_config_admin(ctx, new_admin)
+        instructions::propose_admin::propose_admin(ctx, new_admin)
+    }
+
+    /// Accept a pending admin proposal, signed by the proposed admin
+    pub fn accept_admin(
+        ctx: Context<AcceptAdmin>,
+    ) -> Result<()> {
+        instructions::accept_admin::accept_admin(ctx)
+    }
+
+    /// Cancel a pending admin proposal
+    pub fn cancel_admin_proposal(
+        ctx: Context<CancelAdminProposal>,
+    ) -> Result<()> {
+        instructions::cancel_admin_proposal::cancel_admin_proposal(ctx)
     }
 
     /// Update the config settings
     pub fn update_config(
         ctx: Context<UpdateConfig>,
         min_tvl: Option<u64>,
-        allowed_programs: Option<Vec<Pubkey>>,
+        allowed_programs: Option<Vec<AllowedProgram>>,
+        min_add_votes: Option<u64>,
+        min_net_votes: Option<u64>,
+        vote_lifetime: Option<i64>,
+        authority_policy: Option<AuthorityPolicy>,
     ) -> Result<()> {
-        instructions::update_config::update_config(ctx, min_tvl, allowed_programs)
+        instructions::update_config::update_config(ctx, min_tvl, allowed_programs, min_add_votes, min_net_votes, vote_lifetime, authority_policy)
     }
 
-    /// Delegate authority from a stake pool manager to another address
+    /// Delegate authority from a stake pool manager to another address, scoped by a permission bitmask
     pub fn delegate(
         ctx: Context<Delegate>,
+        index: u8,
+        permissions: u8,
+        expires_at: Option<i64>,
     ) -> Result<()> {
-        instructions::delegate::delegate(ctx)
+        instructions::delegate::delegate(ctx, index, permissions, expires_at)
     }
 
     /// Remove delegation of authority
@@ -56,13 +136,36 @@ pub mod validator_blacklist {
         instructions::undelegate::undelegate(ctx)
     }
 
+    /// Replace the delegate of a delegation without removing it first
+    pub fn update_delegate(
+        ctx: Context<UpdateDelegate>,
+    ) -> Result<()> {
+        instructions::update_delegate::update_delegate(ctx)
+    }
+
+    /// Close a delegation created by a previous manager of the stake pool, callable by anyone
+    pub fn close_stale_delegation(
+        ctx: Context<CloseStaleDelegation>,
+    ) -> Result<()> {
+        instructions::close_stale_delegation::close_stale_delegation(ctx)
+    }
+
+    /// Close a delegation that has expired, callable by anyone
+    pub fn close_expired_delegation(
+        ctx: Context<CloseExpiredDelegation>,
+    ) -> Result<()> {
+        instructions::close_expired_delegation::close_expired_delegation(ctx)
+    }
+
     /// Vote to add a validator to the blacklist
     pub fn vote_add(
         ctx: Context<VoteAdd>,
         validator_identity_address: Pubkey,
         reason: String,
+        category: ReasonCategory,
+        evidence: Option<Evidence>,
     ) -> Result<()> {
-        instructions::vote_add::vote_add(ctx, validator_identity_address, reason)
+        instructions::vote_add::vote_add(ctx, validator_identity_address, reason, category, evidence)
     }
 
     /// Vote to remove a validator from the blacklist
@@ -70,8 +173,19 @@ pub mod validator_blacklist {
         ctx: Context<VoteRemove>,
         validator_identity_address: Pubkey,
         reason: String,
+        category: ReasonCategory,
+        evidence: Option<Evidence>,
+    ) -> Result<()> {
+        instructions::vote_remove::vote_remove(ctx, validator_identity_address, reason, category, evidence)
+    }
+
+    /// Vote to add several validators to the blacklist in one instruction, the blacklist and vote
+    /// accounts of every validator are passed as remaining accounts
+    pub fn vote_add_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VoteAddMany<'info>>,
+        votes: Vec<BatchVote>,
     ) -> Result<()> {
-        instructions::vote_remove::vote_remove(ctx, validator_identity_address, reason)
+        instructions::vote_add_many::vote_add_many(ctx, votes)
     }
 
     /// Remove a previously cast vote to add a validator to the blacklist
@@ -89,4 +203,65 @@ pub mod validator_blacklist {
     ) -> Result<()> {
         instructions::unvote_remove::unvote_remove(ctx, validator_identity_address)
     }
+
+    /// Flip a vote of a stake pool from add to remove or the reverse in one step
+    pub fn change_vote(
+        ctx: Context<ChangeVote>,
+        validator_identity_address: Pubkey,
+        direction: VoteDirection,
+        reason: String,
+        category: ReasonCategory,
+        evidence: Option<Evidence>,
+    ) -> Result<()> {
+        instructions::change_vote::change_vote(ctx, validator_identity_address, direction, reason, category, evidence)
+    }
+
+    /// Refresh the timestamp and slot of a vote so that it does not expire
+    pub fn reaffirm_vote(
+        ctx: Context<ReaffirmVote>,
+        validator_identity_address: Pubkey,
+    ) -> Result<()> {
+        instructions::reaffirm_vote::reaffirm_vote(ctx, validator_identity_address)
+    }
+
+    /// Replace the reason of a vote, resizing the vote account as needed
+    pub fn edit_reason(
+        ctx: Context<EditReason>,
+        validator_identity_address: Pubkey,
+        reason: String,
+    ) -> Result<()> {
+        instructions::edit_reason::edit_reason(ctx, validator_identity_address, reason)
+    }
+
+    /// Close a vote that outlived the vote lifetime, callable by anyone
+    pub fn expire_vote(
+        ctx: Context<ExpireVote>,
+        validator_identity_address: Pubkey,
+    ) -> Result<()> {
+        instructions::expire_vote::expire_vote(ctx, validator_identity_address)
+    }
+
+    /// Close a vote of a stake pool that no longer meets the config requirements, callable by anyone
+    pub fn prune_vote(
+        ctx: Context<PruneVote>,
+        validator_identity_address: Pubkey,
+    ) -> Result<()> {
+        instructions::prune_vote::prune_vote(ctx, validator_identity_address)
+    }
+
+    /// Close a blacklist entry without any votes, callable by anyone
+    pub fn close_blacklist(
+        ctx: Context<CloseBlacklist>,
+        validator_identity_address: Pubkey,
+    ) -> Result<()> {
+        instructions::close_blacklist::close_blacklist(ctx, validator_identity_address)
+    }
+
+    /// Query whether a validator is blacklisted, returned through the return data
+    pub fn is_blacklisted(
+        ctx: Context<IsBlacklisted>,
+        validator_identity_address: Pubkey,
+    ) -> Result<BlacklistInfo> {
+        instructions::is_blacklisted::is_blacklisted(ctx, validator_identity_address)
+    }
 }
@@ -0,0 +1,262 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ValidatorBlacklistError;
+use crate::stake_pool_helpers::deserialize_stake_pool_with_checks;
+use crate::state::{Config, PoolAdapter, PoolOverride, PoolOverrideKind};
+
+/// Layout of the Marinade `State` account, offsets include the 8 byte Anchor discriminator
+mod marinade {
+    /// sha256("account:State")[..8]
+    pub const DISCRIMINATOR: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
+    pub const ADMIN_AUTHORITY: usize = 40;
+    pub const DELAYED_UNSTAKE_COOLING_DOWN: usize = 226;
+    pub const MANAGER_AUTHORITY: usize = 340;
+    pub const TOTAL_ACTIVE_BALANCE: usize = 376;
+    pub const AVAILABLE_RESERVE_BALANCE: usize = 496;
+    pub const CIRCULATING_TICKET_BALANCE: usize = 528;
+    pub const EMERGENCY_COOLING_DOWN: usize = 568;
+    /// End of the last field read
+    pub const MIN_LEN: usize = 576;
+}
+
+/// What the program needs to know about a stake pool, independent of the layout of its program
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolInfo {
+    /// Key that votes for the pool and may delegate that right, e.g. the SPL stake pool manager
+    pub authority: Pubkey,
+    /// Operational key of the pool that may vote directly depending on `Config.authority_policy`,
+    /// e.g. the SPL stake pool staker
+    pub staker: Pubkey,
+    /// Total value locked in lamports, weighs the votes of the pool
+    pub tvl: u64,
+}
+
+impl PoolAdapter {
+    /// Read the authority, staker and TVL from the data of a pool account
+    pub fn read(&self, data: &[u8]) -> Result<PoolInfo> {
+        match self {
+            PoolAdapter::SplStakePool => {
+                let stake_pool = deserialize_stake_pool_with_checks(data)?;
+                Ok(PoolInfo {
+                    authority: stake_pool.manager,
+                    staker: stake_pool.staker,
+                    tvl: stake_pool.total_lamports,
+                })
+            }
+            PoolAdapter::Marinade => read_marinade_state(data),
+        }
+    }
+}
+
+/// Read a Marinade `State` account. The TVL is the SOL under control of the pool minus the SOL
+/// owed to delayed unstake tickets, as in Marinade's `total_virtual_staked_lamports`.
+fn read_marinade_state(data: &[u8]) -> Result<PoolInfo> {
+    require!(
+        data.len() >= marinade::MIN_LEN && data[..8] == marinade::DISCRIMINATOR,
+        ValidatorBlacklistError::InvalidStakePool
+    );
+
+    let read_pubkey = |offset: usize| Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap());
+    let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+    let tvl = read_u64(marinade::TOTAL_ACTIVE_BALANCE)
+        .checked_add(read_u64(marinade::DELAYED_UNSTAKE_COOLING_DOWN))
+        .and_then(|tvl| tvl.checked_add(read_u64(marinade::EMERGENCY_COOLING_DOWN)))
+        .and_then(|tvl| tvl.checked_add(read_u64(marinade::AVAILABLE_RESERVE_BALANCE)))
+        .ok_or(ValidatorBlacklistError::MathOverflow)?
+        .saturating_sub(read_u64(marinade::CIRCULATING_TICKET_BALANCE));
+
+    Ok(PoolInfo {
+        authority: read_pubkey(marinade::ADMIN_AUTHORITY),
+        staker: read_pubkey(marinade::MANAGER_AUTHORITY),
+        tvl,
+    })
+}
+
+/// Read a stake pool through the adapter of its owner program in `Config.allowed_programs`.
+/// Pools of programs that are not allowed (anymore) are rejected, as are accounts the adapter
+/// cannot read. Their votes can be pruned with `prune_vote` and their delegations closed with
+/// `close_stale_delegation`.
+pub fn load_stake_pool(stake_pool: &AccountInfo, config: &Config) -> Result<PoolInfo> {
+    let adapter = config.allowed_programs
+        .iter()
+        .find(|allowed_program| allowed_program.program_id == *stake_pool.owner)
+        .map(|allowed_program| allowed_program.adapter)
+        .ok_or(ValidatorBlacklistError::UnauthorizedStakePoolProgram)?;
+
+    adapter.read(&stake_pool.try_borrow_data()?)
+}
+
+/// Read the admin override of a stake pool, `None` if the override PDA does not exist
+pub fn read_pool_override(pool_override: &AccountInfo) -> Result<Option<PoolOverride>> {
+    if pool_override.data_is_empty() {
+        return Ok(None);
+    }
+
+    require_keys_eq!(*pool_override.owner, crate::ID, ValidatorBlacklistError::InvalidPoolOverride);
+    PoolOverride::try_deserialize(&mut &pool_override.try_borrow_data()?[..])
+        .map(Some)
+        .map_err(|_| error!(ValidatorBlacklistError::InvalidPoolOverride))
+}
+
+/// Check that the stake pool may vote and delegate. An admin override of the pool is consulted
+/// first: a denied pool is rejected outright, an allowed pool skips the minimum TVL.
+pub fn validate_stake_pool_config(
+    stake_pool: &PoolInfo,
+    stake_pool_owner: &Pubkey,
+    config: &Config,
+    pool_override: &AccountInfo,
+) -> Result<()> {
+    let pool_override = read_pool_override(pool_override)?;
+
+    require!(
+        !pool_override.as_ref().is_some_and(|pool_override| pool_override.kind == PoolOverrideKind::Deny),
+        ValidatorBlacklistError::StakePoolDenied
+    );
+
+    // Check if the stake pool owner is in the allowed programs list
+    let allowed_program = config.allowed_programs
+        .iter()
+        .find(|allowed_program| allowed_program.program_id == *stake_pool_owner)
+        .ok_or(ValidatorBlacklistError::UnauthorizedStakePoolProgram)?;
+
+    // Check minimum TVL requirement, the program specific minimum takes precedence
+    require!(
+        pool_override.is_some_and(|pool_override| pool_override.kind == PoolOverrideKind::Allow)
+            || stake_pool.tvl >= allowed_program.min_tvl.unwrap_or(config.min_tvl),
+        ValidatorBlacklistError::InsufficientTvl
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `List` of the Marinade program, the header of an account holding the stake or validator records
+    #[derive(AnchorSerialize, Default)]
+    struct MarinadeList {
+        account: Pubkey,
+        item_size: u32,
+        count: u32,
+        reserved1: Pubkey,
+        reserved2: u32,
+    }
+
+    /// The Marinade `State` account, field by field as declared by the Marinade program, with
+    /// `StakeSystem`, `ValidatorSystem` and `LiqPool` inlined
+    #[derive(AnchorSerialize, Default)]
+    struct MarinadeState {
+        msol_mint: Pubkey,
+        admin_authority: Pubkey,
+        operational_sol_account: Pubkey,
+        treasury_msol_account: Pubkey,
+        reserve_bump_seed: u8,
+        msol_mint_authority_bump_seed: u8,
+        rent_exempt_for_token_acc: u64,
+        reward_fee_basis_points: u32,
+        // StakeSystem
+        stake_list: MarinadeList,
+        delayed_unstake_cooling_down: u64,
+        stake_deposit_bump_seed: u8,
+        stake_withdraw_bump_seed: u8,
+        slots_for_stake_delta: u64,
+        last_stake_delta_epoch: u64,
+        min_stake: u64,
+        extra_stake_delta_runs: u32,
+        // ValidatorSystem
+        validator_list: MarinadeList,
+        manager_authority: Pubkey,
+        total_validator_score: u32,
+        total_active_balance: u64,
+        auto_add_validator_enabled: u8,
+        // LiqPool
+        lp_mint: Pubkey,
+        lp_mint_authority_bump_seed: u8,
+        sol_leg_bump_seed: u8,
+        msol_leg_authority_bump_seed: u8,
+        msol_leg: Pubkey,
+        lp_liquidity_target: u64,
+        lp_max_fee_basis_points: u32,
+        lp_min_fee_basis_points: u32,
+        treasury_cut_basis_points: u32,
+        lp_supply: u64,
+        lent_from_sol_leg: u64,
+        liquidity_sol_cap: u64,
+        // State
+        available_reserve_balance: u64,
+        msol_supply: u64,
+        msol_price: u64,
+        circulating_ticket_count: u64,
+        circulating_ticket_balance: u64,
+        lent_from_reserve: u64,
+        min_deposit: u64,
+        min_withdraw: u64,
+        staking_sol_cap: u64,
+        emergency_cooling_down: u64,
+        pause_authority: Pubkey,
+        paused: bool,
+    }
+
+    fn marinade_state_data(state: &MarinadeState) -> Vec<u8> {
+        let mut data = marinade::DISCRIMINATOR.to_vec();
+        state.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_read_marinade_state() {
+        let state = MarinadeState {
+            msol_mint: Pubkey::new_unique(),
+            admin_authority: Pubkey::new_unique(),
+            operational_sol_account: Pubkey::new_unique(),
+            treasury_msol_account: Pubkey::new_unique(),
+            reserve_bump_seed: 255,
+            msol_mint_authority_bump_seed: 254,
+            rent_exempt_for_token_acc: 2_039_280,
+            reward_fee_basis_points: 600,
+            stake_list: MarinadeList { account: Pubkey::new_unique(), item_size: 57, count: 1_200, ..Default::default() },
+            delayed_unstake_cooling_down: 30_000,
+            slots_for_stake_delta: 3_000,
+            min_stake: 1_000_000_000,
+            validator_list: MarinadeList { account: Pubkey::new_unique(), item_size: 61, count: 400, ..Default::default() },
+            manager_authority: Pubkey::new_unique(),
+            total_validator_score: 1_000_000,
+            total_active_balance: 7_000_000,
+            auto_add_validator_enabled: 1,
+            lp_mint: Pubkey::new_unique(),
+            msol_leg: Pubkey::new_unique(),
+            lp_supply: 123_456,
+            liquidity_sol_cap: u64::MAX,
+            available_reserve_balance: 400_000,
+            msol_supply: 5_000_000,
+            msol_price: 5_000_000_000,
+            circulating_ticket_count: 12,
+            circulating_ticket_balance: 100_000,
+            min_deposit: 1,
+            staking_sol_cap: u64::MAX,
+            emergency_cooling_down: 5_000,
+            pause_authority: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let data = marinade_state_data(&state);
+
+        let pool = PoolAdapter::Marinade.read(&data).unwrap();
+        assert_eq!(pool.authority, state.admin_authority);
+        assert_eq!(pool.staker, state.manager_authority);
+        // total_active_balance + delayed_unstake_cooling_down + emergency_cooling_down
+        // + available_reserve_balance - circulating_ticket_balance
+        assert_eq!(pool.tvl, 7_000_000 + 30_000 + 5_000 + 400_000 - 100_000);
+    }
+
+    #[test]
+    fn test_read_marinade_state_rejects_other_accounts() {
+        let data = marinade_state_data(&MarinadeState::default());
+        assert!(PoolAdapter::Marinade.read(&data[..marinade::MIN_LEN - 1]).is_err());
+
+        let mut other_account = data.clone();
+        other_account[..8].copy_from_slice(&[0; 8]);
+        assert!(PoolAdapter::Marinade.read(&other_account).is_err());
+    }
+}
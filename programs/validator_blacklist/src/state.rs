@@ -1,20 +1,152 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ValidatorBlacklistError;
+use crate::pool_adapters::PoolInfo;
+
 pub const MAX_REASON_LENGTH: usize = 1024;
-const MAX_ALLOWED_PROGRAMS: usize = 10;
+pub const MAX_EVIDENCE_URI_LENGTH: usize = 128;
+pub const MAX_NAMESPACE_LENGTH: usize = 32;
+pub const DEFAULT_ALLOWED_PROGRAMS: usize = 10;
+pub const MAX_ALLOWED_PROGRAMS: usize = 64;
+/// Layout version of `Config`, bumped with every layout change and migrated by `resize_config`.
+/// Configs created before versions were introduced are migrated to version 1, see `config_migration`
+pub const CONFIG_VERSION: u8 = 1;
 
-/// Global configuration for the validator blacklist program
+/// Configuration of one blacklist, derived from `["config", namespace]`
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
+    pub version: u8,                       // 1 byte - layout version, see CONFIG_VERSION
     pub admin: Pubkey,                     // 32 bytes - admin who can update config
-    pub min_tvl: u64,                      // 8 bytes - minimum total value locked required
-    #[max_len(MAX_ALLOWED_PROGRAMS)]
-    pub allowed_programs: Vec<Pubkey>,     // 4 + (32 * 10) bytes - allowed stake pool programs
+    pub min_tvl: u64,                      // 8 bytes - minimum total value locked required, unless overridden per program
+    #[max_len(DEFAULT_ALLOWED_PROGRAMS)]
+    pub allowed_programs: Vec<AllowedProgram>, // 4 + (42 * capacity) bytes - allowed stake pool programs, see `resize_config`
+    pub min_add_votes: u64,                // 8 bytes - minimum add votes (quorum) before a validator is blacklisted
+    pub min_net_votes: u64,                // 8 bytes - minimum margin of add votes over remove votes before a validator is blacklisted
+    pub pending_admin: Option<Pubkey>,     // 1 + 32 bytes - proposed admin that has yet to accept
+    #[max_len(MAX_NAMESPACE_LENGTH)]
+    pub namespace: String,                 // 4 + up to 32 bytes - name of the blacklist, e.g. "mev-sandwich"
+    pub bump: u8,                          // 1 byte - bump of the config PDA
+    pub paused: bool,                      // 1 byte - emergency switch rejecting new votes and delegations
+    pub vote_lifetime: i64,                // 8 bytes - seconds after which a vote can be expired unless reaffirmed, 0 = never
+    pub authority_policy: AuthorityPolicy, // 1 byte - stake pool roles that may vote directly, i.e. without a delegation
 }
 
 impl Config {
-    pub const LEN: usize = 8 + 32 + 8 + 4 + (32 * MAX_ALLOWED_PROGRAMS); // discriminator + admin + min_tvl + vec len + allowed_programs
+    pub const LEN: usize = Self::space(DEFAULT_ALLOWED_PROGRAMS);
+
+    /// Account size needed to hold `capacity` allowed programs
+    pub const fn space(capacity: usize) -> usize {
+        8 + 1 + 32 + 8 + 4 + (AllowedProgram::INIT_SPACE * capacity) + 8 + 8 + 1 + 32 + 4 + MAX_NAMESPACE_LENGTH + 1 + 1 + 8 + 1 // discriminator + version + admin + min_tvl + vec len + allowed_programs + min_add_votes + min_net_votes + pending_admin + string len + namespace + bump + paused + vote_lifetime + authority_policy
+    }
+
+    /// Number of allowed programs that fit into an account of `data_len` bytes
+    pub fn capacity(data_len: usize) -> usize {
+        data_len.saturating_sub(Self::space(0)) / AllowedProgram::INIT_SPACE
+    }
+
+    /// Whether a vote last cast or reaffirmed at `timestamp` has outlived `vote_lifetime`
+    pub fn is_vote_expired(&self, timestamp: i64, now: i64) -> bool {
+        self.vote_lifetime > 0 && now.saturating_sub(timestamp) >= self.vote_lifetime
+    }
+}
+
+/// A stake pool program that is allowed to vote, with an optional program specific minimum TVL
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct AllowedProgram {
+    pub program_id: Pubkey,                // 32 bytes - the stake pool program
+    pub min_tvl: Option<u64>,              // 1 + 8 bytes - minimum TVL for pools of this program, falls back to Config.min_tvl
+    pub adapter: PoolAdapter,              // 1 byte - how the authority and TVL are read from pools of this program
+}
+
+/// Account layout of a stake pool program, see `pool_adapters`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub enum PoolAdapter {
+    /// SPL stake pool and forks sharing its layout, the manager is the authority
+    #[default]
+    SplStakePool,
+    /// Marinade liquid staking, the admin authority is the authority and the validator manager
+    /// authority is the staker
+    Marinade,
+}
+
+/// Stake pool roles that may vote and unvote directly. Delegations are always created by the
+/// pool authority (the manager of an SPL stake pool), regardless of the policy.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub enum AuthorityPolicy {
+    /// Only the manager of the stake pool
+    #[default]
+    ManagerOnly,
+    /// Only the staker of the stake pool
+    StakerOnly,
+    /// Either the manager or the staker of the stake pool
+    Either,
+}
+
+impl AuthorityPolicy {
+    /// Whether `authority` holds a stake pool role allowed by the policy
+    pub fn allows(&self, stake_pool: &PoolInfo, authority: &Pubkey) -> bool {
+        match self {
+            AuthorityPolicy::ManagerOnly => stake_pool.authority == *authority,
+            AuthorityPolicy::StakerOnly => stake_pool.staker == *authority,
+            AuthorityPolicy::Either => stake_pool.authority == *authority || stake_pool.staker == *authority,
+        }
+    }
+}
+
+/// Blacklist status derived from the tallies and the thresholds in the config
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlacklistStatus {
+    /// Votes have been cast, but the thresholds have not been met
+    #[default]
+    Pending,
+    /// The add votes meet both the quorum and the margin
+    Blacklisted,
+    /// The remove votes match or outnumber the add votes
+    Cleared,
+    /// The admin vetoed the entry, regardless of the tallies, see `Veto`
+    Overridden,
+}
+
+/// Direction of a vote, used by instructions that handle both kinds of vote records
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoteDirection {
+    Add,
+    Remove,
+}
+
+/// Category of misbehaviour a vote is about
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub enum ReasonCategory {
+    /// Sandwiching or other MEV extraction against users
+    Sandwiching,
+    /// The validator is delinquent or frequently offline
+    Delinquency,
+    /// The commission was raised abruptly, typically right before an epoch boundary
+    CommissionRug,
+    /// Transactions are censored or deliberately left out of blocks
+    Censorship,
+    /// Anything else, described by the free-text reason
+    #[default]
+    Other,
+}
+
+/// Reference to an evidence bundle stored off-chain (Arweave, IPFS, ...)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct Evidence {
+    pub hash: [u8; 32],                    // 32 bytes - content hash of the evidence bundle, e.g. sha256
+    #[max_len(MAX_EVIDENCE_URI_LENGTH)]
+    pub uri: String,                       // 4 + up to MAX_EVIDENCE_URI_LENGTH bytes - where the bundle can be fetched
+}
+
+impl Evidence {
+    /// Serialized size of an optional evidence reference whose URI is `uri_len` bytes long
+    pub const fn option_space(uri_len: Option<usize>) -> usize {
+        match uri_len {
+            Some(len) => 1 + 32 + 4 + len, // option tag + hash + string len + uri
+            None => 1,                     // option tag
+        }
+    }
 }
 
 /// State account representing a validator that has votes for blacklisting
@@ -23,23 +155,120 @@ pub struct Blacklist {
     pub validator_identity_address: Pubkey,    // 32 bytes
     pub tally_add: u64,              // 8 bytes - votes to add to blacklist
     pub tally_remove: u64,           // 8 bytes - votes to remove from blacklist
+    pub stake_tally_add: u64,        // 8 bytes - sum of stake pool lamports behind the add votes
+    pub stake_tally_remove: u64,     // 8 bytes - sum of stake pool lamports behind the remove votes
+    pub status: BlacklistStatus,     // 1 byte - status derived from the tallies, see `update_status`
+    pub created_at: i64,             // 8 bytes - UTC timestamp of the first vote
+    pub updated_at: i64,             // 8 bytes - UTC timestamp of the last vote or unvote
+    pub config: Pubkey,              // 32 bytes - the config (blacklist namespace) this entry belongs to
+    pub payer: Pubkey,               // 32 bytes - who paid the rent, refunded when the entry is closed
+    pub vote_account: Option<Pubkey>, // 1 + 32 bytes - the validator's vote account, once a vote has supplied it
+    pub vetoed: bool,                // 1 byte - whether the admin vetoed the entry, see `Veto`
 }
 
 impl Blacklist {
-    pub const LEN: usize = 8 + 32 + 8 + 8; // discriminator + validator_identity_address + tally_add + tally_remove
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 1 + 8 + 8 + 32 + 32 + 1 + 32 + 1; // discriminator + validator_identity_address + tally_add + tally_remove + stake_tally_add + stake_tally_remove + status + created_at + updated_at + config + payer + vote_account + vetoed
+
+    /// Offset of `vote_account` in the account data. A `getProgramAccounts` memcmp of `[1]`
+    /// followed by the vote account at this offset finds the entry of a vote account.
+    pub const VOTE_ACCOUNT_OFFSET: usize = 8 + 32 + 8 + 8 + 8 + 8 + 1 + 8 + 8 + 32 + 32;
+
+    /// Recalculate the status from the current tallies. A validator is blacklisted once it has
+    /// at least one add vote, reaches `min_add_votes` and leads the remove votes by at least
+    /// `min_net_votes`. Otherwise it is cleared if the remove votes match or outnumber the add votes.
+    /// A vetoed entry stays overridden whatever the tallies are.
+    pub fn update_status(&mut self, config: &Config) {
+        // None if the remove votes outnumber the add votes, which no margin is met by
+        let net_votes = self.tally_add.checked_sub(self.tally_remove);
+
+        self.status = if self.vetoed {
+            BlacklistStatus::Overridden
+        } else if self.tally_add > 0
+            && self.tally_add >= config.min_add_votes
+            && net_votes.is_some_and(|net_votes| net_votes >= config.min_net_votes)
+        {
+            BlacklistStatus::Blacklisted
+        } else if self.tally_remove > 0 && self.tally_remove >= self.tally_add {
+            BlacklistStatus::Cleared
+        } else {
+            BlacklistStatus::Pending
+        };
+    }
+
+    /// Whether every vote on this validator has been withdrawn or expired
+    pub fn is_empty(&self) -> bool {
+        self.tally_add == 0 && self.tally_remove == 0
+    }
+
+    /// Take back what an add vote contributed to the tallies
+    pub fn remove_add_vote(&mut self, stake_lamports: u64) -> Result<()> {
+        self.tally_add = self.tally_add.checked_sub(1)
+            .ok_or(ValidatorBlacklistError::MathUnderflow)?;
+        self.stake_tally_add = self.stake_tally_add.checked_sub(stake_lamports)
+            .ok_or(ValidatorBlacklistError::MathUnderflow)?;
+        Ok(())
+    }
+
+    /// Take back what a remove vote contributed to the tallies
+    pub fn remove_remove_vote(&mut self, stake_lamports: u64) -> Result<()> {
+        self.tally_remove = self.tally_remove.checked_sub(1)
+            .ok_or(ValidatorBlacklistError::MathUnderflow)?;
+        self.stake_tally_remove = self.stake_tally_remove.checked_sub(stake_lamports)
+            .ok_or(ValidatorBlacklistError::MathUnderflow)?;
+        Ok(())
+    }
 }
 
-/// State account representing delegation from a stake pool manager to another authority
+/// Return data of the `is_blacklisted` instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BlacklistInfo {
+    pub validator_identity_address: Pubkey,
+    pub vote_account: Option<Pubkey>, // the validator's vote account, once a vote has supplied it
+    pub exists: bool,                // false if no vote has ever been cast for the validator
+    pub status: BlacklistStatus,
+    pub tally_add: u64,
+    pub tally_remove: u64,
+    pub stake_tally_add: u64,
+    pub stake_tally_remove: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// State account representing delegation from a stake pool manager to another authority. A stake
+/// pool can have several delegations, derived from `["delegation", config, stake_pool, index]`,
+/// each scoped to the instructions allowed by its `permissions`.
 #[account]
 pub struct Delegation {
     pub stake_pool: Pubkey,          // 32 bytes - the stake pool address
     pub manager: Pubkey,             // 32 bytes - the manager of the stake pool
     pub delegate: Pubkey,            // 32 bytes - the delegated authority
     pub timestamp: i64,              // 8 bytes - when delegation was created
+    pub config: Pubkey,              // 32 bytes - the config (blacklist namespace) this delegation belongs to
+    pub index: u8,                   // 1 byte - index of the delegation among the delegations of the stake pool
+    pub permissions: u8,             // 1 byte - bitmask of the PERMISSION_* instructions the delegate may call
+    pub expires_at: Option<i64>,     // 1 + 8 bytes - UTC timestamp from which the delegation is no longer valid
 }
 
 impl Delegation {
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 8; // discriminator + stake_pool + manager + delegate + timestamp
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 32 + 1 + 1 + 1 + 8; // discriminator + stake_pool + manager + delegate + timestamp + config + index + permissions + expires_at
+
+    /// May call `vote_add` and reaffirm add votes
+    pub const PERMISSION_VOTE_ADD: u8 = 1 << 0;
+    /// May call `unvote_add`
+    pub const PERMISSION_UNVOTE_ADD: u8 = 1 << 1;
+    /// May call `vote_remove` and reaffirm remove votes
+    pub const PERMISSION_VOTE_REMOVE: u8 = 1 << 2;
+    /// May call `unvote_remove`
+    pub const PERMISSION_UNVOTE_REMOVE: u8 = 1 << 3;
+    pub const PERMISSION_ALL: u8 = Self::PERMISSION_VOTE_ADD
+        | Self::PERMISSION_UNVOTE_ADD
+        | Self::PERMISSION_VOTE_REMOVE
+        | Self::PERMISSION_UNVOTE_REMOVE;
+
+    /// Whether the delegation has run out at `now`
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
 }
 
 /// Vote record for adding a validator to the blacklist
@@ -49,13 +278,23 @@ pub struct VoteAddToBlacklist {
     pub stake_pool : Pubkey,         // 32 bytes - the stake pool who cast this vote
     pub validator_identity_address: Pubkey,   // 32 bytes - the validator being voted on
     #[max_len(MAX_REASON_LENGTH)]
-    pub reason: String,              // 4 + up to MAX_REASON_LENGTH bytes - reason for the vote
+    pub reason: String,              // 4 + up to MAX_REASON_LENGTH bytes - reason for the vote, the account is sized to fit it
     pub timestamp: i64,              // 8 bytes - UTC timestamp
     pub slot: u64,                   // 8 bytes - slot when vote was cast
+    pub stake_lamports: u64,         // 8 bytes - stake pool total_lamports at the time of the vote
+    pub payer: Pubkey,               // 32 bytes - who paid the rent, refunded when the vote expires
+    pub category: ReasonCategory,    // 1 byte - category of misbehaviour
+    pub evidence: Option<Evidence>,  // 1 + 32 + 4 + up to MAX_EVIDENCE_URI_LENGTH bytes - optional off-chain evidence reference
 }
 
 impl VoteAddToBlacklist {
-    pub const LEN: usize = 8 + 32 + 32 + 4 + MAX_REASON_LENGTH + 8 + 8; // discriminator + operator + validator_identity_address + string len + reason + timestamp + slot
+    /// Size of a vote with the longest possible reason and evidence URI
+    pub const LEN: usize = Self::space(MAX_REASON_LENGTH, Some(MAX_EVIDENCE_URI_LENGTH));
+
+    /// Account size needed for a vote with a reason of `reason_len` bytes and an optional evidence URI of `evidence_uri_len` bytes
+    pub const fn space(reason_len: usize, evidence_uri_len: Option<usize>) -> usize {
+        8 + 32 + 32 + 4 + reason_len + 8 + 8 + 8 + 32 + 1 + Evidence::option_space(evidence_uri_len) // discriminator + operator + validator_identity_address + string len + reason + timestamp + slot + stake_lamports + payer + category + evidence
+    }
 }
 
 /// Vote record for removing a validator from the blacklist
@@ -65,11 +304,136 @@ pub struct VoteRemoveFromBlacklist {
     pub stake_pool: Pubkey,          // 32 bytes - the stake pool who cast this vote
     pub validator_identity_address: Pubkey,   // 32 bytes - the validator being voted on
     #[max_len(MAX_REASON_LENGTH)]
-    pub reason: String,              // 4 + up to MAX_REASON_LENGTH bytes - reason for the vote
+    pub reason: String,              // 4 + up to MAX_REASON_LENGTH bytes - reason for the vote, the account is sized to fit it
     pub timestamp: i64,              // 8 bytes - UTC timestamp
     pub slot: u64,                   // 8 bytes - slot when vote was cast
+    pub stake_lamports: u64,         // 8 bytes - stake pool total_lamports at the time of the vote
+    pub payer: Pubkey,               // 32 bytes - who paid the rent, refunded when the vote expires
+    pub category: ReasonCategory,    // 1 byte - category of misbehaviour
+    pub evidence: Option<Evidence>,  // 1 + 32 + 4 + up to MAX_EVIDENCE_URI_LENGTH bytes - optional off-chain evidence reference
 }
 
 impl VoteRemoveFromBlacklist {
-    pub const LEN: usize = 8 + 32 + 32 + 4 + MAX_REASON_LENGTH + 8 + 8; // discriminator + operator + validator_identity_address + string len + reason + timestamp + slot
+    /// Size of a vote with the longest possible reason and evidence URI
+    pub const LEN: usize = Self::space(MAX_REASON_LENGTH, Some(MAX_EVIDENCE_URI_LENGTH));
+
+    /// Account size needed for a vote with a reason of `reason_len` bytes and an optional evidence URI of `evidence_uri_len` bytes
+    pub const fn space(reason_len: usize, evidence_uri_len: Option<usize>) -> usize {
+        8 + 32 + 32 + 4 + reason_len + 8 + 8 + 8 + 32 + 1 + Evidence::option_space(evidence_uri_len) // discriminator + operator + validator_identity_address + string len + reason + timestamp + slot + stake_lamports + payer + category + evidence
+    }
+}
+
+/// Admin override for a single stake pool, derived from `["pool_override", config, stake_pool]`.
+/// Consulted by `validate_stake_pool_config` before the program and TVL rules of the config.
+#[account]
+pub struct PoolOverride {
+    pub config: Pubkey,              // 32 bytes - the config this override belongs to
+    pub stake_pool: Pubkey,          // 32 bytes - the stake pool address
+    pub kind: PoolOverrideKind,      // 1 byte - whether the pool is admitted or banned
+    pub timestamp: i64,              // 8 bytes - when the override was last set
+    pub bump: u8,                    // 1 byte - bump of the override PDA
+}
+
+impl PoolOverride {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 1; // discriminator + config + stake_pool + kind + timestamp + bump
+}
+
+/// How a `PoolOverride` treats its stake pool
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolOverrideKind {
+    /// Admit the pool regardless of the minimum TVL, its program must still be allowed
+    Allow,
+    /// Ban the pool from voting and delegating, its existing votes can be pruned
+    Deny,
+}
+
+/// Admin veto of a blacklist entry, derived from `["veto", config, validator_identity_address]`.
+/// While it exists the entry is `Overridden`, see `veto` and `lift_veto`.
+#[account]
+pub struct Veto {
+    pub config: Pubkey,              // 32 bytes - the config this veto belongs to
+    pub validator_identity_address: Pubkey, // 32 bytes - the validator whose entry is vetoed
+    pub admin: Pubkey,               // 32 bytes - the admin who vetoed, receives the rent when lifted
+    pub reason: String,              // 4 + up to MAX_REASON_LENGTH bytes - why the entry was vetoed, the account is sized to fit it
+    pub timestamp: i64,              // 8 bytes - UTC timestamp of the veto
+    pub bump: u8,                    // 1 byte - bump of the veto PDA
+}
+
+impl Veto {
+    /// Account size needed for a veto with a reason of `reason_len` bytes
+    pub const fn space(reason_len: usize) -> usize {
+        8 + 32 + 32 + 32 + 4 + reason_len + 8 + 1 // discriminator + config + validator_identity_address + admin + string len + reason + timestamp + bump
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(min_add_votes: u64, min_net_votes: u64) -> Config {
+        Config {
+            version: CONFIG_VERSION,
+            admin: Pubkey::new_unique(),
+            min_tvl: 0,
+            allowed_programs: vec![],
+            min_add_votes,
+            min_net_votes,
+            pending_admin: None,
+            namespace: "test".to_string(),
+            bump: 0,
+            paused: false,
+            vote_lifetime: 0,
+            authority_policy: AuthorityPolicy::ManagerOnly,
+        }
+    }
+
+    fn blacklist(tally_add: u64, tally_remove: u64) -> Blacklist {
+        Blacklist {
+            validator_identity_address: Pubkey::new_unique(),
+            tally_add,
+            tally_remove,
+            stake_tally_add: 0,
+            stake_tally_remove: 0,
+            status: BlacklistStatus::Pending,
+            created_at: 0,
+            updated_at: 0,
+            config: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+            vote_account: None,
+            vetoed: false,
+        }
+    }
+
+    fn status(tally_add: u64, tally_remove: u64, config: &Config) -> BlacklistStatus {
+        let mut blacklist = blacklist(tally_add, tally_remove);
+        blacklist.update_status(config);
+        blacklist.status
+    }
+
+    #[test]
+    fn test_update_status_without_margin() {
+        let config = config(1, 0);
+        assert_eq!(status(1, 0, &config), BlacklistStatus::Blacklisted);
+        assert_eq!(status(1, 1, &config), BlacklistStatus::Blacklisted);
+        assert_eq!(status(1, 5, &config), BlacklistStatus::Cleared);
+        assert_eq!(status(0, 1, &config), BlacklistStatus::Cleared);
+    }
+
+    #[test]
+    fn test_update_status_with_quorum_and_margin() {
+        let config = config(3, 2);
+        assert_eq!(status(2, 0, &config), BlacklistStatus::Pending);
+        assert_eq!(status(3, 2, &config), BlacklistStatus::Pending);
+        assert_eq!(status(4, 2, &config), BlacklistStatus::Blacklisted);
+        assert_eq!(status(3, 3, &config), BlacklistStatus::Cleared);
+        assert_eq!(status(u64::MAX, 0, &config), BlacklistStatus::Blacklisted);
+    }
+
+    #[test]
+    fn test_update_status_vetoed() {
+        let mut blacklist = blacklist(5, 0);
+        blacklist.vetoed = true;
+        blacklist.update_status(&config(1, 0));
+        assert_eq!(blacklist.status, BlacklistStatus::Overridden);
+    }
 }
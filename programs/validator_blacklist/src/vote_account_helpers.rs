@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote;
+
+use crate::error::ValidatorBlacklistError;
+
+/// Offset of `node_pubkey` in every version of the vote state, right after the version tag
+const NODE_PUBKEY_OFFSET: usize = 4;
+
+/// Read the validator identity (`node_pubkey`) of a vote account
+pub fn read_node_pubkey(vote_account: &AccountInfo) -> Result<Pubkey> {
+    require_keys_eq!(
+        *vote_account.owner,
+        vote::program::ID,
+        ValidatorBlacklistError::InvalidValidatorVoteAccount
+    );
+
+    let data = vote_account.try_borrow_data()?;
+    let node_pubkey = data
+        .get(NODE_PUBKEY_OFFSET..NODE_PUBKEY_OFFSET + 32)
+        .ok_or(ValidatorBlacklistError::InvalidValidatorVoteAccount)?;
+
+    Pubkey::try_from(node_pubkey).map_err(|_| error!(ValidatorBlacklistError::InvalidValidatorVoteAccount))
+}
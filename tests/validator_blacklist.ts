@@ -54,6 +54,18 @@ const StakePoolLayout = struct<StakePoolLayout>([
     u64('lastUpdateEpoch'),
 ]);
 
+// Delegation permission bits, see Delegation::PERMISSION_* in the program
+const VOTE_PROGRAM_ID = new PublicKey("Vote111111111111111111111111111111111111111");
+const VOTE_ADD_PERMISSION = 1 << 0;
+const UNVOTE_ADD_PERMISSION = 1 << 1;
+const ALL_PERMISSIONS = 0b1111;
+
+// Size of a vote account, see VoteAddToBlacklist::space in the program
+function voteAccountSize(reason: string, evidenceUri?: string) {
+    const evidenceSize = evidenceUri === undefined ? 1 : 1 + 32 + 4 + Buffer.byteLength(evidenceUri);
+    return 8 + 32 + 32 + 4 + Buffer.byteLength(reason) + 8 + 8 + 8 + 32 + 1 + evidenceSize;
+}
+
 function expectInstructionErrorCustomCode(result: FailedTransactionMetadata, code: number) {
 
     const error = result.err();
@@ -79,6 +91,18 @@ function expectSuccessfulTransaction(result: TransactionMetadata | FailedTransac
         `Expected successful transaction metadata, got ${result.toString()}`);
 }
 
+// Anchor's EVENT_IX_TAG in front of the data of every emit_cpi! self CPI, followed by the event
+const EVENT_IX_TAG = Buffer.from([0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d]);
+
+// Decode the events a transaction emitted through the self CPIs of emit_cpi!
+function decodeCpiEvents(program: Program<ValidatorBlacklist>, result: TransactionMetadata) {
+    return result.innerInstructions()
+        .flat()
+        .map((innerInstruction) => Buffer.from(innerInstruction.instruction().data()))
+        .filter((data) => data.subarray(0, 8).equals(EVENT_IX_TAG))
+        .map((data) => program.coder.events.decode(data.subarray(8).toString("base64")));
+}
+
 async function cloneAccount(path: string, svm: LiteSVM, account: PublicKey, modifier = (data: Buffer) => data, owner = STAKE_POOL_PROGRAM_ID) {
     const {account: accountInfo} = JSON.parse(fs.readFileSync(path).toString());
     if (accountInfo) {
@@ -101,6 +125,8 @@ describe("Validator Blacklist with LiteSVM", () => {
     // Stake pool account (vSOL stake pool)
     const stakePoolAddress1 = Keypair.generate().publicKey;
     const stakePoolAddress2 = Keypair.generate().publicKey;
+    // A pool cannot vote both ways on a validator, so the remove vote is cast by a third pool
+    const stakePoolAddress3 = Keypair.generate().publicKey;
     const stakePoolBadProgram = Keypair.generate().publicKey;
     const stakePoolManager = Keypair.generate();
 
@@ -108,7 +134,7 @@ describe("Validator Blacklist with LiteSVM", () => {
     let unauthorizedUser: Keypair;
     let validatorToBlacklist: PublicKey;
     let configAdmin: Keypair;
-    let configAddress: Keypair;
+    let configAddress: PublicKey;
 
     // PDAs
     let delegationPda: PublicKey;
@@ -163,6 +189,16 @@ describe("Validator Blacklist with LiteSVM", () => {
             return buffer;
         });
 
+        await cloneAccount("./tests/accounts/stakePool.json", svm, stakePoolAddress3, (data) => {
+            const stakePoolDeserialized = StakePoolLayout.decode(data);
+            stakePoolDeserialized.manager = stakePoolManager.publicKey;
+            const buffer = Buffer.alloc(data.length);
+
+            StakePoolLayout.encode(stakePoolDeserialized, buffer);
+
+            return buffer;
+        });
+
         await cloneAccount("./tests/accounts/stakePool.json", svm, stakePoolBadProgram, (data) => {
             const stakePoolDeserialized = StakePoolLayout.decode(data);
             stakePoolDeserialized.manager = stakePoolManager.publicKey;
@@ -217,16 +253,22 @@ describe("Validator Blacklist with LiteSVM", () => {
         program = new Program(idl, mockProvider) as Program<ValidatorBlacklist>;
 
         // Calculate config PDA
-        configAddress = Keypair.generate();
+        [configAddress] = PublicKey.findProgramAddressSync(
+            [Buffer.from("config"), Buffer.from("mev-sandwich")],
+            programId
+        );
 
         // Initialize the config first
         const initConfigIx = await program.methods
             .initConfig(
+                "mev-sandwich",
                 new BN(1000000000), // 1 SOL minimum TVL
-                [STAKE_POOL_PROGRAM_ID] // Allow the vSOL stake pool program
+                [{programId: STAKE_POOL_PROGRAM_ID, minTvl: null, adapter: { splStakePool: {} }}], // Allow the vSOL stake pool program
+                new BN(1), // A single add vote is enough for a quorum
+                new BN(1) // Add votes must lead remove votes by at least one
             )
-            .accounts({
-                config: configAddress.publicKey,
+            .accountsPartial({
+                config: configAddress,
                 admin: configAdmin.publicKey,
                 systemProgram: SystemProgram.programId,
             })
@@ -235,7 +277,7 @@ describe("Validator Blacklist with LiteSVM", () => {
         const initConfigTx = new Transaction().add(initConfigIx);
         initConfigTx.feePayer = configAdmin.publicKey;
         initConfigTx.recentBlockhash = svm.latestBlockhash();
-        initConfigTx.sign(configAdmin, configAddress);
+        initConfigTx.sign(configAdmin);
         const initConfigResult = svm.sendTransaction(initConfigTx);
         expectSuccessfulTransaction(initConfigResult);
 
@@ -243,8 +285,9 @@ describe("Validator Blacklist with LiteSVM", () => {
         [delegationPda] = PublicKey.findProgramAddressSync(
             [
                 Buffer.from("delegation"),
-                configAddress.publicKey.toBuffer(),
-                stakePoolAddress1.toBuffer()
+                configAddress.toBuffer(),
+                stakePoolAddress1.toBuffer(),
+                Buffer.from([0])
             ],
             programId
         );
@@ -252,7 +295,7 @@ describe("Validator Blacklist with LiteSVM", () => {
         [blacklistPda] = PublicKey.findProgramAddressSync(
             [
                 Buffer.from("blacklist"),
-                configAddress.publicKey.toBuffer(),
+                configAddress.toBuffer(),
                 validatorToBlacklist.toBuffer()
             ],
             programId
@@ -261,7 +304,7 @@ describe("Validator Blacklist with LiteSVM", () => {
         [voteAddPda] = PublicKey.findProgramAddressSync(
             [
                 Buffer.from("vote_add"),
-                configAddress.publicKey.toBuffer(),
+                configAddress.toBuffer(),
                 stakePoolAddress1.toBuffer(),
                 validatorToBlacklist.toBuffer()
             ],
@@ -271,8 +314,8 @@ describe("Validator Blacklist with LiteSVM", () => {
         [voteRemovePda] = PublicKey.findProgramAddressSync(
             [
                 Buffer.from("vote_remove"),
-                configAddress.publicKey.toBuffer(),
-                stakePoolAddress1.toBuffer(),
+                configAddress.toBuffer(),
+                stakePoolAddress3.toBuffer(),
                 validatorToBlacklist.toBuffer()
             ],
             programId
@@ -281,21 +324,30 @@ describe("Validator Blacklist with LiteSVM", () => {
 
     describe("Config Management", () => {
         it("Should have initialized config correctly", async () => {
-            const configAccount = await program.account.config.fetch(configAddress.publicKey);
+            const configAccount = await program.account.config.fetch(configAddress);
             expect(configAccount.admin.toString()).to.equal(configAdmin.publicKey.toString());
+            expect(configAccount.namespace).to.equal("mev-sandwich");
             expect(configAccount.minTvl.toString()).to.equal("1000000000");
             expect(configAccount.allowedPrograms).to.have.length(1);
-            expect(configAccount.allowedPrograms[0].toString()).to.equal(STAKE_POOL_PROGRAM_ID.toString());
+            expect(configAccount.allowedPrograms[0].programId.toString()).to.equal(STAKE_POOL_PROGRAM_ID.toString());
+            expect(configAccount.allowedPrograms[0].minTvl).to.be.null;
+            expect(configAccount.allowedPrograms[0].adapter).to.deep.equal({ splStakePool: {} });
+            expect(configAccount.minAddVotes.toString()).to.equal("1");
+            expect(configAccount.minNetVotes.toString()).to.equal("1");
         });
 
         it("Should allow admin to update config", async () => {
             const updateConfigIx = await program.methods
                 .updateConfig(
                     new BN(2000000000), // 2 SOL minimum TVL
-                    null // Don't update allowed programs
+                    null, // Don't update allowed programs
+                    null,
+                    null,
+                    null,
+                    null
                 )
                 .accounts({
-                    config: configAddress.publicKey,
+                    config: configAddress,
                     admin: configAdmin.publicKey,
                 })
                 .instruction();
@@ -308,23 +360,22 @@ describe("Validator Blacklist with LiteSVM", () => {
             const result = svm.sendTransaction(tx);
             expectSuccessfulTransaction(result);
 
-            const configAccount = await program.account.config.fetch(configAddress.publicKey);
+            const configAccount = await program.account.config.fetch(configAddress);
             expect(configAccount.minTvl.toString()).to.equal("2000000000");
         });
 
-        it("Should allow admin to update admin", async () => {
-            const newAdmin = Keypair.generate();
-            svm.airdrop(newAdmin.publicKey, BigInt(LAMPORTS_PER_SOL));
+        it("Should allow admin to resize the config", async () => {
+            const sizeBefore = svm.getAccount(configAddress).data.length;
 
-            const updateAdminIx = await program.methods
-                .updateConfigAdmin(newAdmin.publicKey)
+            const resizeConfigIx = await program.methods
+                .resizeConfig(20)
                 .accounts({
-                    config: configAddress.publicKey,
+                    config: configAddress,
                     admin: configAdmin.publicKey,
                 })
                 .instruction();
 
-            const tx = new Transaction().add(updateAdminIx);
+            const tx = new Transaction().add(resizeConfigIx);
             tx.feePayer = configAdmin.publicKey;
             tx.recentBlockhash = svm.latestBlockhash();
             tx.sign(configAdmin);
@@ -332,8 +383,199 @@ describe("Validator Blacklist with LiteSVM", () => {
             const result = svm.sendTransaction(tx);
             expectSuccessfulTransaction(result);
 
-            const configAccount = await program.account.config.fetch(configAddress.publicKey);
+            // Room for 10 more allowed programs of 42 bytes each
+            expect(svm.getAccount(configAddress).data.length).to.equal(sizeBefore + 10 * 42);
+
+            const configAccount = await program.account.config.fetch(configAddress);
+            expect(configAccount.version).to.equal(1);
+        });
+
+        it("Should migrate a config created before layout versions", async () => {
+            const legacyConfig = Keypair.generate().publicKey;
+            const le64 = (value: number) => {
+                const buffer = Buffer.alloc(8);
+                buffer.writeBigUInt64LE(BigInt(value));
+                return buffer;
+            };
+            const le32 = (value: number) => {
+                const buffer = Buffer.alloc(4);
+                buffer.writeUInt32LE(value);
+                return buffer;
+            };
+
+            // Legacy layout of a keypair config: admin, min_tvl and room for 10 allowed program ids
+            const legacyData = Buffer.concat([
+                svm.getAccount(configAddress).data.subarray(0, 8),
+                configAdmin.publicKey.toBuffer(),
+                le64(1_000_000_000),
+                le32(1),
+                STAKE_POOL_PROGRAM_ID.toBuffer(),
+            ]);
+            const legacyLength = 8 + 32 + 8 + 4 + 32 * 10;
+            svm.setAccount(legacyConfig, {
+                lamports: Number(svm.minimumBalanceForRentExemption(BigInt(legacyLength))),
+                data: Buffer.concat([legacyData, Buffer.alloc(legacyLength - legacyData.length)]),
+                owner: programId,
+                executable: false,
+            });
+
+            const sendAdminIx = (ix: web3.TransactionInstruction) => {
+                const tx = new Transaction().add(ix);
+                tx.feePayer = configAdmin.publicKey;
+                tx.recentBlockhash = svm.latestBlockhash();
+                tx.sign(configAdmin);
+                return svm.sendTransaction(tx);
+            };
+
+            const setPausedIx = await program.methods
+                .setPaused(true)
+                .accounts({
+                    config: legacyConfig,
+                    admin: configAdmin.publicKey,
+                })
+                .instruction();
+            const pausedResult = sendAdminIx(setPausedIx);
+            expect(pausedResult).to.be.instanceOf(FailedTransactionMetadata);
+            expectInstructionErrorCustomCode(pausedResult as FailedTransactionMetadata, 3003); // AccountDidNotDeserialize error code
+
+            const shrinkIx = await program.methods
+                .resizeConfig(0)
+                .accounts({
+                    config: legacyConfig,
+                    admin: configAdmin.publicKey,
+                })
+                .instruction();
+            const shrinkResult = sendAdminIx(shrinkIx);
+            expect(shrinkResult).to.be.instanceOf(FailedTransactionMetadata);
+            expectInstructionErrorCustomCode(shrinkResult as FailedTransactionMetadata, 6014); // InvalidConfigCapacity error code
+            expect(svm.getAccount(legacyConfig).data.length).to.equal(legacyLength);
+
+            const migrateIx = await program.methods
+                .resizeConfig(10)
+                .accounts({
+                    config: legacyConfig,
+                    admin: configAdmin.publicKey,
+                })
+                .instruction();
+            expectSuccessfulTransaction(sendAdminIx(migrateIx));
+
+            // The current layout with room for 10 allowed programs
+            expect(svm.getAccount(legacyConfig).data.length).to.equal(svm.getAccount(configAddress).data.length - 10 * 42);
+
+            const migrated = await program.account.config.fetch(legacyConfig);
+            expect(migrated.version).to.equal(1);
+            expect(migrated.admin.toString()).to.equal(configAdmin.publicKey.toString());
+            expect(migrated.minTvl.toString()).to.equal("1000000000");
+            expect(migrated.allowedPrograms).to.have.length(1);
+            expect(migrated.allowedPrograms[0].programId.toString()).to.equal(STAKE_POOL_PROGRAM_ID.toString());
+            expect(migrated.allowedPrograms[0].minTvl).to.be.null;
+            expect(migrated.allowedPrograms[0].adapter).to.deep.equal({splStakePool: {}});
+            expect(migrated.minAddVotes.toString()).to.equal("1");
+            expect(migrated.minNetVotes.toString()).to.equal("1");
+            expect(migrated.pendingAdmin).to.be.null;
+            expect(migrated.namespace).to.equal("");
+            expect(migrated.bump).to.equal(0);
+            expect(migrated.paused).to.equal(false);
+            expect(migrated.voteLifetime.toString()).to.equal("0");
+            expect(migrated.authorityPolicy).to.deep.equal({managerOnly: {}});
+
+            svm.expireBlockhash();
+            expectSuccessfulTransaction(sendAdminIx(setPausedIx));
+        });
+
+        it("Should only accept a pending admin from the proposed key", async () => {
+            const proposedAdmin = Keypair.generate();
+
+            const proposeAdminIx = await program.methods
+                .proposeAdmin(proposedAdmin.publicKey)
+                .accounts({
+                    config: configAddress,
+                    admin: configAdmin.publicKey,
+                })
+                .instruction();
+
+            const proposeTx = new Transaction().add(proposeAdminIx);
+            proposeTx.feePayer = configAdmin.publicKey;
+            proposeTx.recentBlockhash = svm.latestBlockhash();
+            proposeTx.sign(configAdmin);
+            expectSuccessfulTransaction(svm.sendTransaction(proposeTx));
+
+            const acceptAdminIx = await program.methods
+                .acceptAdmin()
+                .accounts({
+                    config: configAddress,
+                    pendingAdmin: unauthorizedUser.publicKey,
+                })
+                .instruction();
+
+            const acceptTx = new Transaction().add(acceptAdminIx);
+            acceptTx.feePayer = unauthorizedUser.publicKey;
+            acceptTx.recentBlockhash = svm.latestBlockhash();
+            acceptTx.sign(unauthorizedUser);
+
+            const acceptResult = svm.sendTransaction(acceptTx);
+            expect(acceptResult).to.be.instanceOf(FailedTransactionMetadata);
+            expectInstructionErrorCustomCode(acceptResult as FailedTransactionMetadata, 6011); // InvalidPendingAdmin error code
+
+            const cancelIx = await program.methods
+                .cancelAdminProposal()
+                .accounts({
+                    config: configAddress,
+                    admin: configAdmin.publicKey,
+                })
+                .instruction();
+
+            const cancelTx = new Transaction().add(cancelIx);
+            cancelTx.feePayer = configAdmin.publicKey;
+            cancelTx.recentBlockhash = svm.latestBlockhash();
+            cancelTx.sign(configAdmin);
+            expectSuccessfulTransaction(svm.sendTransaction(cancelTx));
+
+            const configAccount = await program.account.config.fetch(configAddress);
+            expect(configAccount.admin.toString()).to.equal(configAdmin.publicKey.toString());
+            expect(configAccount.pendingAdmin).to.be.null;
+        });
+
+        it("Should allow admin to hand over to a new admin", async () => {
+            const newAdmin = Keypair.generate();
+            svm.airdrop(newAdmin.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+            const proposeAdminIx = await program.methods
+                .proposeAdmin(newAdmin.publicKey)
+                .accounts({
+                    config: configAddress,
+                    admin: configAdmin.publicKey,
+                })
+                .instruction();
+
+            const proposeTx = new Transaction().add(proposeAdminIx);
+            proposeTx.feePayer = configAdmin.publicKey;
+            proposeTx.recentBlockhash = svm.latestBlockhash();
+            proposeTx.sign(configAdmin);
+            expectSuccessfulTransaction(svm.sendTransaction(proposeTx));
+
+            // The admin does not change until the proposal is accepted
+            let configAccount = await program.account.config.fetch(configAddress);
+            expect(configAccount.admin.toString()).to.equal(configAdmin.publicKey.toString());
+            expect(configAccount.pendingAdmin.toString()).to.equal(newAdmin.publicKey.toString());
+
+            const acceptAdminIx = await program.methods
+                .acceptAdmin()
+                .accounts({
+                    config: configAddress,
+                    pendingAdmin: newAdmin.publicKey,
+                })
+                .instruction();
+
+            const acceptTx = new Transaction().add(acceptAdminIx);
+            acceptTx.feePayer = newAdmin.publicKey;
+            acceptTx.recentBlockhash = svm.latestBlockhash();
+            acceptTx.sign(newAdmin);
+            expectSuccessfulTransaction(svm.sendTransaction(acceptTx));
+
+            configAccount = await program.account.config.fetch(configAddress);
             expect(configAccount.admin.toString()).to.equal(newAdmin.publicKey.toString());
+            expect(configAccount.pendingAdmin).to.be.null;
 
             // Update configAdmin for subsequent tests
             configAdmin = newAdmin;
@@ -356,8 +598,9 @@ describe("Validator Blacklist with LiteSVM", () => {
             const [calculatedPda, bump] = PublicKey.findProgramAddressSync(
                 [
                     Buffer.from("delegation"),
-                    configAddress.publicKey.toBuffer(),
-                    stakePoolAddress1.toBuffer()
+                    configAddress.toBuffer(),
+                    stakePoolAddress1.toBuffer(),
+                    Buffer.from([0])
                 ],
                 programId
             );
@@ -374,7 +617,7 @@ describe("Validator Blacklist with LiteSVM", () => {
             const [calculatedPda, bump] = PublicKey.findProgramAddressSync(
                 [
                     Buffer.from("blacklist"),
-                    configAddress.publicKey.toBuffer(),
+                    configAddress.toBuffer(),
                     validatorToBlacklist.toBuffer()
                 ],
                 programId
@@ -391,12 +634,12 @@ describe("Validator Blacklist with LiteSVM", () => {
             const validator2 = Keypair.generate().publicKey;
 
             const [blacklist1Pda] = PublicKey.findProgramAddressSync(
-                [Buffer.from("blacklist"), configAddress.publicKey.toBuffer(), validatorToBlacklist.toBuffer()],
+                [Buffer.from("blacklist"), configAddress.toBuffer(), validatorToBlacklist.toBuffer()],
                 programId
             );
 
             const [blacklist2Pda] = PublicKey.findProgramAddressSync(
-                [Buffer.from("blacklist"), configAddress.publicKey.toBuffer(), validator2.toBuffer()],
+                [Buffer.from("blacklist"), configAddress.toBuffer(), validator2.toBuffer()],
                 programId
             );
 
@@ -409,12 +652,13 @@ describe("Validator Blacklist with LiteSVM", () => {
             it("Should successfully create a delegation", async () => {
 
                 const delegateIx = await program.methods
-                    .delegate()
+                    .delegate(0, ALL_PERMISSIONS, null)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress1,
                         delegation: delegationPda,
                         manager: stakePoolManager.publicKey,
+                        previousManager: null,
                         delegate: delegateAuthority.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
@@ -444,19 +688,21 @@ describe("Validator Blacklist with LiteSVM", () => {
                 const [wrongDelegationPda] = PublicKey.findProgramAddressSync(
                     [
                         Buffer.from("delegation"),
-                        configAddress.publicKey.toBuffer(),
-                        stakePoolAddress2.toBuffer()
+                        configAddress.toBuffer(),
+                        stakePoolAddress2.toBuffer(),
+                        Buffer.from([0])
                     ],
                     programId
                 );
 
                 const delegateIx = await program.methods
-                    .delegate()
+                    .delegate(0, ALL_PERMISSIONS, null)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress2,
                         delegation: wrongDelegationPda,
                         manager: wrongManager.publicKey,
+                        previousManager: null,
                         delegate: delegateAuthority.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
@@ -479,7 +725,7 @@ describe("Validator Blacklist with LiteSVM", () => {
                 const [unauthorizedVoteAddPda] = PublicKey.findProgramAddressSync(
                     [
                         Buffer.from("vote_add"),
-                        configAddress.publicKey.toBuffer(),
+                        configAddress.toBuffer(),
                         stakePoolAddress2.toBuffer(),
                         validatorToBlacklist.toBuffer()
                     ],
@@ -487,12 +733,13 @@ describe("Validator Blacklist with LiteSVM", () => {
                 );
 
                 const voteAddIx = await program.methods
-                    .voteAdd(validatorToBlacklist, "Unauthorized vote")
+                    .voteAdd(validatorToBlacklist, "Unauthorized vote", { other: {} }, null)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress2,
                         blacklist: blacklistPda,
                         voteAdd: unauthorizedVoteAddPda,
+                        validatorVoteAccount: null,
                         delegation: null,
                         authority: unauthorizedUser.publicKey,
                         systemProgram: SystemProgram.programId,
@@ -512,14 +759,16 @@ describe("Validator Blacklist with LiteSVM", () => {
             });
             it("Should successfully vote to add validator to blacklist", async () => {
                 const reason = "Malicious behavior detected";
+                const evidence = { hash: Array.from(Buffer.alloc(32, 7)), uri: "ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cJ3hmCtbDb6JU8" };
 
                 const voteAddIx = await program.methods
-                    .voteAdd(validatorToBlacklist, reason)
+                    .voteAdd(validatorToBlacklist, reason, { sandwiching: {} }, evidence)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress1,
                         blacklist: blacklistPda,
                         voteAdd: voteAddPda,
+                        validatorVoteAccount: null,
                         delegation: null,
                         authority: stakePoolManager.publicKey,
                         systemProgram: SystemProgram.programId,
@@ -535,6 +784,77 @@ describe("Validator Blacklist with LiteSVM", () => {
 
                 expectSuccessfulTransaction(result);
 
+                // Verify the vote is weighted by the stake pool's total lamports
+                const stakePool = StakePoolLayout.decode(Buffer.from(svm.getAccount(stakePoolAddress1)?.data));
+                const voteAddAccount = await program.account.voteAddToBlacklist.fetch(voteAddPda);
+                expect(voteAddAccount.stakeLamports.toString()).to.equal(stakePool.totalLamports.toString());
+
+                // The category and evidence reference are stored and the account is sized to fit them
+                expect(voteAddAccount.category).to.deep.equal({sandwiching: {}});
+                expect(voteAddAccount.evidence.hash).to.deep.equal(evidence.hash);
+                expect(voteAddAccount.evidence.uri).to.equal(evidence.uri);
+                expect(svm.getAccount(voteAddPda).data.length).to.equal(voteAccountSize(reason, evidence.uri));
+
+                const blacklistAccount = await program.account.blacklist.fetch(blacklistPda);
+                expect(blacklistAccount.tallyAdd.toString()).to.equal("1");
+                expect(blacklistAccount.stakeTallyAdd.toString()).to.equal(stakePool.totalLamports.toString());
+                expect(blacklistAccount.status).to.deep.equal({blacklisted: {}});
+            });
+
+            it("Should record the vote account of the validator", async () => {
+                const validatorIdentity = Keypair.generate().publicKey;
+                const validatorVoteAccount = Keypair.generate().publicKey;
+                const otherVoteAccount = Keypair.generate().publicKey;
+                const [identityBlacklistPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), configAddress.toBuffer(), validatorIdentity.toBuffer()],
+                    programId
+                );
+
+                // Vote state: a 4 byte version tag followed by the node_pubkey
+                const setVoteAccount = (address: PublicKey, nodePubkey: PublicKey) => {
+                    const data = Buffer.alloc(3762);
+                    data.writeUInt32LE(2, 0);
+                    nodePubkey.toBuffer().copy(data, 4);
+                    svm.setAccount(address, {
+                        lamports: LAMPORTS_PER_SOL,
+                        data,
+                        owner: VOTE_PROGRAM_ID,
+                        executable: false,
+                    });
+                };
+                setVoteAccount(validatorVoteAccount, validatorIdentity);
+                setVoteAccount(otherVoteAccount, Keypair.generate().publicKey);
+
+                const sendVoteAdd = async (voteAccount: PublicKey) => {
+                    const voteAddIx = await program.methods
+                        .voteAdd(validatorIdentity, "Vote with a vote account", { other: {} }, null)
+                        .accountsPartial({
+                            config: configAddress,
+                            stakePool: stakePoolAddress1,
+                            blacklist: identityBlacklistPda,
+                            validatorVoteAccount: voteAccount,
+                            delegation: null,
+                            authority: stakePoolManager.publicKey,
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(voteAddIx);
+                    tx.feePayer = stakePoolManager.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(stakePoolManager);
+                    return svm.sendTransaction(tx);
+                };
+
+                // The node_pubkey of the vote account has to be the validator identity
+                const mismatchResult = await sendVoteAdd(otherVoteAccount);
+                expect(mismatchResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(mismatchResult as FailedTransactionMetadata, 6034);
+
+                expectSuccessfulTransaction(await sendVoteAdd(validatorVoteAccount));
+
+                const blacklistAccount = await program.account.blacklist.fetch(identityBlacklistPda);
+                expect(blacklistAccount.voteAccount.toString()).to.equal(validatorVoteAccount.toString());
             });
 
         });
@@ -544,10 +864,10 @@ describe("Validator Blacklist with LiteSVM", () => {
                 const reason = "False positive, validator is legitimate";
 
                 const voteRemoveIx = await program.methods
-                    .voteRemove(validatorToBlacklist, reason)
+                    .voteRemove(validatorToBlacklist, reason, { other: {} }, null)
                     .accountsPartial({
-                        config: configAddress.publicKey,
-                        stakePool: stakePoolAddress1,
+                        config: configAddress,
+                        stakePool: stakePoolAddress3,
                         blacklist: blacklistPda,
                         voteRemove: voteRemovePda,
                         delegation: null,
@@ -563,6 +883,238 @@ describe("Validator Blacklist with LiteSVM", () => {
 
                 const result = svm.sendTransaction(tx);
                 expectSuccessfulTransaction(result);
+
+                // One add vote and one remove vote no longer meet the margin
+                const blacklistAccount = await program.account.blacklist.fetch(blacklistPda);
+                expect(blacklistAccount.tallyRemove.toString()).to.equal("1");
+                expect(blacklistAccount.status).to.deep.equal({cleared: {}});
+
+                // The vote account is sized to fit the reason
+                expect(svm.getAccount(voteRemovePda).data.length).to.equal(voteAccountSize(reason));
+            });
+
+            it("Should resize the vote account when editing the reason", async () => {
+                const sendEditReason = async (reason: string) => {
+                    const editReasonIx = await program.methods
+                        .editReason(validatorToBlacklist, reason)
+                        .accountsPartial({
+                            config: configAddress,
+                            stakePool: stakePoolAddress3,
+                            voteAdd: null,
+                            voteRemove: voteRemovePda,
+                            delegation: null,
+                            authority: stakePoolManager.publicKey,
+                            payer: stakePoolManager.publicKey,
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(editReasonIx);
+                    tx.feePayer = stakePoolManager.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(stakePoolManager);
+                    expectSuccessfulTransaction(svm.sendTransaction(tx));
+                };
+
+                const longReason = "False positive, the sandwiching was traced to a different validator ".repeat(4);
+                await sendEditReason(longReason);
+
+                let voteRemoveAccount = await program.account.voteRemoveFromBlacklist.fetch(voteRemovePda);
+                expect(voteRemoveAccount.reason).to.equal(longReason);
+                expect(svm.getAccount(voteRemovePda).data.length).to.equal(voteAccountSize(longReason));
+
+                const shortReason = "False positive";
+                await sendEditReason(shortReason);
+
+                voteRemoveAccount = await program.account.voteRemoveFromBlacklist.fetch(voteRemovePda);
+                expect(voteRemoveAccount.reason).to.equal(shortReason);
+                expect(svm.getAccount(voteRemovePda).data.length).to.equal(voteAccountSize(shortReason));
+            });
+        });
+
+        describe("Is Blacklisted Instruction", () => {
+            async function queryBlacklistInfo(validator: PublicKey) {
+                const [validatorBlacklistPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), configAddress.toBuffer(), validator.toBuffer()],
+                    programId
+                );
+
+                const isBlacklistedIx = await program.methods
+                    .isBlacklisted(validator)
+                    .accountsPartial({
+                        config: configAddress,
+                        blacklist: validatorBlacklistPda,
+                    })
+                    .instruction();
+
+                const tx = new Transaction().add(isBlacklistedIx);
+                tx.feePayer = unauthorizedUser.publicKey;
+                tx.recentBlockhash = svm.latestBlockhash();
+                tx.sign(unauthorizedUser);
+
+                const result = svm.sendTransaction(tx);
+                expectSuccessfulTransaction(result);
+
+                const returnData = (result as TransactionMetadata).returnData();
+                expect(returnData.programId()).to.deep.equal(programId.toBytes());
+
+                return program.coder.types.decode("blacklistInfo", Buffer.from(returnData.data()));
+            }
+
+            it("Should return the status of a validator with votes", async () => {
+                const info = await queryBlacklistInfo(validatorToBlacklist);
+
+                expect(info.exists).to.be.true;
+                expect(info.status).to.deep.equal({cleared: {}});
+                expect(info.tallyAdd.toString()).to.equal("1");
+                expect(info.tallyRemove.toString()).to.equal("1");
+                expect(info.updatedAt.toNumber()).to.be.at.least(info.createdAt.toNumber());
+            });
+
+            it("Should report a validator without votes as pending", async () => {
+                const info = await queryBlacklistInfo(Keypair.generate().publicKey);
+
+                expect(info.exists).to.be.false;
+                expect(info.voteAccount).to.be.null;
+                expect(info.status).to.deep.equal({pending: {}});
+                expect(info.tallyAdd.toString()).to.equal("0");
+            });
+
+            it("Should report a vetoed validator as overridden until the veto is lifted", async () => {
+                const [vetoPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("veto"), configAddress.toBuffer(), validatorToBlacklist.toBuffer()],
+                    programId
+                );
+
+                const sendAsAdmin = (instruction: TransactionInstruction) => {
+                    const tx = new Transaction().add(instruction);
+                    tx.feePayer = configAdmin.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(configAdmin);
+                    return svm.sendTransaction(tx);
+                };
+
+                const buildVetoIx = (admin: PublicKey) => program.methods
+                    .veto(validatorToBlacklist, "Targeted by colluding pools")
+                    .accountsPartial({
+                        config: configAddress,
+                        blacklist: blacklistPda,
+                        veto: vetoPda,
+                        admin,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction();
+
+                // Only the admin can veto
+                const unauthorizedTx = new Transaction().add(await buildVetoIx(unauthorizedUser.publicKey));
+                unauthorizedTx.feePayer = unauthorizedUser.publicKey;
+                unauthorizedTx.recentBlockhash = svm.latestBlockhash();
+                unauthorizedTx.sign(unauthorizedUser);
+                const unauthorizedResult = svm.sendTransaction(unauthorizedTx);
+                expect(unauthorizedResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(unauthorizedResult as FailedTransactionMetadata, 6009); // UnauthorizedAdmin error code
+
+                expectSuccessfulTransaction(sendAsAdmin(await buildVetoIx(configAdmin.publicKey)));
+
+                const veto = await program.account.veto.fetch(vetoPda);
+                expect(veto.reason).to.equal("Targeted by colluding pools");
+                expect(veto.admin.toString()).to.equal(configAdmin.publicKey.toString());
+
+                const blacklistAccount = await program.account.blacklist.fetch(blacklistPda);
+                expect(blacklistAccount.vetoed).to.be.true;
+                expect(blacklistAccount.status).to.deep.equal({overridden: {}});
+
+                svm.expireBlockhash();
+                const vetoedInfo = await queryBlacklistInfo(validatorToBlacklist);
+                expect(vetoedInfo.status).to.deep.equal({overridden: {}});
+
+                const liftVetoIx = await program.methods
+                    .liftVeto(validatorToBlacklist)
+                    .accountsPartial({
+                        config: configAddress,
+                        blacklist: blacklistPda,
+                        veto: vetoPda,
+                        vetoAdmin: configAdmin.publicKey,
+                        admin: configAdmin.publicKey,
+                    })
+                    .instruction();
+                expectSuccessfulTransaction(sendAsAdmin(liftVetoIx));
+                expect(svm.getAccount(vetoPda).lamports).to.equal(0);
+
+                svm.expireBlockhash();
+                const liftedInfo = await queryBlacklistInfo(validatorToBlacklist);
+                expect(liftedInfo.status).to.deep.equal({cleared: {}});
+            });
+        });
+
+        describe("Change Vote Instruction", () => {
+            it("Should flip a vote instead of holding both at once", async () => {
+                const [poolVoteRemovePda] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("vote_remove"),
+                        configAddress.toBuffer(),
+                        stakePoolAddress1.toBuffer(),
+                        validatorToBlacklist.toBuffer()
+                    ],
+                    programId
+                );
+
+                const sendAsManager = (ix: TransactionInstruction) => {
+                    const tx = new Transaction().add(ix);
+                    tx.feePayer = stakePoolManager.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(stakePoolManager);
+                    return svm.sendTransaction(tx);
+                };
+
+                const changeVoteIx = (direction: any, reason: string) => program.methods
+                    .changeVote(validatorToBlacklist, direction, reason, { other: {} }, null)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        blacklist: blacklistPda,
+                        voteAdd: voteAddPda,
+                        voteRemove: poolVoteRemovePda,
+                        delegation: null,
+                        authority: stakePoolManager.publicKey,
+                        payer: stakePoolManager.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction();
+
+                // The pool already voted to add, so it cannot also vote to remove
+                const conflictingResult = sendAsManager(await program.methods
+                    .voteRemove(validatorToBlacklist, "Changed my mind", { other: {} }, null)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        blacklist: blacklistPda,
+                        voteRemove: poolVoteRemovePda,
+                        voteAdd: voteAddPda,
+                        delegation: null,
+                        authority: stakePoolManager.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction());
+                expect(conflictingResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(conflictingResult as FailedTransactionMetadata, 6031);
+
+                expectSuccessfulTransaction(sendAsManager(await changeVoteIx({ remove: {} }, "Changed my mind")));
+                expect(svm.getAccount(voteAddPda).lamports).to.equal(0);
+
+                let blacklistAccount = await program.account.blacklist.fetch(blacklistPda);
+                expect(blacklistAccount.tallyAdd.toString()).to.equal("0");
+                expect(blacklistAccount.tallyRemove.toString()).to.equal("2");
+                const voteRemoveAccount = await program.account.voteRemoveFromBlacklist.fetch(poolVoteRemovePda);
+                expect(voteRemoveAccount.reason).to.equal("Changed my mind");
+
+                // And back again, which restores the add vote for the tests below
+                expectSuccessfulTransaction(sendAsManager(await changeVoteIx({ add: {} }, "Malicious behavior detected")));
+                expect(svm.getAccount(poolVoteRemovePda).lamports).to.equal(0);
+
+                blacklistAccount = await program.account.blacklist.fetch(blacklistPda);
+                expect(blacklistAccount.tallyAdd.toString()).to.equal("1");
+                expect(blacklistAccount.tallyRemove.toString()).to.equal("1");
             });
         });
 
@@ -571,7 +1123,7 @@ describe("Validator Blacklist with LiteSVM", () => {
                 const unvoteAddIx = await program.methods
                     .unvoteAdd(validatorToBlacklist)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress1,
                         blacklist: blacklistPda,
                         voteAdd: voteAddPda,
@@ -595,6 +1147,93 @@ describe("Validator Blacklist with LiteSVM", () => {
                 expect(voteAddAccount.owner.toBase58()).to.eq(SystemProgram.programId.toBase58());
 
             });
+
+            it("Should emit the vote and unvote events through self CPI", async () => {
+                const eventValidator = Keypair.generate().publicKey;
+                const reason = "Sandwiching users";
+                const [eventBlacklistPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), configAddress.toBuffer(), eventValidator.toBuffer()],
+                    programId
+                );
+                const [eventVoteAddPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("vote_add"), configAddress.toBuffer(), stakePoolAddress1.toBuffer(), eventValidator.toBuffer()],
+                    programId
+                );
+                const stakePool = StakePoolLayout.decode(Buffer.from(svm.getAccount(stakePoolAddress1)?.data));
+
+                const sendAsManager = (ix: TransactionInstruction) => {
+                    const tx = new Transaction().add(ix);
+                    tx.feePayer = stakePoolManager.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(stakePoolManager);
+                    return svm.sendTransaction(tx);
+                };
+
+                const voteAddIx = await program.methods
+                    .voteAdd(eventValidator, reason, { sandwiching: {} }, null)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        blacklist: eventBlacklistPda,
+                        voteAdd: eventVoteAddPda,
+                        validatorVoteAccount: null,
+                        delegation: null,
+                        authority: stakePoolManager.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction();
+                const voteResult = sendAsManager(voteAddIx);
+                expectSuccessfulTransaction(voteResult);
+
+                const voteEvents = decodeCpiEvents(program, voteResult as TransactionMetadata);
+                expect(voteEvents).to.have.length(1);
+                expect(voteEvents[0].name).to.equal("VoteAddEvent");
+                const voteEvent = voteEvents[0].data;
+                expect(voteEvent.config.toString()).to.equal(configAddress.toString());
+                expect(voteEvent.stakePool.toString()).to.equal(stakePoolAddress1.toString());
+                expect(voteEvent.authority.toString()).to.equal(stakePoolManager.publicKey.toString());
+                expect(voteEvent.validatorIdentityAddress.toString()).to.equal(eventValidator.toString());
+                expect(voteEvent.reason).to.equal(reason);
+                expect(voteEvent.category).to.deep.equal({sandwiching: {}});
+                expect(voteEvent.stakeLamports.toString()).to.equal(stakePool.totalLamports.toString());
+                expect(voteEvent.tallyAdd.toString()).to.equal("1");
+                expect(voteEvent.tallyRemove.toString()).to.equal("0");
+                expect(voteEvent.stakeTallyAdd.toString()).to.equal(stakePool.totalLamports.toString());
+                expect(voteEvent.stakeTallyRemove.toString()).to.equal("0");
+                expect(voteEvent.status).to.deep.equal({blacklisted: {}});
+                expect(voteEvent.slot.toString()).to.equal(svm.getClock().slot.toString());
+
+                const unvoteAddIx = await program.methods
+                    .unvoteAdd(eventValidator)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        blacklist: eventBlacklistPda,
+                        voteAdd: eventVoteAddPda,
+                        delegation: null,
+                        authority: stakePoolManager.publicKey,
+                    })
+                    .instruction();
+                const unvoteResult = sendAsManager(unvoteAddIx);
+                expectSuccessfulTransaction(unvoteResult);
+
+                const unvoteEvents = decodeCpiEvents(program, unvoteResult as TransactionMetadata);
+                expect(unvoteEvents).to.have.length(1);
+                expect(unvoteEvents[0].name).to.equal("UnvoteAddEvent");
+                const unvoteEvent = unvoteEvents[0].data;
+                expect(unvoteEvent.config.toString()).to.equal(configAddress.toString());
+                expect(unvoteEvent.stakePool.toString()).to.equal(stakePoolAddress1.toString());
+                expect(unvoteEvent.authority.toString()).to.equal(stakePoolManager.publicKey.toString());
+                expect(unvoteEvent.validatorIdentityAddress.toString()).to.equal(eventValidator.toString());
+                expect(unvoteEvent.reason).to.equal(reason);
+                expect(unvoteEvent.stakeLamports.toString()).to.equal(stakePool.totalLamports.toString());
+                expect(unvoteEvent.tallyAdd.toString()).to.equal("0");
+                expect(unvoteEvent.tallyRemove.toString()).to.equal("0");
+                expect(unvoteEvent.stakeTallyAdd.toString()).to.equal("0");
+                expect(unvoteEvent.stakeTallyRemove.toString()).to.equal("0");
+                expect(unvoteEvent.status).to.deep.equal({pending: {}});
+                expect(unvoteEvent.slot.toString()).to.equal(svm.getClock().slot.toString());
+            });
         });
 
         describe("Delegated Authority Tests", () => {
@@ -605,7 +1244,7 @@ describe("Validator Blacklist with LiteSVM", () => {
                 const [wrongDelegatedVoteAddPda] = PublicKey.findProgramAddressSync(
                     [
                         Buffer.from("vote_add"),
-                        configAddress.publicKey.toBuffer(),
+                        configAddress.toBuffer(),
                         stakePoolAddress1.toBuffer(),
                         validatorToBlacklist.toBuffer()
                     ],
@@ -613,12 +1252,13 @@ describe("Validator Blacklist with LiteSVM", () => {
                 );
 
                 const voteAddIx = await program.methods
-                    .voteAdd(validatorToBlacklist, "Wrong delegate")
+                    .voteAdd(validatorToBlacklist, "Wrong delegate", { other: {} }, null)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress1,
                         blacklist: blacklistPda,
                         voteAdd: wrongDelegatedVoteAddPda,
+                        validatorVoteAccount: null,
                         delegation: delegationPda,
                         authority: wrongDelegate.publicKey,
                         systemProgram: SystemProgram.programId,
@@ -641,7 +1281,7 @@ describe("Validator Blacklist with LiteSVM", () => {
                 const [delegatedVoteAddPda] = PublicKey.findProgramAddressSync(
                     [
                         Buffer.from("vote_add"),
-                        configAddress.publicKey.toBuffer(),
+                        configAddress.toBuffer(),
                         stakePoolAddress1.toBuffer(),
                         validatorToBlacklist.toBuffer()
                     ],
@@ -650,12 +1290,13 @@ describe("Validator Blacklist with LiteSVM", () => {
 
                 const reason = "some valid reason for blacklisting a validator";
                 const voteAddIx = await program.methods
-                    .voteAdd(validatorToBlacklist, reason)
+                    .voteAdd(validatorToBlacklist, reason, { other: {} }, null)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress1,
                         blacklist: blacklistPda,
                         voteAdd: delegatedVoteAddPda,
+                        validatorVoteAccount: null,
                         delegation: delegationPda,
                         authority: delegateAuthority.publicKey,
                         systemProgram: SystemProgram.programId,
@@ -676,15 +1317,278 @@ describe("Validator Blacklist with LiteSVM", () => {
                 expect(voteAddAccount.stakePool.toString()).to.equal(stakePoolAddress1.toString());
             });
 
+            it("Should enforce the permissions of a scoped delegation", async () => {
+                const hotKey = Keypair.generate();
+                svm.airdrop(hotKey.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+                // A second delegation of the same stake pool that may only vote to add
+                const [hotKeyDelegationPda] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("delegation"),
+                        configAddress.toBuffer(),
+                        stakePoolAddress1.toBuffer(),
+                        Buffer.from([1])
+                    ],
+                    programId
+                );
+
+                const delegateIx = await program.methods
+                    .delegate(1, VOTE_ADD_PERMISSION | UNVOTE_ADD_PERMISSION, null)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        delegation: hotKeyDelegationPda,
+                        manager: stakePoolManager.publicKey,
+                        previousManager: null,
+                        delegate: hotKey.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction();
+
+                const delegateTx = new Transaction().add(delegateIx);
+                delegateTx.feePayer = stakePoolManager.publicKey;
+                delegateTx.recentBlockhash = svm.latestBlockhash();
+                delegateTx.sign(stakePoolManager);
+                expectSuccessfulTransaction(svm.sendTransaction(delegateTx));
+
+                const delegationAccount = await program.account.delegation.fetch(hotKeyDelegationPda);
+                expect(delegationAccount.index).to.equal(1);
+                expect(delegationAccount.permissions).to.equal(VOTE_ADD_PERMISSION | UNVOTE_ADD_PERMISSION);
+
+                const scopedValidator = Keypair.generate().publicKey;
+                const [scopedBlacklistPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), configAddress.toBuffer(), scopedValidator.toBuffer()],
+                    programId
+                );
+                const [scopedVoteRemovePda] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("vote_remove"),
+                        configAddress.toBuffer(),
+                        stakePoolAddress1.toBuffer(),
+                        scopedValidator.toBuffer()
+                    ],
+                    programId
+                );
+
+                const voteRemoveIx = await program.methods
+                    .voteRemove(scopedValidator, "Not allowed for the hot key", { other: {} }, null)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        blacklist: scopedBlacklistPda,
+                        voteRemove: scopedVoteRemovePda,
+                        delegation: hotKeyDelegationPda,
+                        authority: hotKey.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction();
+
+                const voteTx = new Transaction().add(voteRemoveIx);
+                voteTx.feePayer = hotKey.publicKey;
+                voteTx.recentBlockhash = svm.latestBlockhash();
+                voteTx.sign(hotKey);
+
+                const result = svm.sendTransaction(voteTx);
+                expect(result).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(result as FailedTransactionMetadata, 6024);
+            });
+
+            it("Should reject and close an expired delegation", async () => {
+                const temporaryDelegate = Keypair.generate();
+                svm.airdrop(temporaryDelegate.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+                const [temporaryDelegationPda] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("delegation"),
+                        configAddress.toBuffer(),
+                        stakePoolAddress1.toBuffer(),
+                        Buffer.from([2])
+                    ],
+                    programId
+                );
+
+                const expiresAt = new BN((svm.getClock().unixTimestamp + BigInt(60)).toString());
+                const delegateIx = await program.methods
+                    .delegate(2, ALL_PERMISSIONS, expiresAt)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        delegation: temporaryDelegationPda,
+                        manager: stakePoolManager.publicKey,
+                        previousManager: null,
+                        delegate: temporaryDelegate.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction();
+
+                const delegateTx = new Transaction().add(delegateIx);
+                delegateTx.feePayer = stakePoolManager.publicKey;
+                delegateTx.recentBlockhash = svm.latestBlockhash();
+                delegateTx.sign(stakePoolManager);
+                expectSuccessfulTransaction(svm.sendTransaction(delegateTx));
+
+                const sendCloseExpiredDelegation = async () => {
+                    const closeIx = await program.methods
+                        .closeExpiredDelegation()
+                        .accounts({
+                            config: configAddress,
+                            delegation: temporaryDelegationPda,
+                            stakePool: stakePoolAddress1,
+                            manager: stakePoolManager.publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(closeIx);
+                    tx.feePayer = unauthorizedUser.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(unauthorizedUser);
+                    return svm.sendTransaction(tx);
+                };
+
+                const earlyResult = await sendCloseExpiredDelegation();
+                expect(earlyResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(earlyResult as FailedTransactionMetadata, 6028);
+
+                const clock = svm.getClock();
+                clock.unixTimestamp = clock.unixTimestamp + BigInt(61);
+                svm.setClock(clock);
+                svm.expireBlockhash();
+
+                const expiredValidator = Keypair.generate().publicKey;
+                const [expiredBlacklistPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), configAddress.toBuffer(), expiredValidator.toBuffer()],
+                    programId
+                );
+                const [expiredVoteAddPda] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("vote_add"),
+                        configAddress.toBuffer(),
+                        stakePoolAddress1.toBuffer(),
+                        expiredValidator.toBuffer()
+                    ],
+                    programId
+                );
+
+                const voteAddIx = await program.methods
+                    .voteAdd(expiredValidator, "Vote with an expired delegation", { other: {} }, null)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        blacklist: expiredBlacklistPda,
+                        voteAdd: expiredVoteAddPda,
+                        validatorVoteAccount: null,
+                        delegation: temporaryDelegationPda,
+                        authority: temporaryDelegate.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction();
+
+                const voteTx = new Transaction().add(voteAddIx);
+                voteTx.feePayer = temporaryDelegate.publicKey;
+                voteTx.recentBlockhash = svm.latestBlockhash();
+                voteTx.sign(temporaryDelegate);
+
+                const voteResult = svm.sendTransaction(voteTx);
+                expect(voteResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(voteResult as FailedTransactionMetadata, 6026);
+
+                expectSuccessfulTransaction(await sendCloseExpiredDelegation());
+                expect(svm.getAccount(temporaryDelegationPda).lamports).to.equal(0);
+            });
+
         });
 
 
+        describe("Update Delegate Instruction", () => {
+            it("Should replace the delegate in place", async () => {
+                const newDelegate = Keypair.generate();
+
+                const updateDelegateIx = await program.methods
+                    .updateDelegate()
+                    .accounts({
+                        config: configAddress,
+                        delegation: delegationPda,
+                        stakePool: stakePoolAddress1,
+                        manager: stakePoolManager.publicKey,
+                        newDelegate: newDelegate.publicKey,
+                    })
+                    .instruction();
+
+                const tx = new Transaction().add(updateDelegateIx);
+                tx.feePayer = stakePoolManager.publicKey;
+                tx.recentBlockhash = svm.latestBlockhash();
+                tx.sign(stakePoolManager);
+                expectSuccessfulTransaction(svm.sendTransaction(tx));
+
+                const delegationAccount = await program.account.delegation.fetch(delegationPda);
+                expect(delegationAccount.delegate.toString()).to.equal(newDelegate.publicKey.toString());
+                expect(delegationAccount.permissions).to.equal(ALL_PERMISSIONS);
+            });
+
+            it("Should only let the current manager update the delegate while not paused", async () => {
+                const sendUpdateDelegate = async () => {
+                    const updateDelegateIx = await program.methods
+                        .updateDelegate()
+                        .accounts({
+                            config: configAddress,
+                            delegation: delegationPda,
+                            stakePool: stakePoolAddress1,
+                            manager: stakePoolManager.publicKey,
+                            newDelegate: Keypair.generate().publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(updateDelegateIx);
+                    tx.feePayer = stakePoolManager.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(stakePoolManager);
+                    return svm.sendTransaction(tx);
+                };
+
+                const sendSetPaused = async (paused: boolean) => {
+                    const setPausedIx = await program.methods
+                        .setPaused(paused)
+                        .accounts({
+                            config: configAddress,
+                            admin: configAdmin.publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(setPausedIx);
+                    tx.feePayer = configAdmin.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(configAdmin);
+                    expectSuccessfulTransaction(svm.sendTransaction(tx));
+                };
+
+                await sendSetPaused(true);
+                const pausedResult = await sendUpdateDelegate();
+                expect(pausedResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(pausedResult as FailedTransactionMetadata, 6015); // VotingPaused error code
+                await sendSetPaused(false);
+
+                // After a manager change, the previous manager no longer controls the delegation
+                const account = svm.getAccount(stakePoolAddress1);
+                const data = Buffer.from(account.data);
+                const stakePool = StakePoolLayout.decode(data);
+                stakePool.manager = Keypair.generate().publicKey;
+                StakePoolLayout.encode(stakePool, data);
+                svm.setAccount(stakePoolAddress1, {...account, data});
+
+                const staleResult = await sendUpdateDelegate();
+                expect(staleResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(staleResult as FailedTransactionMetadata, 6000); // InvalidManager error code
+
+                svm.setAccount(stakePoolAddress1, account);
+            });
+        });
+
         describe("Undelegate Instruction", () => {
             it("Should successfully remove delegation", async () => {
                 const undelegateIx = await program.methods
                     .undelegate()
                     .accounts({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress1,
                         delegation: delegationPda,
                         manager: stakePoolManager.publicKey,
@@ -701,20 +1605,112 @@ describe("Validator Blacklist with LiteSVM", () => {
 
                 console.log("Undelegate instruction properly structured");
             });
+
+            it("Should let a new manager overwrite and anyone close a stale delegation", async () => {
+                const newManager = Keypair.generate();
+                svm.airdrop(newManager.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+                const setStakePoolManager = (manager: PublicKey) => {
+                    const account = svm.getAccount(stakePoolAddress1);
+                    const data = Buffer.from(account.data);
+                    const stakePool = StakePoolLayout.decode(data);
+                    stakePool.manager = manager;
+                    StakePoolLayout.encode(stakePool, data);
+                    svm.setAccount(stakePoolAddress1, {...account, data});
+                };
+
+                const sendDelegate = async (manager: Keypair, previousManager: PublicKey | null) => {
+                    const delegateIx = await program.methods
+                        .delegate(0, ALL_PERMISSIONS, null)
+                        .accountsPartial({
+                            config: configAddress,
+                            stakePool: stakePoolAddress1,
+                            delegation: delegationPda,
+                            manager: manager.publicKey,
+                            previousManager,
+                            delegate: delegateAuthority.publicKey,
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(delegateIx);
+                    tx.feePayer = manager.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(manager);
+                    return svm.sendTransaction(tx);
+                };
+
+                const sendCloseStaleDelegation = async (manager: PublicKey) => {
+                    const closeIx = await program.methods
+                        .closeStaleDelegation()
+                        .accounts({
+                            config: configAddress,
+                            delegation: delegationPda,
+                            stakePool: stakePoolAddress1,
+                            manager,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(closeIx);
+                    tx.feePayer = unauthorizedUser.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(unauthorizedUser);
+                    return svm.sendTransaction(tx);
+                };
+
+                expectSuccessfulTransaction(await sendDelegate(stakePoolManager, null));
+
+                // The delegation of the current manager can neither be overwritten nor closed
+                svm.expireBlockhash();
+                const duplicateResult = await sendDelegate(stakePoolManager, stakePoolManager.publicKey);
+                expect(duplicateResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(duplicateResult as FailedTransactionMetadata, 6021);
+
+                const notStaleResult = await sendCloseStaleDelegation(stakePoolManager.publicKey);
+                expect(notStaleResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(notStaleResult as FailedTransactionMetadata, 6022);
+
+                // Once the manager rotates, the new manager can overwrite the delegation, but only by
+                // refunding its rent to the previous manager
+                setStakePoolManager(newManager.publicKey);
+                const missingPreviousManagerResult = await sendDelegate(newManager, null);
+                expect(missingPreviousManagerResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(missingPreviousManagerResult as FailedTransactionMetadata, 6040);
+
+                const wrongPreviousManagerResult = await sendDelegate(newManager, unauthorizedUser.publicKey);
+                expect(wrongPreviousManagerResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(wrongPreviousManagerResult as FailedTransactionMetadata, 6040);
+
+                const previousManagerBalance = svm.getBalance(stakePoolManager.publicKey);
+                const delegationRent = svm.getAccount(delegationPda).lamports;
+                svm.expireBlockhash();
+                expectSuccessfulTransaction(await sendDelegate(newManager, stakePoolManager.publicKey));
+                expect(svm.getBalance(stakePoolManager.publicKey)).to.equal(previousManagerBalance + BigInt(delegationRent));
+
+                let delegationAccount = await program.account.delegation.fetch(delegationPda);
+                expect(delegationAccount.manager.toString()).to.equal(newManager.publicKey.toString());
+
+                // Rotating back makes the delegation stale again, so anyone can close it
+                setStakePoolManager(stakePoolManager.publicKey);
+                svm.expireBlockhash();
+                expectSuccessfulTransaction(await sendCloseStaleDelegation(newManager.publicKey));
+
+                expect(svm.getAccount(delegationPda).lamports).to.equal(0);
+            });
         });
 
         describe("Edge Cases and Error Conditions", () => {
             it("Should handle voting on multiple validators", async () => {
                 const validator2 = Keypair.generate().publicKey;
                 const [blacklist2Pda] = PublicKey.findProgramAddressSync(
-                    [Buffer.from("blacklist"), configAddress.publicKey.toBuffer(), validator2.toBuffer()],
+                    [Buffer.from("blacklist"), configAddress.toBuffer(), validator2.toBuffer()],
                     programId
                 );
 
                 const [vote2AddPda] = PublicKey.findProgramAddressSync(
                     [
                         Buffer.from("vote_add"),
-                        configAddress.publicKey.toBuffer(),
+                        configAddress.toBuffer(),
                         stakePoolAddress1.toBuffer(),
                         validator2.toBuffer()
                     ],
@@ -722,12 +1718,13 @@ describe("Validator Blacklist with LiteSVM", () => {
                 );
 
                 const voteAddIx = await program.methods
-                    .voteAdd(validator2, "Second validator")
+                    .voteAdd(validator2, "Second validator", { other: {} }, null)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress1,
                         blacklist: blacklist2Pda,
                         voteAdd: vote2AddPda,
+                        validatorVoteAccount: null,
                         delegation: null,
                         authority: stakePoolManager.publicKey,
                         systemProgram: SystemProgram.programId,
@@ -743,16 +1740,208 @@ describe("Validator Blacklist with LiteSVM", () => {
                 expectSuccessfulTransaction(result);
             });
 
+            it("Should let the staker vote according to the authority policy", async () => {
+                const staker = Keypair.generate();
+                svm.airdrop(staker.publicKey, BigInt(LAMPORTS_PER_SOL));
+
+                const account = svm.getAccount(stakePoolAddress2);
+                const data = Buffer.from(account.data);
+                const stakePool = StakePoolLayout.decode(data);
+                stakePool.staker = staker.publicKey;
+                StakePoolLayout.encode(stakePool, data);
+                svm.setAccount(stakePoolAddress2, {...account, data});
+
+                const policyValidator = Keypair.generate().publicKey;
+                const [policyBlacklistPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), configAddress.toBuffer(), policyValidator.toBuffer()],
+                    programId
+                );
+                const [policyVoteAddPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("vote_add"), configAddress.toBuffer(), stakePoolAddress2.toBuffer(), policyValidator.toBuffer()],
+                    programId
+                );
+
+                const sendAuthorityPolicy = async (authorityPolicy: any) => {
+                    const updateConfigIx = await program.methods
+                        .updateConfig(null, null, null, null, null, authorityPolicy)
+                        .accounts({
+                            config: configAddress,
+                            admin: configAdmin.publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(updateConfigIx);
+                    tx.feePayer = configAdmin.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(configAdmin);
+                    expectSuccessfulTransaction(svm.sendTransaction(tx));
+                };
+
+                const sendVoteAdd = async (authority: Keypair) => {
+                    const voteAddIx = await program.methods
+                        .voteAdd(policyValidator, "Voted by the staker", { other: {} }, null)
+                        .accountsPartial({
+                            config: configAddress,
+                            stakePool: stakePoolAddress2,
+                            blacklist: policyBlacklistPda,
+                            voteAdd: policyVoteAddPda,
+                            validatorVoteAccount: null,
+                            delegation: null,
+                            authority: authority.publicKey,
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(voteAddIx);
+                    tx.feePayer = authority.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(authority);
+                    return svm.sendTransaction(tx);
+                };
+
+                const sendUnvoteAdd = async (authority: Keypair) => {
+                    const unvoteAddIx = await program.methods
+                        .unvoteAdd(policyValidator)
+                        .accountsPartial({
+                            config: configAddress,
+                            stakePool: stakePoolAddress2,
+                            blacklist: policyBlacklistPda,
+                            voteAdd: policyVoteAddPda,
+                            delegation: null,
+                            authority: authority.publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(unvoteAddIx);
+                    tx.feePayer = authority.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(authority);
+                    return svm.sendTransaction(tx);
+                };
+
+                // By default only the manager may vote
+                const managerOnlyResult = await sendVoteAdd(staker);
+                expect(managerOnlyResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(managerOnlyResult as FailedTransactionMetadata, 6000);
+
+                await sendAuthorityPolicy({ stakerOnly: {} });
+                const configAccount = await program.account.config.fetch(configAddress);
+                expect(configAccount.authorityPolicy).to.deep.equal({ stakerOnly: {} });
+
+                expectSuccessfulTransaction(await sendVoteAdd(staker));
+
+                // The manager is locked out while the policy is staker only
+                const stakerOnlyResult = await sendUnvoteAdd(stakePoolManager);
+                expect(stakerOnlyResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(stakerOnlyResult as FailedTransactionMetadata, 6035);
+
+                await sendAuthorityPolicy({ either: {} });
+
+                // The rent goes back to the staker who paid for the vote, not to the manager closing it
+                const stakerBalance = svm.getBalance(staker.publicKey);
+                const voteRent = svm.getAccount(policyVoteAddPda).lamports;
+                expectSuccessfulTransaction(await sendUnvoteAdd(stakePoolManager));
+                expect(svm.getBalance(staker.publicKey)).to.equal(stakerBalance + BigInt(voteRent));
+
+                await sendAuthorityPolicy({ managerOnly: {} });
+            });
+
+            it("Should vote on several validators in one instruction", async () => {
+                const validators = [Keypair.generate().publicKey, Keypair.generate().publicKey, Keypair.generate().publicKey];
+                const pda = (seed: string, validator: PublicKey, stakePool?: PublicKey) => PublicKey.findProgramAddressSync(
+                    stakePool
+                        ? [Buffer.from(seed), configAddress.toBuffer(), stakePool.toBuffer(), validator.toBuffer()]
+                        : [Buffer.from(seed), configAddress.toBuffer(), validator.toBuffer()],
+                    programId
+                )[0];
+
+                // The first validator comes with its vote account, passed after the PDAs of its vote
+                const batchVoteAccount = Keypair.generate().publicKey;
+                const voteData = Buffer.alloc(3762);
+                voteData.writeUInt32LE(2, 0);
+                validators[0].toBuffer().copy(voteData, 4);
+                svm.setAccount(batchVoteAccount, {
+                    lamports: LAMPORTS_PER_SOL,
+                    data: voteData,
+                    owner: VOTE_PROGRAM_ID,
+                    executable: false,
+                });
+
+                const remainingAccounts = validators.flatMap((validator, i) => [
+                    {pubkey: pda("blacklist", validator), isSigner: false, isWritable: true},
+                    {pubkey: pda("vote_add", validator, stakePoolAddress1), isSigner: false, isWritable: true},
+                    {pubkey: pda("vote_remove", validator, stakePoolAddress1), isSigner: false, isWritable: false},
+                    ...(i === 0 ? [{pubkey: batchVoteAccount, isSigner: false, isWritable: false}] : []),
+                ]);
+
+                const sendVoteAddMany = async (accounts: any[]) => {
+                    const voteAddManyIx = await program.methods
+                        .voteAddMany(validators.map((validator, i) => ({
+                            validatorIdentityAddress: validator,
+                            reason: `Batch vote ${i}`,
+                            category: { sandwiching: {} },
+                            evidence: null,
+                            voteAccount: i === 0 ? batchVoteAccount : null,
+                        })))
+                        .accountsPartial({
+                            config: configAddress,
+                            stakePool: stakePoolAddress1,
+                            delegation: null,
+                            authority: stakePoolManager.publicKey,
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .remainingAccounts(accounts)
+                        .instruction();
+
+                    const tx = new Transaction().add(voteAddManyIx);
+                    tx.feePayer = stakePoolManager.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(stakePoolManager);
+                    return svm.sendTransaction(tx);
+                };
+
+                // The accounts of every vote have to be passed
+                const missingResult = await sendVoteAddMany(remainingAccounts.slice(0, 6));
+                expect(missingResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(missingResult as FailedTransactionMetadata, 6033);
+
+                // Every vote needs its own PDAs, here the blacklist entries of the last two are swapped
+                const swappedAccounts = [...remainingAccounts];
+                [swappedAccounts[4], swappedAccounts[7]] = [remainingAccounts[7], remainingAccounts[4]];
+                const swappedResult = await sendVoteAddMany(swappedAccounts);
+                expect(swappedResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(swappedResult as FailedTransactionMetadata, 6033);
+
+                expectSuccessfulTransaction(await sendVoteAddMany(remainingAccounts));
+
+                for (const [i, validator] of validators.entries()) {
+                    const voteAddAccount = await program.account.voteAddToBlacklist.fetch(pda("vote_add", validator, stakePoolAddress1));
+                    expect(voteAddAccount.reason).to.equal(`Batch vote ${i}`);
+
+                    const blacklistAccount = await program.account.blacklist.fetch(pda("blacklist", validator));
+                    expect(blacklistAccount.tallyAdd.toString()).to.equal("1");
+                    expect(blacklistAccount.status).to.deep.equal({blacklisted: {}});
+                    expect(blacklistAccount.voteAccount?.toString() ?? null).to.equal(i === 0 ? batchVoteAccount.toString() : null);
+                }
+
+                // Voting on the same validators again is rejected
+                svm.expireBlockhash();
+                const duplicateResult = await sendVoteAddMany(remainingAccounts);
+                expect(duplicateResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(duplicateResult as FailedTransactionMetadata, 6032);
+            });
+
             it("Should handle transaction with invalid PDA", async () => {
                 const invalidPda = Keypair.generate().publicKey;
 
                 const voteAddIx = await program.methods
-                    .voteAdd(validatorToBlacklist, "Invalid PDA test")
+                    .voteAdd(validatorToBlacklist, "Invalid PDA test", { other: {} }, null)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress1,
                         blacklist: invalidPda, // Invalid PDA
                         voteAdd: voteAddPda,
+                        validatorVoteAccount: null,
                         delegation: null,
                         authority: stakePoolManager.publicKey,
                         systemProgram: SystemProgram.programId,
@@ -775,10 +1964,14 @@ describe("Validator Blacklist with LiteSVM", () => {
                 const updateConfigIx = await program.methods
                     .updateConfig(
                         null,
-                        [] // Empty allowed programs list
+                        [], // Empty allowed programs list
+                        null,
+                        null,
+                        null,
+                        null
                     )
                     .accounts({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         admin: configAdmin.publicKey,
                     })
                     .instruction();
@@ -795,7 +1988,7 @@ describe("Validator Blacklist with LiteSVM", () => {
                 const [unauthorizedProgramVoteAddPda] = PublicKey.findProgramAddressSync(
                     [
                         Buffer.from("vote_add"),
-                        configAddress.publicKey.toBuffer(),
+                        configAddress.toBuffer(),
                         stakePoolBadProgram.toBuffer(),
                         validatorToBlacklist.toBuffer()
                     ],
@@ -803,12 +1996,13 @@ describe("Validator Blacklist with LiteSVM", () => {
                 );
 
                 const voteAddIx = await program.methods
-                    .voteAdd(validatorToBlacklist, "Should fail due to unauthorized program")
+                    .voteAdd(validatorToBlacklist, "Should fail due to unauthorized program", { other: {} }, null)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolBadProgram,
                         blacklist: blacklistPda,
                         voteAdd: unauthorizedProgramVoteAddPda,
+                        validatorVoteAccount: null,
                         delegation: null,
                         authority: stakePoolManager.publicKey,
                         systemProgram: SystemProgram.programId,
@@ -833,10 +2027,14 @@ describe("Validator Blacklist with LiteSVM", () => {
                 const updateConfigIx = await program.methods
                     .updateConfig(
                         higherTvl,
+                        null,
+                        null,
+                        null,
+                        null,
                         null
                     )
                     .accounts({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         admin: configAdmin.publicKey,
                     })
                     .instruction();
@@ -853,7 +2051,7 @@ describe("Validator Blacklist with LiteSVM", () => {
                 const [insufficientTvlVoteAddPda] = PublicKey.findProgramAddressSync(
                     [
                         Buffer.from("vote_add"),
-                        configAddress.publicKey.toBuffer(),
+                        configAddress.toBuffer(),
                         stakePoolAddress2.toBuffer(),
                         validatorToBlacklist.toBuffer()
                     ],
@@ -861,12 +2059,13 @@ describe("Validator Blacklist with LiteSVM", () => {
                 );
 
                 const voteAddIx = await program.methods
-                    .voteAdd(validatorToBlacklist, "Should fail due to TVL")
+                    .voteAdd(validatorToBlacklist, "Should fail due to TVL", { other: {} }, null)
                     .accountsPartial({
-                        config: configAddress.publicKey,
+                        config: configAddress,
                         stakePool: stakePoolAddress2,
                         blacklist: blacklistPda,
                         voteAdd: insufficientTvlVoteAddPda,
+                        validatorVoteAccount: null,
                         delegation: null,
                         authority: stakePoolManager.publicKey,
                         systemProgram: SystemProgram.programId,
@@ -883,6 +2082,499 @@ describe("Validator Blacklist with LiteSVM", () => {
                 expectInstructionErrorCustomCode(voteResult as FailedTransactionMetadata, 6007); // InsufficientTvl error code
             });
 
+            it("Should apply admin overrides of a single stake pool before the TVL rules", async () => {
+                const [poolOverridePda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("pool_override"), configAddress.toBuffer(), stakePoolAddress2.toBuffer()],
+                    programId
+                );
+
+                const sendAdminInstruction = (instruction: TransactionInstruction) => {
+                    const tx = new Transaction().add(instruction);
+                    tx.feePayer = configAdmin.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(configAdmin);
+                    expectSuccessfulTransaction(svm.sendTransaction(tx));
+                };
+
+                const setPoolOverride = async (kind: any) => sendAdminInstruction(await program.methods
+                    .setPoolOverride(kind)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress2,
+                        poolOverride: poolOverridePda,
+                        admin: configAdmin.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction());
+
+                const sendVoteAdd = async (validator: PublicKey) => {
+                    const [overrideBlacklistPda] = PublicKey.findProgramAddressSync(
+                        [Buffer.from("blacklist"), configAddress.toBuffer(), validator.toBuffer()],
+                        programId
+                    );
+                    const [overrideVoteAddPda] = PublicKey.findProgramAddressSync(
+                        [Buffer.from("vote_add"), configAddress.toBuffer(), stakePoolAddress2.toBuffer(), validator.toBuffer()],
+                        programId
+                    );
+
+                    const voteAddIx = await program.methods
+                        .voteAdd(validator, "Voted under a pool override", { other: {} }, null)
+                        .accountsPartial({
+                            config: configAddress,
+                            stakePool: stakePoolAddress2,
+                            poolOverride: poolOverridePda,
+                            blacklist: overrideBlacklistPda,
+                            voteAdd: overrideVoteAddPda,
+                            validatorVoteAccount: null,
+                            delegation: null,
+                            authority: stakePoolManager.publicKey,
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(voteAddIx);
+                    tx.feePayer = stakePoolManager.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(stakePoolManager);
+                    return svm.sendTransaction(tx);
+                };
+
+                // The global minimum TVL is above the stake pool's TVL, but the admin admits the pool
+                await setPoolOverride({ allow: {} });
+                const poolOverride = await program.account.poolOverride.fetch(poolOverridePda);
+                expect(poolOverride.stakePool.toString()).to.equal(stakePoolAddress2.toString());
+                expect(poolOverride.kind).to.deep.equal({ allow: {} });
+
+                expectSuccessfulTransaction(await sendVoteAdd(Keypair.generate().publicKey));
+
+                // A denied pool can no longer vote
+                await setPoolOverride({ deny: {} });
+                const deniedResult = await sendVoteAdd(Keypair.generate().publicKey);
+                expect(deniedResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(deniedResult as FailedTransactionMetadata, 6036); // StakePoolDenied error code
+
+                sendAdminInstruction(await program.methods
+                    .removePoolOverride()
+                    .accountsPartial({
+                        config: configAddress,
+                        poolOverride: poolOverridePda,
+                        admin: configAdmin.publicKey,
+                    })
+                    .instruction());
+
+                expect(svm.getAccount(poolOverridePda).lamports).to.equal(0);
+            });
+
+            it("Should apply a program specific minimum TVL over the global one", async () => {
+                // The global minimum TVL is still above the stake pool's TVL, but the program allows any TVL
+                const updateConfigIx = await program.methods
+                    .updateConfig(
+                        null,
+                        [{programId: STAKE_POOL_PROGRAM_ID, minTvl: new BN(0), adapter: { splStakePool: {} }}],
+                        null,
+                        null,
+                        null,
+                        null
+                    )
+                    .accounts({
+                        config: configAddress,
+                        admin: configAdmin.publicKey,
+                    })
+                    .instruction();
+
+                const updateTx = new Transaction().add(updateConfigIx);
+                updateTx.feePayer = configAdmin.publicKey;
+                updateTx.recentBlockhash = svm.latestBlockhash();
+                updateTx.sign(configAdmin);
+
+                const updateResult = svm.sendTransaction(updateTx);
+                expectSuccessfulTransaction(updateResult);
+
+                const [programTvlVoteAddPda] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("vote_add"),
+                        configAddress.toBuffer(),
+                        stakePoolAddress2.toBuffer(),
+                        validatorToBlacklist.toBuffer()
+                    ],
+                    programId
+                );
+
+                const voteAddIx = await program.methods
+                    .voteAdd(validatorToBlacklist, "Allowed by the program specific TVL", { other: {} }, null)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress2,
+                        blacklist: blacklistPda,
+                        voteAdd: programTvlVoteAddPda,
+                        validatorVoteAccount: null,
+                        delegation: null,
+                        authority: stakePoolManager.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction();
+
+                const voteTx = new Transaction().add(voteAddIx);
+                voteTx.feePayer = stakePoolManager.publicKey;
+                voteTx.recentBlockhash = svm.latestBlockhash();
+                voteTx.sign(stakePoolManager);
+
+                const voteResult = svm.sendTransaction(voteTx);
+                expectSuccessfulTransaction(voteResult);
+            });
+
+            it("Should reject votes while the config is paused", async () => {
+                const sendSetPaused = async (paused: boolean) => {
+                    const setPausedIx = await program.methods
+                        .setPaused(paused)
+                        .accounts({
+                            config: configAddress,
+                            admin: configAdmin.publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(setPausedIx);
+                    tx.feePayer = configAdmin.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(configAdmin);
+
+                    expectSuccessfulTransaction(svm.sendTransaction(tx));
+                };
+
+                await sendSetPaused(true);
+
+                const pausedValidator = Keypair.generate().publicKey;
+                const [pausedBlacklistPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), configAddress.toBuffer(), pausedValidator.toBuffer()],
+                    programId
+                );
+                const [pausedVoteAddPda] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("vote_add"),
+                        configAddress.toBuffer(),
+                        stakePoolAddress1.toBuffer(),
+                        pausedValidator.toBuffer()
+                    ],
+                    programId
+                );
+
+                const voteAddIx = await program.methods
+                    .voteAdd(pausedValidator, "Paused vote", { other: {} }, null)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        blacklist: pausedBlacklistPda,
+                        voteAdd: pausedVoteAddPda,
+                        validatorVoteAccount: null,
+                        delegation: null,
+                        authority: stakePoolManager.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction();
+
+                const voteTx = new Transaction().add(voteAddIx);
+                voteTx.feePayer = stakePoolManager.publicKey;
+                voteTx.recentBlockhash = svm.latestBlockhash();
+                voteTx.sign(stakePoolManager);
+
+                const voteResult = svm.sendTransaction(voteTx);
+                expect(voteResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(voteResult as FailedTransactionMetadata, 6015);
+
+                await sendSetPaused(false);
+
+                const configAccount = await program.account.config.fetch(configAddress);
+                expect(configAccount.paused).to.be.false;
+            });
+
+            it("Should only expire votes that outlived the vote lifetime", async () => {
+                const [expiringVoteAddPda] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("vote_add"),
+                        configAddress.toBuffer(),
+                        stakePoolAddress2.toBuffer(),
+                        validatorToBlacklist.toBuffer()
+                    ],
+                    programId
+                );
+
+                const updateConfigIx = await program.methods
+                    .updateConfig(null, null, null, null, new BN(60), null)
+                    .accounts({
+                        config: configAddress,
+                        admin: configAdmin.publicKey,
+                    })
+                    .instruction();
+
+                const updateTx = new Transaction().add(updateConfigIx);
+                updateTx.feePayer = configAdmin.publicKey;
+                updateTx.recentBlockhash = svm.latestBlockhash();
+                updateTx.sign(configAdmin);
+                expectSuccessfulTransaction(svm.sendTransaction(updateTx));
+
+                // The manager reaffirms the vote, so it is not expired yet
+                const reaffirmIx = await program.methods
+                    .reaffirmVote(validatorToBlacklist)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress2,
+                        voteAdd: expiringVoteAddPda,
+                        voteRemove: null,
+                        delegation: null,
+                        authority: stakePoolManager.publicKey,
+                    })
+                    .instruction();
+
+                const reaffirmTx = new Transaction().add(reaffirmIx);
+                reaffirmTx.feePayer = stakePoolManager.publicKey;
+                reaffirmTx.recentBlockhash = svm.latestBlockhash();
+                reaffirmTx.sign(stakePoolManager);
+                expectSuccessfulTransaction(svm.sendTransaction(reaffirmTx));
+
+                // Anyone can crank the expiry, the rent goes back to the manager who paid for the vote
+                const sendExpireVote = async () => {
+                    const expireIx = await program.methods
+                        .expireVote(validatorToBlacklist)
+                        .accountsPartial({
+                            config: configAddress,
+                            stakePool: stakePoolAddress2,
+                            blacklist: blacklistPda,
+                            voteAdd: expiringVoteAddPda,
+                            voteRemove: null,
+                            payer: stakePoolManager.publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(expireIx);
+                    tx.feePayer = unauthorizedUser.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(unauthorizedUser);
+                    return svm.sendTransaction(tx);
+                };
+
+                const earlyResult = await sendExpireVote();
+                expect(earlyResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(earlyResult as FailedTransactionMetadata, 6018);
+
+                const tallyBefore = (await program.account.blacklist.fetch(blacklistPda)).tallyAdd;
+
+                const clock = svm.getClock();
+                clock.unixTimestamp = clock.unixTimestamp + BigInt(61);
+                svm.setClock(clock);
+                svm.expireBlockhash();
+
+                expectSuccessfulTransaction(await sendExpireVote());
+
+                const voteAddAccount = svm.getAccount(expiringVoteAddPda);
+                expect(voteAddAccount.lamports).to.equal(0);
+
+                const blacklistAccount = await program.account.blacklist.fetch(blacklistPda);
+                expect(blacklistAccount.tallyAdd.toString()).to.equal(tallyBefore.subn(1).toString());
+            });
+
+            it("Should only prune votes of stake pools that no longer qualify", async () => {
+                const prunedValidator = Keypair.generate().publicKey;
+                const [prunedBlacklistPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), configAddress.toBuffer(), prunedValidator.toBuffer()],
+                    programId
+                );
+                const [prunedVoteAddPda] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("vote_add"),
+                        configAddress.toBuffer(),
+                        stakePoolAddress1.toBuffer(),
+                        prunedValidator.toBuffer()
+                    ],
+                    programId
+                );
+
+                const sendUpdateAllowedPrograms = async (allowedPrograms: any[]) => {
+                    const updateConfigIx = await program.methods
+                        .updateConfig(null, allowedPrograms, null, null, null, null)
+                        .accounts({
+                            config: configAddress,
+                            admin: configAdmin.publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(updateConfigIx);
+                    tx.feePayer = configAdmin.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(configAdmin);
+                    expectSuccessfulTransaction(svm.sendTransaction(tx));
+                };
+
+                const voteAddIx = await program.methods
+                    .voteAdd(prunedValidator, "Vote to be pruned", { other: {} }, null)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        blacklist: prunedBlacklistPda,
+                        voteAdd: prunedVoteAddPda,
+                        validatorVoteAccount: null,
+                        delegation: null,
+                        authority: stakePoolManager.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction();
+
+                const voteTx = new Transaction().add(voteAddIx);
+                voteTx.feePayer = stakePoolManager.publicKey;
+                voteTx.recentBlockhash = svm.latestBlockhash();
+                voteTx.sign(stakePoolManager);
+                expectSuccessfulTransaction(svm.sendTransaction(voteTx));
+
+                const sendPruneVote = async () => {
+                    const pruneIx = await program.methods
+                        .pruneVote(prunedValidator)
+                        .accountsPartial({
+                            config: configAddress,
+                            stakePool: stakePoolAddress1,
+                            blacklist: prunedBlacklistPda,
+                            voteAdd: prunedVoteAddPda,
+                            voteRemove: null,
+                            payer: stakePoolManager.publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(pruneIx);
+                    tx.feePayer = unauthorizedUser.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(unauthorizedUser);
+                    return svm.sendTransaction(tx);
+                };
+
+                const qualifyingResult = await sendPruneVote();
+                expect(qualifyingResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(qualifyingResult as FailedTransactionMetadata, 6020);
+
+                const sendUnvoteAdd = async () => {
+                    const unvoteAddIx = await program.methods
+                        .unvoteAdd(prunedValidator)
+                        .accountsPartial({
+                            config: configAddress,
+                            stakePool: stakePoolAddress1,
+                            blacklist: prunedBlacklistPda,
+                            voteAdd: prunedVoteAddPda,
+                            delegation: null,
+                            authority: stakePoolManager.publicKey,
+                            payer: stakePoolManager.publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(unvoteAddIx);
+                    tx.feePayer = stakePoolManager.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(stakePoolManager);
+                    return svm.sendTransaction(tx);
+                };
+
+                // The pool is not read as an SPL stake pool when the program is configured with another adapter
+                await sendUpdateAllowedPrograms([{programId: STAKE_POOL_PROGRAM_ID, minTvl: new BN(0), adapter: { marinade: {} }}]);
+                svm.expireBlockhash();
+
+                const mismatchedResult = await sendUnvoteAdd();
+                expect(mismatchedResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(mismatchedResult as FailedTransactionMetadata, 6001); // InvalidStakePool
+
+                // Removing the stake pool program disqualifies the pool, it can no longer unvote but its vote can be pruned
+                await sendUpdateAllowedPrograms([]);
+                svm.expireBlockhash();
+
+                const unlistedResult = await sendUnvoteAdd();
+                expect(unlistedResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(unlistedResult as FailedTransactionMetadata, 6008); // UnauthorizedStakePoolProgram
+
+                expectSuccessfulTransaction(await sendPruneVote());
+                expect(svm.getAccount(prunedVoteAddPda).lamports).to.equal(0);
+
+                const blacklistAccount = await program.account.blacklist.fetch(prunedBlacklistPda);
+                expect(blacklistAccount.tallyAdd.toString()).to.equal("0");
+                expect(blacklistAccount.stakeTallyAdd.toString()).to.equal("0");
+
+                await sendUpdateAllowedPrograms([{programId: STAKE_POOL_PROGRAM_ID, minTvl: new BN(0), adapter: { splStakePool: {} }}]);
+            });
+
+            it("Should close a blacklist entry once all of its votes are gone", async () => {
+                const closedValidator = Keypair.generate().publicKey;
+                const [closedBlacklistPda] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("blacklist"), configAddress.toBuffer(), closedValidator.toBuffer()],
+                    programId
+                );
+                const [closedVoteAddPda] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("vote_add"),
+                        configAddress.toBuffer(),
+                        stakePoolAddress1.toBuffer(),
+                        closedValidator.toBuffer()
+                    ],
+                    programId
+                );
+
+                const sendAsManager = (ix: TransactionInstruction) => {
+                    const tx = new Transaction().add(ix);
+                    tx.feePayer = stakePoolManager.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(stakePoolManager);
+                    return svm.sendTransaction(tx);
+                };
+
+                const sendCloseBlacklist = async () => {
+                    const closeIx = await program.methods
+                        .closeBlacklist(closedValidator)
+                        .accountsPartial({
+                            config: configAddress,
+                            blacklist: closedBlacklistPda,
+                            payer: stakePoolManager.publicKey,
+                        })
+                        .instruction();
+
+                    const tx = new Transaction().add(closeIx);
+                    tx.feePayer = unauthorizedUser.publicKey;
+                    tx.recentBlockhash = svm.latestBlockhash();
+                    tx.sign(unauthorizedUser);
+                    return svm.sendTransaction(tx);
+                };
+
+                expectSuccessfulTransaction(sendAsManager(await program.methods
+                    .voteAdd(closedValidator, "Vote to be withdrawn", { other: {} }, null)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        blacklist: closedBlacklistPda,
+                        voteAdd: closedVoteAddPda,
+                        validatorVoteAccount: null,
+                        delegation: null,
+                        authority: stakePoolManager.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .instruction()));
+
+                const blacklistAccount = await program.account.blacklist.fetch(closedBlacklistPda);
+                expect(blacklistAccount.payer.toString()).to.equal(stakePoolManager.publicKey.toString());
+
+                // An entry that still has votes stays open
+                const notEmptyResult = await sendCloseBlacklist();
+                expect(notEmptyResult).to.be.instanceOf(FailedTransactionMetadata);
+                expectInstructionErrorCustomCode(notEmptyResult as FailedTransactionMetadata, 6030);
+
+                expectSuccessfulTransaction(sendAsManager(await program.methods
+                    .unvoteAdd(closedValidator)
+                    .accountsPartial({
+                        config: configAddress,
+                        stakePool: stakePoolAddress1,
+                        blacklist: closedBlacklistPda,
+                        voteAdd: closedVoteAddPda,
+                        delegation: null,
+                        authority: stakePoolManager.publicKey,
+                    })
+                    .instruction()));
+
+                svm.expireBlockhash();
+                expectSuccessfulTransaction(await sendCloseBlacklist());
+                expect(svm.getAccount(closedBlacklistPda).lamports).to.equal(0);
+            });
+
         });
     });
 });